@@ -0,0 +1,176 @@
+use actix_web::body::EitherBody;
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{Error, HttpMessage, HttpResponse};
+use futures_util::future::LocalBoxFuture;
+use std::future::{ready, Ready};
+use std::sync::Arc;
+
+/// What a presented credential is allowed to do. Stored in the request
+/// extensions by the middleware so handlers that outlive the HTTP
+/// exchange — the WS control channel — can re-check it per message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Role {
+    /// Read-only: prices, trades, status
+    Viewer,
+    /// Everything, including config mutation and engine control
+    Operator,
+}
+
+/// API-key middleware with two credential roles.
+///
+/// Operator keys (`[api] api_keys` / `ARBITER_API_KEYS`) gate every
+/// non-GET request under `/api` — config updates, engine control, order
+/// cancels. Viewer keys (`[api] viewer_api_keys` / `ARBITER_VIEWER_KEYS`)
+/// grant read access only; once any viewer key is configured, GETs and
+/// the WS upgrade require a key too, so dashboard access can be handed
+/// out without the kill switch. Keys travel in `X-Api-Key` or
+/// `Authorization: Bearer`. With no keys configured at all the guard is
+/// off, preserving the old open behavior for isolated deployments.
+#[derive(Clone)]
+pub struct RequireApiKey {
+    operator_keys: Arc<Vec<String>>,
+    viewer_keys: Arc<Vec<String>>,
+}
+
+impl RequireApiKey {
+    pub fn new(operator: &[String], viewer: &[String]) -> Self {
+        let operator_keys = with_env_keys(operator, "ARBITER_API_KEYS");
+        let viewer_keys = with_env_keys(viewer, "ARBITER_VIEWER_KEYS");
+        if operator_keys.is_empty() {
+            tracing::warn!(
+                "No API keys configured — mutating endpoints are unauthenticated"
+            );
+        }
+        Self {
+            operator_keys: Arc::new(operator_keys),
+            viewer_keys: Arc::new(viewer_keys),
+        }
+    }
+
+    /// The role this request's credential carries, if any
+    fn presented_role(&self, req: &ServiceRequest) -> Option<Role> {
+        let presented = req
+            .headers()
+            .get("X-Api-Key")
+            .and_then(|v| v.to_str().ok())
+            .or_else(|| {
+                req.headers()
+                    .get("Authorization")
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.strip_prefix("Bearer "))
+            })
+            // Browsers can't set headers on a WS upgrade, so the key may
+            // ride in the query string there
+            .or_else(|| {
+                req.query_string()
+                    .split('&')
+                    .find_map(|kv| kv.strip_prefix("api_key="))
+            })?;
+        if self.operator_keys.iter().any(|k| k == presented) {
+            Some(Role::Operator)
+        } else if self.viewer_keys.iter().any(|k| k == presented) {
+            Some(Role::Viewer)
+        } else {
+            None
+        }
+    }
+
+    /// The effective role this connection carries: the presented key's
+    /// role, or Operator when no operator keys are configured at all
+    /// (open deployments keep the old unrestricted behavior)
+    fn connection_role(&self, req: &ServiceRequest) -> Option<Role> {
+        match self.presented_role(req) {
+            Some(role) => Some(role),
+            None if self.operator_keys.is_empty() => Some(Role::Operator),
+            None => None,
+        }
+    }
+
+    fn authorized(&self, req: &ServiceRequest) -> bool {
+        let read_only = req.method() == actix_web::http::Method::GET;
+        let guarded_path = req.path().starts_with("/api") || req.path() == "/ws";
+        if !guarded_path {
+            return true;
+        }
+        if read_only {
+            // Reads stay open until a viewer key exists; after that any
+            // valid credential will do
+            if self.viewer_keys.is_empty() {
+                return true;
+            }
+            return self.presented_role(req).is_some();
+        }
+        // Mutations always need an operator key (when any are configured)
+        if self.operator_keys.is_empty() {
+            return true;
+        }
+        self.presented_role(req) == Some(Role::Operator)
+    }
+}
+
+/// Configured keys plus any from a comma-separated env var
+fn with_env_keys(configured: &[String], var: &str) -> Vec<String> {
+    let mut keys: Vec<String> = configured.to_vec();
+    if let Ok(env_keys) = std::env::var(var) {
+        keys.extend(
+            env_keys
+                .split(',')
+                .map(|k| k.trim().to_string())
+                .filter(|k| !k.is_empty()),
+        );
+    }
+    keys
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RequireApiKey
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = RequireApiKeyMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequireApiKeyMiddleware {
+            service,
+            guard: self.clone(),
+        }))
+    }
+}
+
+pub struct RequireApiKeyMiddleware<S> {
+    service: S,
+    guard: RequireApiKey,
+}
+
+impl<S, B> Service<ServiceRequest> for RequireApiKeyMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        if let Some(role) = self.guard.connection_role(&req) {
+            req.extensions_mut().insert(role);
+        }
+        if self.guard.authorized(&req) {
+            let fut = self.service.call(req);
+            return Box::pin(async move {
+                fut.await.map(ServiceResponse::map_into_left_body)
+            });
+        }
+        Box::pin(async move {
+            let response = HttpResponse::Unauthorized()
+                .json(serde_json::json!({ "error": "missing or invalid API key" }));
+            Ok(req.into_response(response).map_into_right_body())
+        })
+    }
+}
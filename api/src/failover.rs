@@ -0,0 +1,110 @@
+use chrono::Utc;
+use serde_json::json;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{error, info, warn};
+
+use arb_core::exchange::ExchangeConnector;
+
+use crate::state::AppState;
+
+/// Standby election loop: poll the primary's heartbeat and take over
+/// execution (acquiring leadership and reconciling account state) once it
+/// has been silent past the takeover window. If the primary comes back
+/// claiming leadership, yield it back.
+pub async fn run_standby(
+    state: Arc<AppState>,
+    connectors: Vec<Arc<dyn ExchangeConnector>>,
+) {
+    let config = state.config.read().await;
+    let peer_url = config.failover.peer_url.trim_end_matches('/').to_string();
+    let heartbeat_secs = config.failover.heartbeat_secs.max(1);
+    let takeover_after = Duration::from_secs(config.failover.takeover_after_secs.max(1));
+    drop(config);
+
+    if peer_url.is_empty() {
+        error!("Failover standby configured without failover.peer_url — staying passive");
+        return;
+    }
+
+    info!(
+        "Standby mode: watching {} (takeover after {:?} of silence)",
+        peer_url, takeover_after
+    );
+
+    let client = reqwest::Client::new();
+    let heartbeat_url = format!("{}/api/heartbeat", peer_url);
+    let mut last_seen = Instant::now();
+
+    loop {
+        tokio::time::sleep(Duration::from_secs(heartbeat_secs)).await;
+
+        let peer_alive = match client
+            .get(&heartbeat_url)
+            .timeout(Duration::from_secs(heartbeat_secs))
+            .send()
+            .await
+        {
+            Ok(resp) => resp
+                .json::<serde_json::Value>()
+                .await
+                .map(|hb| hb["leader"].as_bool().unwrap_or(false))
+                .unwrap_or(false),
+            Err(_) => false,
+        };
+
+        let we_lead = state.execution_enabled.load(Ordering::Relaxed);
+
+        if peer_alive {
+            last_seen = Instant::now();
+            if we_lead {
+                // Primary is back and claims leadership — stand down so
+                // both instances never execute at once
+                warn!("Primary heartbeat returned — yielding execution leadership");
+                state.execution_enabled.store(false, Ordering::Relaxed);
+                state
+                    .record_action(
+                        "failover".to_string(),
+                        "leadership_yielded",
+                        json!({ "peer": peer_url }),
+                    )
+                    .await;
+            }
+        } else if !we_lead && last_seen.elapsed() >= takeover_after {
+            warn!(
+                "Primary heartbeat silent for {:?} — taking over execution",
+                last_seen.elapsed()
+            );
+            reconcile_on_takeover(&connectors).await;
+            state.execution_enabled.store(true, Ordering::Relaxed);
+            state
+                .record_action(
+                    "failover".to_string(),
+                    "leadership_acquired",
+                    json!({ "peer": peer_url, "at": Utc::now() }),
+                )
+                .await;
+        }
+    }
+}
+
+/// Re-establish account state before the first trade as leader. Until the
+/// open-orders API lands this verifies exchange connectivity and logs
+/// balances; in-flight orders from the old primary settle on their own.
+async fn reconcile_on_takeover(connectors: &[Arc<dyn ExchangeConnector>]) {
+    for connector in connectors {
+        match connector.get_balances().await {
+            Ok(balances) => info!(
+                "Takeover reconciliation: {} reachable, {} non-zero balances",
+                connector.exchange(),
+                balances.len()
+            ),
+            Err(e) => error!(
+                "Takeover reconciliation: {} unreachable: {}",
+                connector.exchange(),
+                e
+            ),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use actix_web::{web, HttpResponse};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::warn;
+
+use crate::state::AppState;
+
+/// Fetch a JSON path from one peer, tolerating slow or dead instances
+async fn fetch_peer(peer: &str, path: &str, timeout_ms: u64) -> Result<Value, String> {
+    let url = format!("{}{}", peer.trim_end_matches('/'), path);
+    let client = reqwest::Client::new();
+    client
+        .get(&url)
+        .timeout(Duration::from_millis(timeout_ms))
+        .send()
+        .await
+        .map_err(|e| e.to_string())?
+        .json()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Query every configured peer for `path`, returning (peer URL, result)
+async fn fetch_all_peers(state: &AppState, path: &str) -> Vec<(String, Result<Value, String>)> {
+    let config = state.config.read().await;
+    let peers = config.aggregation.peers.clone();
+    let timeout_ms = config.aggregation.timeout_ms;
+    drop(config);
+
+    let mut results = Vec::with_capacity(peers.len());
+    for peer in peers {
+        let result = fetch_peer(&peer, path, timeout_ms).await;
+        if let Err(ref e) = result {
+            warn!("Fleet peer {} unreachable: {}", peer, e);
+        }
+        results.push((peer, result));
+    }
+    results
+}
+
+/// GET /api/fleet/status — local status plus every peer's, with combined
+/// totals for quick fleet monitoring
+pub async fn fleet_status(state: web::Data<Arc<AppState>>) -> HttpResponse {
+    let local = serde_json::to_value(state.get_status().await).unwrap_or(Value::Null);
+    let peers = fetch_all_peers(&state, "/api/status").await;
+
+    let mut total_profit = local["total_profit"]
+        .as_str()
+        .and_then(|p| p.parse::<f64>().ok())
+        .unwrap_or(0.0);
+    let mut opportunities = local["opportunities_found"].as_u64().unwrap_or(0);
+    let mut trades = local["trades_executed"].as_u64().unwrap_or(0);
+    let mut running = local["running"].as_bool().unwrap_or(false) as u64;
+
+    let mut instances = vec![json!({ "source": "local", "status": local })];
+    for (peer, result) in peers {
+        match result {
+            Ok(status) => {
+                total_profit += status["total_profit"]
+                    .as_str()
+                    .and_then(|p| p.parse::<f64>().ok())
+                    .unwrap_or(0.0);
+                opportunities += status["opportunities_found"].as_u64().unwrap_or(0);
+                trades += status["trades_executed"].as_u64().unwrap_or(0);
+                running += status["running"].as_bool().unwrap_or(false) as u64;
+                instances.push(json!({ "source": peer, "status": status }));
+            }
+            Err(e) => instances.push(json!({ "source": peer, "error": e })),
+        }
+    }
+
+    HttpResponse::Ok().json(json!({
+        "instances": instances,
+        "combined": {
+            "instances_running": running,
+            "opportunities_found": opportunities,
+            "trades_executed": trades,
+            "total_profit": total_profit,
+        }
+    }))
+}
+
+/// GET /api/fleet/trades — all instances' trades, newest first, each
+/// tagged with its source instance
+pub async fn fleet_trades(state: web::Data<Arc<AppState>>) -> HttpResponse {
+    let mut tagged: Vec<Value> = state
+        .trades
+        .lock()
+        .await
+        .iter()
+        .filter_map(|t| serde_json::to_value(t).ok())
+        .map(|mut t| {
+            t["source"] = json!("local");
+            t
+        })
+        .collect();
+
+    for (peer, result) in fetch_all_peers(&state, "/api/trades").await {
+        let Ok(body) = result else {
+            continue;
+        };
+        // /api/trades serves a {total, limit, offset, trades} envelope;
+        // tolerate the older bare-array shape from not-yet-upgraded peers
+        let trades = match body {
+            Value::Array(trades) => trades,
+            Value::Object(mut envelope) => match envelope.remove("trades") {
+                Some(Value::Array(trades)) => trades,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        for mut trade in trades {
+            trade["source"] = json!(peer.clone());
+            tagged.push(trade);
+        }
+    }
+
+    tagged.sort_by(|a, b| {
+        b["executed_at"]
+            .as_str()
+            .unwrap_or("")
+            .cmp(a["executed_at"].as_str().unwrap_or(""))
+    });
+
+    HttpResponse::Ok().json(tagged)
+}
+
+/// GET /api/fleet/portfolio — balances from all instances combined per
+/// (exchange, asset)
+pub async fn fleet_portfolio(
+    state: web::Data<Arc<AppState>>,
+    connectors: web::Data<Vec<Arc<dyn arb_core::exchange::ExchangeConnector>>>,
+    portfolio: web::Data<Arc<arb_core::portfolio::PortfolioCache>>,
+) -> HttpResponse {
+    let mut instances = Vec::new();
+
+    // Reuse the local portfolio handler's response
+    let local = crate::routes::get_portfolio(state.clone(), connectors, portfolio).await;
+    if let Ok(body) = actix_web::body::to_bytes(local.into_body()).await {
+        if let Ok(balances) = serde_json::from_slice::<Value>(&body) {
+            instances.push(("local".to_string(), balances));
+        }
+    }
+
+    for (peer, result) in fetch_all_peers(&state, "/api/portfolio").await {
+        if let Ok(balances) = result {
+            instances.push((peer, balances));
+        }
+    }
+
+    // Combine totals per (exchange, asset) across instances
+    let mut combined: std::collections::BTreeMap<(String, String), f64> =
+        std::collections::BTreeMap::new();
+    for (_, balances) in &instances {
+        // Portfolio responses wrap the list: {"balances": [...], ...}
+        if let Some(arr) = balances["balances"].as_array().or(balances.as_array()) {
+            for b in arr {
+                let exchange = b["exchange"].as_str().unwrap_or("unknown").to_string();
+                let asset = b["asset"].as_str().unwrap_or("unknown").to_string();
+                let total = b["total"]
+                    .as_str()
+                    .and_then(|t| t.parse::<f64>().ok())
+                    .unwrap_or(0.0);
+                *combined.entry((exchange, asset)).or_default() += total;
+            }
+        }
+    }
+
+    HttpResponse::Ok().json(json!({
+        "instances": instances
+            .iter()
+            .map(|(source, balances)| json!({ "source": source, "balances": balances }))
+            .collect::<Vec<_>>(),
+        "combined": combined
+            .iter()
+            .map(|((exchange, asset), total)| json!({
+                "exchange": exchange,
+                "asset": asset,
+                "total": total,
+            }))
+            .collect::<Vec<_>>(),
+    }))
+}
@@ -1,34 +1,172 @@
+mod auth;
+mod failover;
+mod fleet;
+mod ratelimit;
+mod reload;
 mod routes;
 mod state;
 mod ws;
 
 use actix_cors::Cors;
 use actix_web::{web, App, HttpServer};
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tracing::info;
+use tracing::{info, warn};
 use tracing_subscriber::EnvFilter;
 
 use arb_core::exchange::bybit::BybitConnector;
 use arb_core::exchange::bitget::BitgetConnector;
-use arb_core::exchange::ExchangeConnector;
-use arb_core::{ArbitrageDetector, Config, OrderExecutor};
+use arb_core::exchange::{ExchangeConnector, RetryPolicy};
+use arb_core::flatten::FlatPositionEnforcer;
+use arb_core::costmodel::CostModel;
+use arb_core::funding::FundingArbMonitor;
+use arb_core::fx::FxRateCache;
+use arb_core::orders::OrderTracker;
+use arb_core::reference::ReferencePriceCache;
+use arb_core::sla::VenueSla;
+use arb_core::types::Exchange;
+use arb_core::{AccountEventMonitor, ArbitrageDetector, Config, OrderExecutor, PriceCache};
 
 use state::AppState;
 
+/// Console subscriber, with an OpenTelemetry layer exporting spans over
+/// OTLP when enabled — the pipeline spans carry the opportunity id, so a
+/// trace shows where each trade's latency went
+fn init_tracing(otel: &arb_core::config::OtelConfig) {
+    use tracing_subscriber::layer::SubscriberExt;
+    use tracing_subscriber::util::SubscriberInitExt;
+
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    let fmt_layer = tracing_subscriber::fmt::layer();
+
+    if otel.enabled {
+        use opentelemetry_otlp::WithExportConfig;
+        let exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_tonic()
+            .with_endpoint(&otel.endpoint)
+            .build();
+        match exporter {
+            Ok(exporter) => {
+                use opentelemetry::trace::TracerProvider as _;
+                let provider = opentelemetry_sdk::trace::TracerProvider::builder()
+                    .with_batch_exporter(exporter, opentelemetry_sdk::runtime::Tokio)
+                    .with_resource(opentelemetry_sdk::Resource::new(vec![
+                        opentelemetry::KeyValue::new(
+                            "service.name",
+                            otel.service_name.clone(),
+                        ),
+                    ]))
+                    .build();
+                let tracer = provider.tracer("arb-api");
+                opentelemetry::global::set_tracer_provider(provider);
+                tracing_subscriber::registry()
+                    .with(filter)
+                    .with(fmt_layer)
+                    .with(tracing_opentelemetry::layer().with_tracer(tracer))
+                    .init();
+                return;
+            }
+            Err(e) => eprintln!("OTLP exporter disabled: {}", e),
+        }
+    }
+    tracing_subscriber::registry().with(filter).with(fmt_layer).init();
+}
+
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
-    // Initialize tracing
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
-        )
-        .init();
+    // Initialize tracing: console output, plus OTLP span export over the
+    // trade pipeline when [otel] is enabled
+    init_tracing(&Config::load("config.toml").otel);
+
+    // `arb-api download <from> [to]` — backfill historical klines into the
+    // recorder's storage format and exit (dates are UTC, YYYY-MM-DD; `to`
+    // defaults to now)
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("download") {
+        let parse_day = |value: &str| {
+            chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|day| day.and_hms_opt(0, 0, 0))
+                .map(|naive| naive.and_utc())
+        };
+        let Some(start) = args.get(2).and_then(|value| parse_day(value)) else {
+            eprintln!("Usage: arb-api download <from: YYYY-MM-DD> [to: YYYY-MM-DD]");
+            std::process::exit(2);
+        };
+        let end = match args.get(3) {
+            Some(value) => match parse_day(value) {
+                Some(end) => end,
+                None => {
+                    eprintln!("Usage: arb-api download <from: YYYY-MM-DD> [to: YYYY-MM-DD]");
+                    std::process::exit(2);
+                }
+            },
+            None => chrono::Utc::now(),
+        };
+        let config = Config::load("config.toml");
+        arb_core::download::HistoricalDownloader::new(config)
+            .run(start, end)
+            .await;
+        return Ok(());
+    }
+
+    // `arb-api sweep <path> [random <samples>]` — sweep detector/executor
+    // parameters over a recorded capture and print the best sets per pair,
+    // then exit (grid search by default)
+    if args.get(1).map(String::as_str) == Some("sweep") {
+        let Some(path) = args.get(2) else {
+            eprintln!("Usage: arb-api sweep <file-or-dir> [random <samples>]");
+            std::process::exit(2);
+        };
+        let tickers = match arb_core::recorder::load_tickers(path) {
+            Ok(tickers) => tickers,
+            Err(e) => {
+                eprintln!("Could not load {}: {}", path, e);
+                std::process::exit(1);
+            }
+        };
+        let config = Config::load("config.toml");
+        let spec = arb_core::optimize::SweepSpec::around(&config);
+        let optimizer = arb_core::optimize::Optimizer::new(config, spec);
+        let results = if args.get(3).map(String::as_str) == Some("random") {
+            let samples = args
+                .get(4)
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(25);
+            optimizer.random(&tickers, samples).await
+        } else {
+            optimizer.grid(&tickers).await
+        };
+        match serde_json::to_string_pretty(&results) {
+            Ok(json) => println!("{}", json),
+            Err(e) => eprintln!("Could not serialize sweep results: {}", e),
+        }
+        return Ok(());
+    }
+
+    // `arb-api replay <path> [paced]` — run the full engine against a
+    // recorded capture instead of live WebSockets (path is a capture file
+    // or the recorder directory; "paced" honors recorded inter-tick gaps)
+    let replay: Option<(String, bool)> = if args.get(1).map(String::as_str) == Some("replay") {
+        let Some(path) = args.get(2).cloned() else {
+            eprintln!("Usage: arb-api replay <file-or-dir> [paced]");
+            std::process::exit(2);
+        };
+        Some((path, args.get(3).map(String::as_str) == Some("paced")))
+    } else {
+        None
+    };
 
     info!("🚀 ArbitrageBot API Server starting...");
 
     // Load configuration
-    let config = Config::load("config.toml");
+    let mut config = Config::load("config.toml");
+    if replay.is_some() {
+        // A replay must never reach live venues, whatever the config says
+        config.engine.simulation_mode = true;
+        info!("Replay mode: simulation forced on, live feeds disabled");
+    }
+    let config = config;
     let api_port = config.engine.api_port;
 
     info!(
@@ -36,8 +174,104 @@ async fn main() -> std::io::Result<()> {
         config.engine.simulation_mode, config.engine.min_spread_pct, config.trading.pairs
     );
 
+    // Single price cache shared by the detector and the API layer
+    let price_cache = Arc::new(PriceCache::new());
+
+    // FX rates for non-USD quote normalization
+    let fx_cache = Arc::new(FxRateCache::from_config(&config.fx));
+    let fx_for_refresh = fx_cache.clone();
+    tokio::spawn(async move {
+        fx_for_refresh.start().await;
+    });
+
+    // Execution cost model, shared by the detector and the calibration job
+    let cost_model = Arc::new(CostModel::new(&config.cost_model));
+
+    // External reference prices for the opportunity sanity check
+    let reference_cache = Arc::new(ReferencePriceCache::from_config(&config.reference));
+    let reference_for_refresh = reference_cache.clone();
+    tokio::spawn(async move {
+        reference_for_refresh.start().await;
+    });
+
+    // Rolling venue latency tracking, shared by the detector, the executor
+    // and the API
+    let venue_sla = Arc::new(VenueSla::new());
+
+    // Central order registry, shared by the executor and the API
+    let order_tracker = Arc::new(OrderTracker::new());
+    let portfolio_cache = Arc::new(arb_core::portfolio::PortfolioCache::new(5_000));
+    let position_tracker = Arc::new(arb_core::positions::PositionTracker::new());
+    let rebalancer = Arc::new(arb_core::rebalance::Rebalancer::from_config(
+        &config.rebalance,
+        position_tracker.clone(),
+    ));
+    let rebalancer_for_loop = rebalancer.clone();
+    tokio::spawn(async move {
+        rebalancer_for_loop.start().await;
+    });
+
+    // A standby instance consumes market data but holds off executing
+    // until failover promotes it
+    let is_standby = config.failover.enabled && config.failover.role == "standby";
+    let execution_enabled = Arc::new(AtomicBool::new(!is_standby));
+    let engine_paused = Arc::new(AtomicBool::new(false));
+    let (config_tx, config_rx) = tokio::sync::watch::channel(config.clone());
+    if is_standby {
+        info!("⏸  Standby role — execution disabled until leadership is acquired");
+    }
+
     // Create shared state
-    let app_state = Arc::new(AppState::new(config.clone()));
+    // Optional SQLite persistence of trades and opportunities
+    let store = Arc::new(arb_core::store::SqliteStore::from_config(&config.store));
+
+    // Optional Redis mirror of prices/opportunities/trades (no-op unless
+    // enabled); the ticker stream is mirrored from its own loop
+    let mirror = Arc::new(arb_core::mirror::RedisMirror::from_config(&config.mirror));
+    let mirror_for_ticks = mirror.clone();
+    let prices_for_mirror = price_cache.clone();
+    tokio::spawn(async move {
+        mirror_for_ticks.start(prices_for_mirror).await;
+    });
+
+    // Optional NATS/Kafka event publishing (no-op unless enabled)
+    let events = Arc::new(arb_core::events::EventBus::from_config(&config.events));
+    let events_for_ticks = events.clone();
+    let prices_for_events = price_cache.clone();
+    tokio::spawn(async move {
+        events_for_ticks.start(prices_for_events).await;
+    });
+
+    // Optional webhook notifications, including the connector feed watch
+    // (no-op unless enabled)
+    let notifier = Arc::new(arb_core::notify::Notifier::from_config(&config));
+    let notifier_for_watch = notifier.clone();
+    let prices_for_notify = price_cache.clone();
+    let paused_for_notify = engine_paused.clone();
+    tokio::spawn(async move {
+        notifier_for_watch
+            .start(prices_for_notify, paused_for_notify)
+            .await;
+    });
+
+    let app_state = Arc::new(AppState::new(
+        config.clone(),
+        price_cache.clone(),
+        fx_cache.clone(),
+        cost_model.clone(),
+        execution_enabled.clone(),
+        engine_paused.clone(),
+        config_tx,
+        store.clone(),
+        mirror.clone(),
+        events.clone(),
+        notifier.clone(),
+        venue_sla.clone(),
+    ));
+
+    // Append-only audit trail of order requests/responses, shared by all
+    // connectors and served via GET /api/audit
+    let audit_log = Arc::new(arb_core::audit::OrderAuditLog::from_config(&config.audit));
 
     // Create exchange connectors
     let mut connectors: Vec<Arc<dyn ExchangeConnector>> = Vec::new();
@@ -50,7 +284,12 @@ async fn main() -> std::io::Result<()> {
                 "(empty)".to_string()
             };
             info!("Bybit connector enabled | api_key={} | has_secret={}", key_mask, !cfg.api_secret.is_empty());
-            connectors.push(Arc::new(BybitConnector::new(cfg.clone())));
+            connectors.push(Arc::new(BybitConnector::new(
+                cfg.clone(),
+                RetryPolicy::from_config(&config.retry)
+                    .with_latency(Exchange::Bybit, venue_sla.clone()),
+                Some(audit_log.clone()),
+            )));
         }
     }
 
@@ -62,35 +301,167 @@ async fn main() -> std::io::Result<()> {
                 "(empty)".to_string()
             };
             info!("Bitget connector enabled | api_key={} | has_secret={} | has_passphrase={}", 
-                key_mask, !cfg.api_secret.is_empty(), cfg.passphrase.as_ref().map_or(false, |p| !p.is_empty()));
-            connectors.push(Arc::new(BitgetConnector::new(cfg.clone())));
+                key_mask, !cfg.api_secret.is_empty(), cfg.passphrase.as_ref().is_some_and(|p| !p.is_empty()));
+            connectors.push(Arc::new(BitgetConnector::new(
+                cfg.clone(),
+                RetryPolicy::from_config(&config.retry)
+                    .with_latency(Exchange::Bitget, venue_sla.clone()),
+                Some(audit_log.clone()),
+            )));
+        }
+    }
+
+    // Hot-apply safe tuning fields when config.toml changes on disk
+    reload::spawn("config.toml", app_state.clone());
+
+    // Forward system events to dashboard WS clients as they happen
+    let state_for_events = app_state.clone();
+    let mut system_events = notifier.event_log().subscribe();
+    tokio::spawn(async move {
+        use tokio::sync::broadcast::error::RecvError;
+        loop {
+            match system_events.recv().await {
+                Ok(event) => {
+                    state_for_events
+                        .broadcast(&arb_core::types::WsMessage::SystemEvent(event))
+                        .await;
+                }
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+
+    // Per-venue connectivity registry, for GET /api/exchanges and the
+    // dashboard health push
+    let health_registry = Arc::new(arb_core::health::ExchangeHealthRegistry::new(
+        connectors.clone(),
+        config_rx.clone(),
+        price_cache.clone(),
+    ));
+    let health_for_push = health_registry.clone();
+    let state_for_health = app_state.clone();
+    tokio::spawn(async move {
+        // Push only when a venue's state or reconnect count moves, so the
+        // socket isn't spammed every tick
+        let mut last_key = String::new();
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+            let snapshot = health_for_push.snapshot();
+            let key = snapshot
+                .iter()
+                .map(|h| format!("{}:{:?}:{}", h.exchange, h.state, h.reconnects))
+                .collect::<Vec<_>>()
+                .join(",");
+            if key != last_key {
+                last_key = key;
+                state_for_health
+                    .broadcast(&arb_core::types::WsMessage::ExchangeHealth(snapshot))
+                    .await;
+            }
+        }
+    });
+
+    // Keep signed timestamps aligned with each exchange's server clock —
+    // hosts with drifting clocks otherwise hit "timestamp expired" errors
+    let connectors_for_time = connectors.clone();
+    tokio::spawn(async move {
+        loop {
+            for connector in &connectors_for_time {
+                if let Err(e) = connector.sync_server_time().await {
+                    tracing::warn!(
+                        "Server time sync failed for {}: {}",
+                        connector.exchange(),
+                        e
+                    );
+                }
+            }
+            tokio::time::sleep(std::time::Duration::from_secs(300)).await;
+        }
+    });
+
+    // Probe each exchange's public API before trading on it, so breaking
+    // API changes show up at startup instead of as degraded data
+    for connector in &connectors {
+        match connector.probe_compatibility().await {
+            Ok(()) => info!(
+                "{} API {} compatibility probe passed",
+                connector.exchange(),
+                connector.api_version()
+            ),
+            Err(e) => {
+                tracing::error!(
+                    "{} API {} compatibility probe FAILED: {}",
+                    connector.exchange(),
+                    connector.api_version(),
+                    e
+                );
+                app_state.compat_probes_ok.store(false, Ordering::Relaxed);
+            }
         }
     }
 
     // Create channels for inter-component communication
     let (opp_tx, opp_rx) = tokio::sync::mpsc::unbounded_channel();
-    let (ticker_tx, mut ticker_rx) = tokio::sync::mpsc::unbounded_channel();
     let (trade_tx, mut trade_rx) = tokio::sync::mpsc::unbounded_channel();
 
     // Create the core engine components
     let detector = Arc::new(ArbitrageDetector::new(
         connectors.clone(),
-        config.clone(),
+        config_rx.clone(),
         opp_tx.clone(),
-        ticker_tx,
+        price_cache.clone(),
+        cost_model.clone(),
+        fx_cache.clone(),
+        venue_sla.clone(),
+        reference_cache.clone(),
+        engine_paused.clone(),
     ));
 
     let executor = Arc::new(OrderExecutor::new(
         connectors.clone(),
-        config.clone(),
+        config_rx.clone(),
         trade_tx,
+        price_cache.clone(),
+        fx_cache.clone(),
+        venue_sla.clone(),
+        order_tracker.clone(),
+        position_tracker.clone(),
+        execution_enabled.clone(),
+        engine_paused.clone(),
+        notifier.clone(),
     ));
 
-    // Spawn ticker forwarding to app state
+    // Periodically re-anchor tracked inventory from real balances
+    if !config.engine.simulation_mode {
+        let positions_for_sync = position_tracker.clone();
+        let portfolio_for_sync = portfolio_cache.clone();
+        let connectors_for_sync = connectors.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+                let snapshot = portfolio_for_sync.snapshot(&connectors_for_sync).await;
+                positions_for_sync.sync_balances(&snapshot.balances);
+            }
+        });
+    }
+
+    // Push live ticker updates out to WebSocket clients
     let state_for_ticker = app_state.clone();
+    let mut price_updates = price_cache.subscribe();
     tokio::spawn(async move {
-        while let Some(ticker) = ticker_rx.recv().await {
-            state_for_ticker.update_price(ticker).await;
+        use tokio::sync::broadcast::error::RecvError;
+        loop {
+            match price_updates.recv().await {
+                Ok(ticker) => {
+                    state_for_ticker
+                        .broadcast(&arb_core::types::WsMessage::Ticker(ticker))
+                        .await
+                }
+                // Slow consumer: skip missed tickers and catch up
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
         }
     });
 
@@ -113,15 +484,120 @@ async fn main() -> std::io::Result<()> {
         }
     });
 
-    // Start the arbitrage detector
-    let detector_clone = detector.clone();
+    // Start the arbitrage detector — live WebSocket feeds, or a recorded
+    // capture when replaying
+    if let Some((replay_path, paced)) = replay.clone() {
+        let detector_for_replay = detector.clone();
+        tokio::spawn(async move {
+            arb_core::replay::Replayer::new(detector_for_replay, paced)
+                .run(&replay_path)
+                .await;
+        });
+    } else {
+        let detector_clone = detector.clone();
+        tokio::spawn(async move {
+            detector_clone.start().await;
+        });
+    }
+
+    // Start the order executor
+    let executor_for_api = executor.clone();
+    let executor_for_loop = executor.clone();
+    tokio::spawn(async move {
+        executor_for_loop.start(opp_to_exec_rx).await;
+    });
+
+    // Periodically re-fit the execution cost model from recorded fills
+    let state_for_calibration = app_state.clone();
+    let cost_model_for_job = cost_model.clone();
+    let calibration_secs = config.cost_model.refresh_secs.max(60);
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(calibration_secs)).await;
+            let trades = state_for_calibration.trades.lock().await.clone();
+            let opportunities: Vec<_> = state_for_calibration
+                .opportunities
+                .lock()
+                .await
+                .iter()
+                .cloned()
+                .collect();
+            cost_model_for_job.calibrate(&trades, &opportunities);
+        }
+    });
+
+    // Standby leader election against the primary's heartbeat
+    if is_standby {
+        let state_for_failover = app_state.clone();
+        let connectors_for_failover = connectors.clone();
+        tokio::spawn(async move {
+            failover::run_standby(state_for_failover, connectors_for_failover).await;
+        });
+    }
+
+    // Record live market data to disk (no-op unless enabled)
+    let recorder = arb_core::recorder::MarketDataRecorder::new(price_cache.clone(), config.clone());
     tokio::spawn(async move {
-        detector_clone.start().await;
+        recorder.start().await;
     });
 
-    // Start the order executor
+    // Funding-rate arbitrage monitor (no-op unless enabled)
+    let funding_monitor = Arc::new(FundingArbMonitor::new(
+        connectors.clone(),
+        price_cache.clone(),
+        config.clone(),
+        executor.clone(),
+    ));
+    let funding_for_loop = funding_monitor.clone();
     tokio::spawn(async move {
-        executor.start(opp_to_exec_rx).await;
+        funding_for_loop.start().await;
+    });
+
+    // Arm the end-of-day flat-position policy (no-op unless enabled)
+    let flattener = FlatPositionEnforcer::new(
+        connectors.clone(),
+        price_cache.clone(),
+        config.clone(),
+        executor.clone(),
+    );
+    tokio::spawn(async move {
+        flattener.start().await;
+    });
+
+    // Start the account event monitor and forward its events to app state
+    let (account_tx, mut account_rx) = tokio::sync::mpsc::unbounded_channel();
+    let account_monitor = AccountEventMonitor::new(
+        connectors.clone(),
+        config.engine.account_poll_secs,
+        account_tx,
+    );
+    tokio::spawn(async move {
+        account_monitor.start().await;
+    });
+    let state_for_account = app_state.clone();
+    tokio::spawn(async move {
+        while let Some(event) = account_rx.recv().await {
+            state_for_account.add_account_event(event).await;
+        }
+    });
+
+    // Reconcile local trade records against exchange order history,
+    // surfacing orphan fills and phantom trades over the WS channel
+    let (discrepancy_tx, mut discrepancy_rx) = tokio::sync::mpsc::unbounded_channel();
+    let reconciler = arb_core::reconcile::Reconciler::new(
+        connectors.clone(),
+        order_tracker.clone(),
+        config.clone(),
+        discrepancy_tx,
+    );
+    tokio::spawn(async move {
+        reconciler.start().await;
+    });
+    let state_for_reconcile = app_state.clone();
+    tokio::spawn(async move {
+        while let Some(discrepancy) = discrepancy_rx.recv().await {
+            state_for_reconcile.add_discrepancy(discrepancy).await;
+        }
     });
 
     // Mark engine as running
@@ -132,16 +608,61 @@ async fn main() -> std::io::Result<()> {
 
     // Start HTTP server
     let state_data = app_state.clone();
+    let detector_data = detector.clone();
+    let funding_data = funding_monitor.clone();
+    let sla_data = venue_sla.clone();
+    let orders_data = order_tracker.clone();
+    let connectors_data = connectors.clone();
+    let reference_data = reference_cache.clone();
+    let portfolio_data = portfolio_cache.clone();
+    let positions_data = position_tracker.clone();
+    let rebalancer_data = rebalancer.clone();
+    let executor_data = executor_for_api.clone();
+    let audit_data = audit_log.clone();
+    let health_data = health_registry.clone();
+    let api_guard =
+        auth::RequireApiKey::new(&config.api.api_keys, &config.api.viewer_api_keys);
+    let rate_limiter = ratelimit::RateLimit::new(
+        config.api.rate_limit_per_minute,
+        config.api.sensitive_rate_limit_per_minute,
+    );
+    let cors_allow_all = config.engine.cors_allow_all;
+    let cors_origins = config.engine.cors_allowed_origins.clone();
+    if cors_allow_all {
+        warn!("CORS is wide open (cors_allow_all) — any site can call this API");
+    }
     HttpServer::new(move || {
-        let cors = Cors::default()
-            .allow_any_origin()
+        // Browsers only get the origins the operator listed; the old
+        // wildcard needs an explicit opt-in now that the API mutates config
+        let mut cors = Cors::default()
             .allow_any_method()
             .allow_any_header()
             .max_age(3600);
+        if cors_allow_all {
+            cors = cors.allow_any_origin();
+        } else {
+            for origin in &cors_origins {
+                cors = cors.allowed_origin(origin);
+            }
+        }
 
         App::new()
+            .wrap(rate_limiter.clone())
+            .wrap(api_guard.clone())
             .wrap(cors)
             .app_data(web::Data::new(state_data.clone()))
+            .app_data(web::Data::new(detector_data.clone()))
+            .app_data(web::Data::new(funding_data.clone()))
+            .app_data(web::Data::new(sla_data.clone()))
+            .app_data(web::Data::new(orders_data.clone()))
+            .app_data(web::Data::new(connectors_data.clone()))
+            .app_data(web::Data::new(reference_data.clone()))
+            .app_data(web::Data::new(portfolio_data.clone()))
+            .app_data(web::Data::new(positions_data.clone()))
+            .app_data(web::Data::new(rebalancer_data.clone()))
+            .app_data(web::Data::new(executor_data.clone()))
+            .app_data(web::Data::new(audit_data.clone()))
+            .app_data(web::Data::new(health_data.clone()))
             .configure(routes::configure)
             .route("/ws", web::get().to(ws::ws_handler))
     })
@@ -0,0 +1,123 @@
+use actix_web::body::EitherBody;
+use actix_web::dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform};
+use actix_web::{Error, HttpResponse};
+use dashmap::DashMap;
+use futures_util::future::LocalBoxFuture;
+use std::future::{ready, Ready};
+use std::sync::Arc;
+
+/// Paths that get the stricter sensitive-endpoint budget
+const SENSITIVE_PREFIXES: [&str; 3] = ["/api/config", "/api/orders", "/ws"];
+
+/// Per-IP fixed-window rate limiter.
+///
+/// Two budgets, both per client IP per minute: a general one across the
+/// whole API and a stricter one for config mutation, order control and
+/// the WS upgrade — the endpoints worth abusing on a publicly exposed
+/// deployment. A limit of 0 disables that budget.
+#[derive(Clone)]
+pub struct RateLimit {
+    general_per_minute: u32,
+    sensitive_per_minute: u32,
+    /// (ip, sensitive) → (window start minute, requests in window)
+    windows: Arc<DashMap<(String, bool), (i64, u32)>>,
+}
+
+impl RateLimit {
+    pub fn new(general_per_minute: u32, sensitive_per_minute: u32) -> Self {
+        Self {
+            general_per_minute,
+            sensitive_per_minute,
+            windows: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// Count this request against the caller's window; true when it is
+    /// still within budget
+    fn allow(&self, req: &ServiceRequest) -> bool {
+        let sensitive = SENSITIVE_PREFIXES
+            .iter()
+            .any(|prefix| req.path().starts_with(prefix));
+        let limit = if sensitive {
+            self.sensitive_per_minute
+        } else {
+            self.general_per_minute
+        };
+        if limit == 0 {
+            return true;
+        }
+        let ip = req
+            .connection_info()
+            .realip_remote_addr()
+            .unwrap_or("unknown")
+            .to_string();
+        let minute = chrono::Utc::now().timestamp() / 60;
+
+        let mut window = self
+            .windows
+            .entry((ip, sensitive))
+            .or_insert((minute, 0));
+        if window.0 != minute {
+            *window = (minute, 0);
+        }
+        window.1 += 1;
+        let allowed = window.1 <= limit;
+        drop(window);
+
+        // Drop stale windows so the map doesn't grow with one-off clients
+        if self.windows.len() > 10_000 {
+            self.windows.retain(|_, (start, _)| *start >= minute - 1);
+        }
+        allowed
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for RateLimit
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Transform = RateLimitMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RateLimitMiddleware {
+            service,
+            limiter: self.clone(),
+        }))
+    }
+}
+
+pub struct RateLimitMiddleware<S> {
+    service: S,
+    limiter: RateLimit,
+}
+
+impl<S, B> Service<ServiceRequest> for RateLimitMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<EitherBody<B>>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        if self.limiter.allow(&req) {
+            let fut = self.service.call(req);
+            return Box::pin(async move {
+                fut.await.map(ServiceResponse::map_into_left_body)
+            });
+        }
+        Box::pin(async move {
+            let response = HttpResponse::TooManyRequests()
+                .json(serde_json::json!({ "error": "rate limit exceeded" }));
+            Ok(req.into_response(response).map_into_right_body())
+        })
+    }
+}
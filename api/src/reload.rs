@@ -0,0 +1,123 @@
+use notify::{RecursiveMode, Watcher};
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::state::AppState;
+
+/// Editors fire several events per save; wait this long and drain before
+/// reloading
+const DEBOUNCE_MS: u64 = 500;
+
+/// Watch `config.toml` and hot-apply the safe tuning fields — spread
+/// threshold, quantities, risk limits — at runtime, logging a diff of
+/// what changed.
+///
+/// Anything dangerous to flip on a file save stays restart-only:
+/// simulation_mode (live trading has its own two-step confirmation),
+/// exchange credentials, ports and subsystem wiring.
+pub fn spawn(path: &str, state: Arc<AppState>) {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+    let mut watcher = match notify::recommended_watcher(move |event| {
+        if let Ok(event) = event {
+            let _ = tx.send(event);
+        }
+    }) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            warn!("Config hot-reload disabled: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = watcher.watch(Path::new(path), RecursiveMode::NonRecursive) {
+        warn!("Config hot-reload disabled: could not watch {}: {}", path, e);
+        return;
+    }
+    info!("Watching {} for config changes", path);
+
+    let path = path.to_string();
+    tokio::spawn(async move {
+        // Keep the watcher alive for the life of the task
+        let _watcher = watcher;
+        while rx.recv().await.is_some() {
+            tokio::time::sleep(std::time::Duration::from_millis(DEBOUNCE_MS)).await;
+            while rx.try_recv().is_ok() {}
+            apply(&path, &state).await;
+        }
+    });
+}
+
+/// Reload the file and fold the safe fields into the live config,
+/// broadcasting the new snapshot if anything actually changed
+async fn apply(path: &str, state: &Arc<AppState>) {
+    let fresh = arb_core::config::Config::load(path);
+    let mut config = state.config.write().await;
+    let mut changes = Vec::new();
+
+    macro_rules! sync_field {
+        ($field:expr, $new:expr, $name:literal) => {
+            if $field != $new {
+                changes.push(format!("{}: {:?} -> {:?}", $name, $field, $new));
+                $field = $new;
+            }
+        };
+    }
+
+    sync_field!(
+        config.engine.min_spread_pct,
+        fresh.engine.min_spread_pct,
+        "min_spread_pct"
+    );
+    sync_field!(
+        config.engine.scan_interval_ms,
+        fresh.engine.scan_interval_ms,
+        "scan_interval_ms"
+    );
+    sync_field!(
+        config.trading.max_trade_qty,
+        fresh.trading.max_trade_qty,
+        "max_trade_qty"
+    );
+    sync_field!(
+        config.trading.min_trade_qty,
+        fresh.trading.min_trade_qty,
+        "min_trade_qty"
+    );
+    sync_field!(
+        config.trading.order_type,
+        fresh.trading.order_type.clone(),
+        "order_type"
+    );
+    sync_field!(config.trading.pairs, fresh.trading.pairs.clone(), "pairs");
+    sync_field!(
+        config.risk.max_position,
+        fresh.risk.max_position,
+        "max_position"
+    );
+    sync_field!(
+        config.risk.max_daily_loss,
+        fresh.risk.max_daily_loss,
+        "max_daily_loss"
+    );
+    sync_field!(
+        config.risk.max_concurrent_trades,
+        fresh.risk.max_concurrent_trades,
+        "max_concurrent_trades"
+    );
+    sync_field!(
+        config.risk.trade_cooldown_ms,
+        fresh.risk.trade_cooldown_ms,
+        "trade_cooldown_ms"
+    );
+
+    if changes.is_empty() {
+        return;
+    }
+    info!("Config file changed, applied: {}", changes.join(", "));
+    state.notifier.notify(
+        arb_core::notify::NotificationKind::ConfigChange,
+        "Config file reloaded",
+        changes.join(", "),
+    );
+    let _ = state.config_tx.send(config.clone());
+}
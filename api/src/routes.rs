@@ -1,26 +1,649 @@
-use actix_web::{web, HttpResponse};
+use actix_web::{web, HttpRequest, HttpResponse};
 use serde::Deserialize;
 use std::sync::Arc;
 
+use arb_core::ArbitrageDetector;
+
 use crate::state::AppState;
 
 /// GET /api/prices — current prices across all exchanges
 pub async fn get_prices(state: web::Data<Arc<AppState>>) -> HttpResponse {
-    let prices: Vec<_> = state.prices.iter().map(|entry| entry.value().clone()).collect();
-    HttpResponse::Ok().json(prices)
+    HttpResponse::Ok().json(state.prices.all())
+}
+
+/// GET /api/debug/prices — the detector's internal price cache diagnostics
+pub async fn get_debug_prices(detector: web::Data<Arc<ArbitrageDetector>>) -> HttpResponse {
+    HttpResponse::Ok().json(detector.diagnostics())
+}
+
+/// Query parameters for GET /api/spreads/{pair}
+#[derive(Deserialize)]
+pub struct SpreadQuery {
+    /// Trailing window, e.g. "30s", "15m", "1h" (default "1h")
+    window: Option<String>,
+    /// Maximum points to return after downsampling (default 500)
+    points: Option<usize>,
+}
+
+/// Parse a "30s" / "15m" / "1h" style window into milliseconds
+fn parse_window_ms(window: &str) -> Option<i64> {
+    let (digits, unit) = window.split_at(window.len().saturating_sub(1));
+    let value: i64 = digits.parse().ok()?;
+    let ms = match unit {
+        "s" => value.checked_mul(1_000)?,
+        "m" => value.checked_mul(60_000)?,
+        "h" => value.checked_mul(3_600_000)?,
+        "d" => value.checked_mul(86_400_000)?,
+        _ => return None,
+    };
+    (ms > 0).then_some(ms)
+}
+
+/// GET /api/spreads/{pair} — recorded spread time series for a pair,
+/// downsampled to at most `points` observations over the trailing `window`
+pub async fn get_spread_series(
+    path: web::Path<String>,
+    query: web::Query<SpreadQuery>,
+    detector: web::Data<Arc<ArbitrageDetector>>,
+) -> HttpResponse {
+    let pair = path.into_inner().to_uppercase();
+    let window = query.window.as_deref().unwrap_or("1h");
+    let Some(window_ms) = parse_window_ms(window) else {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": format!("invalid window '{}', expected e.g. 30s, 15m, 1h", window)
+        }));
+    };
+    let points = query.points.unwrap_or(500).min(5_000);
+    HttpResponse::Ok().json(serde_json::json!({
+        "pair": pair,
+        "window": window,
+        "points": detector.spread_series(&pair, window_ms, points),
+    }))
+}
+
+/// GET /api/spreads — pairs with recorded spread samples
+pub async fn get_spread_pairs(detector: web::Data<Arc<ArbitrageDetector>>) -> HttpResponse {
+    HttpResponse::Ok().json(detector.spread_pairs())
+}
+
+/// Query parameters for GET /api/candles
+#[derive(Deserialize)]
+pub struct CandleQuery {
+    exchange: arb_core::Exchange,
+    pair: String,
+    /// Candle interval, "1s" or "1m" (default "1m")
+    interval: Option<String>,
+    /// Maximum candles to return (default 500)
+    limit: Option<usize>,
+}
+
+/// GET /api/candles — recent OHLCV candles for one (exchange, pair) series
+pub async fn get_candles(
+    query: web::Query<CandleQuery>,
+    detector: web::Data<Arc<ArbitrageDetector>>,
+) -> HttpResponse {
+    let interval = query.interval.as_deref().unwrap_or("1m");
+    let interval_secs = match interval {
+        "1s" => 1,
+        "1m" => 60,
+        _ => {
+            return HttpResponse::BadRequest().json(serde_json::json!({
+                "error": format!("invalid interval '{}', expected 1s or 1m", interval)
+            }))
+        }
+    };
+    let pair = query.pair.to_uppercase();
+    let limit = query.limit.unwrap_or(500).min(5_000);
+    HttpResponse::Ok().json(serde_json::json!({
+        "exchange": query.exchange,
+        "pair": pair,
+        "interval": interval,
+        "candles": detector.candles(query.exchange, &pair, interval_secs, limit),
+    }))
+}
+
+/// Query parameters for historical trade/opportunity lookups: passing
+/// `since` serves from the SQLite store (when enabled) instead of the
+/// bounded in-memory window
+#[derive(serde::Deserialize)]
+pub struct HistoryQuery {
+    /// RFC 3339 timestamp; rows at or after this instant
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    pub pair: Option<String>,
+    pub limit: Option<usize>,
 }
 
-/// GET /api/opportunities — recent arbitrage opportunities
-pub async fn get_opportunities(state: web::Data<Arc<AppState>>) -> HttpResponse {
+/// GET /api/opportunities — recent arbitrage opportunities, or historical
+/// ones from the store when `since` is given
+pub async fn get_opportunities(
+    state: web::Data<Arc<AppState>>,
+    query: web::Query<HistoryQuery>,
+) -> HttpResponse {
+    if let Some(since) = query.since {
+        if !state.store.enabled() {
+            return HttpResponse::BadRequest().json(serde_json::json!({
+                "error": "historical queries need [store] enabled in config"
+            }));
+        }
+        let limit = query.limit.unwrap_or(1_000).min(10_000);
+        let list = state
+            .store
+            .opportunities_since(since, query.pair.as_deref(), limit);
+        return HttpResponse::Ok().json(list);
+    }
     let opps = state.opportunities.lock().await;
     let list: Vec<_> = opps.iter().cloned().collect();
     HttpResponse::Ok().json(list)
 }
 
-/// GET /api/trades — trade history
-pub async fn get_trades(state: web::Data<Arc<AppState>>) -> HttpResponse {
+/// GET /api/fees/tiers — 30-day volume, current fee tier and projected
+/// next tier change per exchange
+pub async fn get_fee_tiers(state: web::Data<Arc<AppState>>) -> HttpResponse {
     let trades = state.trades.lock().await;
-    HttpResponse::Ok().json(trades.clone())
+    let statuses = arb_core::fees::project_fee_tiers(&trades, chrono::Utc::now());
+    HttpResponse::Ok().json(statuses)
+}
+
+/// GET /api/fx/rates — cached FX rates with staleness
+pub async fn get_fx_rates(state: web::Data<Arc<AppState>>) -> HttpResponse {
+    HttpResponse::Ok().json(state.fx.snapshot())
+}
+
+/// GET /api/cost-model — current execution cost model parameters
+pub async fn get_cost_model(state: web::Data<Arc<AppState>>) -> HttpResponse {
+    HttpResponse::Ok().json(state.cost_model.current())
+}
+
+/// Manual cost model override (or release with `clear: true`)
+#[derive(serde::Deserialize)]
+pub struct CostModelOverride {
+    pub slippage_bps: Option<f64>,
+    pub impact_bps_per_qty: Option<f64>,
+    #[serde(default)]
+    pub clear: bool,
+}
+
+/// POST /api/cost-model — pin or release a manual override
+pub async fn update_cost_model(
+    req: HttpRequest,
+    state: web::Data<Arc<AppState>>,
+    body: web::Json<CostModelOverride>,
+) -> HttpResponse {
+    let actor = actor_from_request(&req);
+    if body.clear {
+        state
+            .record_action(actor, "cost_model_clear_override", serde_json::json!({}))
+            .await;
+        state.cost_model.clear_override();
+        return HttpResponse::Ok().json(state.cost_model.current());
+    }
+
+    let current = state.cost_model.current();
+    let slippage = body
+        .slippage_bps
+        .and_then(rust_decimal::Decimal::from_f64_retain)
+        .unwrap_or(current.slippage_bps);
+    let impact = body
+        .impact_bps_per_qty
+        .and_then(rust_decimal::Decimal::from_f64_retain)
+        .unwrap_or(current.impact_bps_per_qty);
+
+    state
+        .record_action(
+            actor,
+            "cost_model_override",
+            serde_json::json!({
+                "slippage_bps": body.slippage_bps,
+                "impact_bps_per_qty": body.impact_bps_per_qty,
+            }),
+        )
+        .await;
+    state.cost_model.set_override(slippage, impact);
+    HttpResponse::Ok().json(state.cost_model.current())
+}
+
+/// GET /api/funding — latest funding-rate arbitrage evaluations
+pub async fn get_funding(
+    monitor: web::Data<Arc<arb_core::funding::FundingArbMonitor>>,
+) -> HttpResponse {
+    HttpResponse::Ok().json(monitor.snapshot())
+}
+
+/// GET /api/sla — rolling per-venue latency percentiles and health scores
+pub async fn get_venue_sla(sla: web::Data<Arc<arb_core::sla::VenueSla>>) -> HttpResponse {
+    HttpResponse::Ok().json(sla.snapshot())
+}
+
+/// GET /api/reference/prices — cached external reference prices with
+/// staleness
+pub async fn get_reference_prices(
+    reference: web::Data<Arc<arb_core::reference::ReferencePriceCache>>,
+) -> HttpResponse {
+    HttpResponse::Ok().json(reference.snapshot())
+}
+
+/// GET /api/orders — orders the bot has placed; `?open=true` restricts to
+/// orders still working on the exchange
+pub async fn get_orders(
+    req: HttpRequest,
+    orders: web::Data<Arc<arb_core::orders::OrderTracker>>,
+) -> HttpResponse {
+    let open_only = req.query_string().contains("open=true");
+    if open_only {
+        HttpResponse::Ok().json(orders.open())
+    } else {
+        HttpResponse::Ok().json(orders.all())
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CancelOrderRequest {
+    pub exchange: arb_core::types::Exchange,
+    /// Pair string as in config, e.g. "BTC/USDT"
+    pub pair: String,
+    pub order_id: String,
+}
+
+/// POST /api/orders/cancel — cancel an open order on its exchange
+pub async fn cancel_order(
+    req: HttpRequest,
+    orders: web::Data<Arc<arb_core::orders::OrderTracker>>,
+    connectors: web::Data<Vec<Arc<dyn arb_core::exchange::ExchangeConnector>>>,
+    state: web::Data<Arc<AppState>>,
+    body: web::Json<CancelOrderRequest>,
+) -> HttpResponse {
+    let Some(pair) = arb_core::types::TradingPair::parse(&body.pair) else {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": format!("invalid pair '{}'", body.pair),
+        }));
+    };
+
+    let Some(connector) = connectors.iter().find(|c| c.exchange() == body.exchange) else {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": format!("no connector for {}", body.exchange),
+        }));
+    };
+
+    state
+        .record_action(
+            actor_from_request(&req),
+            "order_cancel",
+            serde_json::json!({
+                "exchange": body.exchange,
+                "pair": body.pair,
+                "order_id": body.order_id,
+            }),
+        )
+        .await;
+
+    match connector.cancel_order(&pair, &body.order_id).await {
+        Ok(()) => {
+            orders.mark_cancelled(body.exchange, &body.order_id);
+            HttpResponse::Ok().json(serde_json::json!({
+                "status": "cancelled",
+                "order_id": body.order_id,
+            }))
+        }
+        Err(e) => HttpResponse::BadGateway().json(serde_json::json!({
+            "error": e.to_string(),
+        })),
+    }
+}
+
+/// How recently the executor loop must have stamped its heartbeat to
+/// count as alive, seconds
+const EXECUTOR_ALIVE_SECS: i64 = 10;
+
+/// GET /healthz — process liveness, for orchestrators
+pub async fn healthz() -> HttpResponse {
+    HttpResponse::Ok().body("ok")
+}
+
+/// GET /readyz — ready only when every enabled venue has produced a
+/// ticker within its staleness window and the executor loop is alive;
+/// 503 otherwise, so an orchestrator restarts the bot when a feed
+/// silently dies
+pub async fn readyz(
+    state: web::Data<Arc<AppState>>,
+    executor: web::Data<Arc<arb_core::executor::OrderExecutor>>,
+) -> HttpResponse {
+    let config = state.config.read().await;
+    let now = chrono::Utc::now();
+    let mut feeds = serde_json::Map::new();
+    let mut ready = true;
+
+    for exchange in [
+        arb_core::types::Exchange::Bybit,
+        arb_core::types::Exchange::Bitget,
+    ] {
+        let Some(cfg) = config.get_exchange(&exchange).filter(|c| c.enabled) else {
+            continue;
+        };
+        let age_secs = state
+            .prices
+            .all()
+            .into_iter()
+            .filter(|t| t.exchange == exchange)
+            .map(|t| t.timestamp)
+            .max()
+            .map(|latest| (now - latest).num_seconds());
+        let fresh = age_secs
+            .map(|age| age <= cfg.ws_stale_secs as i64)
+            .unwrap_or(false);
+        ready &= fresh;
+        feeds.insert(
+            exchange.to_string(),
+            serde_json::json!({ "fresh": fresh, "age_secs": age_secs }),
+        );
+    }
+
+    let executor_alive =
+        now.timestamp_millis() - executor.last_alive_ms() <= EXECUTOR_ALIVE_SECS * 1_000;
+    ready &= executor_alive;
+
+    let body = serde_json::json!({
+        "ready": ready,
+        "executor_alive": executor_alive,
+        "feeds": feeds,
+    });
+    if ready {
+        HttpResponse::Ok().json(body)
+    } else {
+        HttpResponse::ServiceUnavailable().json(body)
+    }
+}
+
+/// GET /api/exchanges — per-venue connectivity (state, last ticker,
+/// reconnect count)
+pub async fn get_exchanges(
+    registry: web::Data<Arc<arb_core::health::ExchangeHealthRegistry>>,
+) -> HttpResponse {
+    HttpResponse::Ok().json(registry.snapshot())
+}
+
+#[derive(Deserialize)]
+pub struct EventsQuery {
+    /// Only events with an id greater than this (cursor for polling)
+    since_id: Option<u64>,
+    limit: Option<usize>,
+}
+
+/// GET /api/events — recent system events (connector drops, risk-limit
+/// hits, breaker trips, config changes), newest first
+pub async fn get_events(
+    state: web::Data<Arc<AppState>>,
+    query: web::Query<EventsQuery>,
+) -> HttpResponse {
+    let limit = query.limit.unwrap_or(100).min(1_000);
+    HttpResponse::Ok().json(state.notifier.event_log().recent(limit, query.since_id))
+}
+
+/// GET /api/heartbeat — liveness + leadership, polled by a standby peer
+pub async fn get_heartbeat(state: web::Data<Arc<AppState>>) -> HttpResponse {
+    HttpResponse::Ok().json(serde_json::json!({
+        "instance_id": state.instance_id,
+        "leader": state
+            .execution_enabled
+            .load(std::sync::atomic::Ordering::Relaxed),
+        "uptime_secs": state.start_time.elapsed().as_secs(),
+        "ts": chrono::Utc::now(),
+    }))
+}
+
+/// Query parameters for GET /api/trades
+#[derive(serde::Deserialize)]
+pub struct TradesQuery {
+    /// RFC 3339 bounds on `executed_at`
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    pub until: Option<chrono::DateTime<chrono::Utc>>,
+    pub pair: Option<String>,
+    /// Matches either leg's venue, e.g. "bybit"
+    pub exchange: Option<String>,
+    /// Trade status, e.g. "filled" or "partialfill"
+    pub status: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+}
+
+/// GET /api/trades — paged trade history, newest first, filterable by
+/// time range, pair, exchange and status. Serves from the SQLite store
+/// when enabled (covering past restarts), this run's in-memory window
+/// otherwise. The envelope carries the total match count so clients can
+/// page through weeks of history.
+pub async fn get_trades(
+    state: web::Data<Arc<AppState>>,
+    query: web::Query<TradesQuery>,
+) -> HttpResponse {
+    if query.since.is_some() && !state.store.enabled() {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "historical queries need [store] enabled in config"
+        }));
+    }
+    let mut trades = if state.store.enabled() {
+        let since = query
+            .since
+            .unwrap_or(chrono::DateTime::<chrono::Utc>::MIN_UTC);
+        state.store.trades_since(since, query.pair.as_deref(), 100_000)
+    } else {
+        state.trades.lock().await.clone()
+    };
+
+    let until = query.until.unwrap_or_else(chrono::Utc::now);
+    trades.retain(|t| t.executed_at <= until);
+    if let Some(since) = query.since {
+        trades.retain(|t| t.executed_at >= since);
+    }
+    if let Some(pair) = &query.pair {
+        trades.retain(|t| t.pair.to_string().eq_ignore_ascii_case(pair));
+    }
+    if let Some(exchange) = &query.exchange {
+        trades.retain(|t| {
+            t.buy_exchange.to_string().eq_ignore_ascii_case(exchange)
+                || t.sell_exchange.to_string().eq_ignore_ascii_case(exchange)
+        });
+    }
+    if let Some(status) = &query.status {
+        trades.retain(|t| {
+            serde_json::to_value(&t.status)
+                .ok()
+                .and_then(|v| v.as_str().map(|s| s.eq_ignore_ascii_case(status)))
+                .unwrap_or(false)
+        });
+    }
+
+    trades.sort_by_key(|t| std::cmp::Reverse(t.executed_at));
+    let total = trades.len();
+    let limit = query.limit.unwrap_or(1_000).min(10_000);
+    let offset = query.offset.unwrap_or(0);
+    let page: Vec<_> = trades.into_iter().skip(offset).take(limit).collect();
+
+    HttpResponse::Ok().json(serde_json::json!({
+        "total": total,
+        "limit": limit,
+        "offset": offset,
+        "trades": page,
+    }))
+}
+
+/// Query parameters for GET /api/stats
+#[derive(serde::Deserialize)]
+pub struct StatsQuery {
+    /// RFC 3339 bounds; default is everything available
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// GET /api/stats — aggregate trade statistics over a time window: win
+/// rate, average/median realized spread, PnL by pair and by exchange
+/// route, trade count per day and the largest win/loss. Serves from the
+/// SQLite store when enabled, this run's in-memory history otherwise.
+pub async fn get_stats(
+    state: web::Data<Arc<AppState>>,
+    query: web::Query<StatsQuery>,
+) -> HttpResponse {
+    use rust_decimal::Decimal;
+
+    let mut trades = if state.store.enabled() {
+        let since = query
+            .from
+            .unwrap_or(chrono::DateTime::<chrono::Utc>::MIN_UTC);
+        state.store.trades_since(since, None, 100_000)
+    } else {
+        state.trades.lock().await.clone()
+    };
+    let to = query.to.unwrap_or_else(chrono::Utc::now);
+    trades.retain(|t| t.executed_at <= to);
+    if let Some(from) = query.from {
+        trades.retain(|t| t.executed_at >= from);
+    }
+
+    let count = trades.len();
+    let wins = trades.iter().filter(|t| t.net_profit > Decimal::ZERO).count();
+    let win_rate = if count > 0 {
+        Some(wins as f64 / count as f64)
+    } else {
+        None
+    };
+
+    // Realized spread per trade, from the booked fill prices
+    let mut spreads: Vec<Decimal> = trades
+        .iter()
+        .filter(|t| t.buy_price > Decimal::ZERO)
+        .map(|t| (t.sell_price - t.buy_price) / t.buy_price * Decimal::ONE_HUNDRED)
+        .collect();
+    spreads.sort();
+    let avg_spread_pct = if spreads.is_empty() {
+        None
+    } else {
+        Some(spreads.iter().sum::<Decimal>() / Decimal::from(spreads.len()))
+    };
+    let median_spread_pct = spreads.get(spreads.len() / 2).copied();
+
+    let profit_of = |t: &arb_core::types::TradeResult| {
+        t.net_profit_reporting.unwrap_or(t.net_profit)
+    };
+    let mut pnl_by_pair: std::collections::BTreeMap<String, Decimal> = Default::default();
+    let mut pnl_by_route: std::collections::BTreeMap<String, Decimal> = Default::default();
+    let mut trades_per_day: std::collections::BTreeMap<String, usize> = Default::default();
+    for t in &trades {
+        *pnl_by_pair.entry(t.pair.to_string()).or_default() += profit_of(t);
+        let route = format!("{}->{}", t.buy_exchange, t.sell_exchange);
+        *pnl_by_route.entry(route).or_default() += profit_of(t);
+        *trades_per_day
+            .entry(t.executed_at.format("%Y-%m-%d").to_string())
+            .or_default() += 1;
+    }
+
+    let largest = |best: bool| {
+        trades
+            .iter()
+            .max_by_key(|t| if best { profit_of(t) } else { -profit_of(t) })
+            .map(|t| {
+                serde_json::json!({
+                    "trade_id": t.id,
+                    "pair": t.pair.to_string(),
+                    "net_profit": profit_of(t),
+                    "executed_at": t.executed_at,
+                })
+            })
+    };
+
+    HttpResponse::Ok().json(serde_json::json!({
+        "from": query.from,
+        "to": to,
+        "trade_count": count,
+        "win_rate": win_rate,
+        "total_net_profit": trades.iter().map(profit_of).sum::<Decimal>(),
+        "avg_spread_pct": avg_spread_pct,
+        "median_spread_pct": median_spread_pct,
+        "pnl_by_pair": pnl_by_pair,
+        "pnl_by_exchange_route": pnl_by_route,
+        "trades_per_day": trades_per_day,
+        "largest_win": largest(true),
+        "largest_loss": largest(false),
+    }))
+}
+
+/// Query parameters for GET /api/trades/export
+#[derive(serde::Deserialize)]
+pub struct ExportQuery {
+    /// Only "csv" is supported today
+    pub format: Option<String>,
+    /// RFC 3339 bounds; default is everything available
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// GET /api/trades/export?format=csv&from=...&to=... — trade history as
+/// CSV with every profit/fee field, for spreadsheets and tax tooling.
+/// Reads the SQLite store when enabled (covering past restarts), this
+/// run's in-memory history otherwise.
+pub async fn export_trades(
+    state: web::Data<Arc<AppState>>,
+    query: web::Query<ExportQuery>,
+) -> HttpResponse {
+    if let Some(format) = query.format.as_deref() {
+        if format != "csv" {
+            return HttpResponse::BadRequest().json(serde_json::json!({
+                "error": format!("unsupported format '{}', only csv is available", format)
+            }));
+        }
+    }
+    let from = query.from.unwrap_or(chrono::DateTime::<chrono::Utc>::MIN_UTC);
+    let to = query.to.unwrap_or_else(chrono::Utc::now);
+
+    let mut trades = if state.store.enabled() {
+        state.store.trades_since(from, None, 100_000)
+    } else {
+        state.trades.lock().await.clone()
+    };
+    trades.retain(|t| t.executed_at >= from && t.executed_at <= to);
+
+    let mut csv = String::from(
+        "id,opportunity_id,strategy,pair,buy_exchange,sell_exchange,buy_price,sell_price,\
+         quantity,gross_profit,fees,net_profit,net_profit_reporting,status,executed_at\n",
+    );
+    for t in &trades {
+        let status = serde_json::to_value(&t.status)
+            .ok()
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_else(|| format!("{:?}", t.status));
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
+            t.id,
+            t.opportunity_id,
+            t.strategy,
+            t.pair,
+            t.buy_exchange,
+            t.sell_exchange,
+            t.buy_price,
+            t.sell_price,
+            t.quantity,
+            t.gross_profit,
+            t.fees,
+            t.net_profit,
+            t.net_profit_reporting
+                .map(|p| p.to_string())
+                .unwrap_or_default(),
+            status,
+            t.executed_at.to_rfc3339()
+        ));
+    }
+
+    HttpResponse::Ok()
+        .content_type("text/csv; charset=utf-8")
+        .insert_header((
+            "Content-Disposition",
+            "attachment; filename=\"trades.csv\"",
+        ))
+        .body(csv)
+}
+
+/// GET /api/account-events — recent deposits, withdrawals and external trades
+pub async fn get_account_events(state: web::Data<Arc<AppState>>) -> HttpResponse {
+    let events = state.account_events.lock().await;
+    let list: Vec<_> = events.iter().cloned().collect();
+    HttpResponse::Ok().json(list)
 }
 
 /// GET /api/status — engine status
@@ -29,39 +652,530 @@ pub async fn get_status(state: web::Data<Arc<AppState>>) -> HttpResponse {
     HttpResponse::Ok().json(status)
 }
 
-/// GET /api/portfolio — balances across all exchanges
-pub async fn get_portfolio(state: web::Data<Arc<AppState>>) -> HttpResponse {
-    // In a real implementation, this would query each exchange connector
-    // For now, return simulated balances
+/// Balances for the portfolio endpoints: nominal figures in simulation
+/// mode, otherwise the connectors' balances through the short-lived cache.
+/// Returns (balances, per-exchange errors, simulated).
+async fn portfolio_balances(
+    state: &web::Data<Arc<AppState>>,
+    connectors: &web::Data<Vec<Arc<dyn arb_core::exchange::ExchangeConnector>>>,
+    portfolio: &web::Data<Arc<arb_core::portfolio::PortfolioCache>>,
+) -> (
+    Vec<arb_core::types::ExchangeBalance>,
+    Vec<arb_core::portfolio::PortfolioError>,
+    bool,
+) {
     let config = state.config.read().await;
-    let mut balances = Vec::new();
-
-    for (name, cfg) in &config.exchanges {
-        if cfg.enabled {
-            let exchange = match name.as_str() {
-                "bybit" => arb_core::types::Exchange::Bybit,
-                "bitget" => arb_core::types::Exchange::Bitget,
-                _ => continue,
+    if config.engine.simulation_mode {
+        let mut balances = Vec::new();
+        for (name, cfg) in &config.exchanges {
+            if cfg.enabled {
+                let exchange = match name.as_str() {
+                    "bybit" => arb_core::types::Exchange::Bybit,
+                    "bitget" => arb_core::types::Exchange::Bitget,
+                    _ => continue,
+                };
+
+                balances.push(arb_core::types::ExchangeBalance {
+                    exchange,
+                    asset: "BTC".to_string(),
+                    free: rust_decimal::Decimal::new(5, 2), // 0.05 BTC
+                    locked: rust_decimal::Decimal::ZERO,
+                    total: rust_decimal::Decimal::new(5, 2),
+                });
+                balances.push(arb_core::types::ExchangeBalance {
+                    exchange,
+                    asset: "USDT".to_string(),
+                    free: rust_decimal::Decimal::new(5000, 0),
+                    locked: rust_decimal::Decimal::ZERO,
+                    total: rust_decimal::Decimal::new(5000, 0),
+                });
+            }
+        }
+        return (balances, Vec::new(), true);
+    }
+    drop(config);
+
+    let snapshot = portfolio.snapshot(connectors.get_ref()).await;
+    (snapshot.balances, snapshot.errors, false)
+}
+
+/// GET /api/portfolio — balances across all exchanges, with per-exchange
+/// errors alongside
+pub async fn get_portfolio(
+    state: web::Data<Arc<AppState>>,
+    connectors: web::Data<Vec<Arc<dyn arb_core::exchange::ExchangeConnector>>>,
+    portfolio: web::Data<Arc<arb_core::portfolio::PortfolioCache>>,
+) -> HttpResponse {
+    let (balances, errors, simulated) = portfolio_balances(&state, &connectors, &portfolio).await;
+    HttpResponse::Ok().json(serde_json::json!({
+        "balances": balances,
+        "errors": errors,
+        "simulated": simulated,
+    }))
+}
+
+/// GET /api/portfolio/value — balances valued in the reporting currency
+/// via current tickers (FX fallback), per exchange and per asset, with
+/// totals. Assets with no conversion path are listed unpriced.
+pub async fn get_portfolio_value(
+    state: web::Data<Arc<AppState>>,
+    connectors: web::Data<Vec<Arc<dyn arb_core::exchange::ExchangeConnector>>>,
+    portfolio: web::Data<Arc<arb_core::portfolio::PortfolioCache>>,
+) -> HttpResponse {
+    let (balances, errors, simulated) = portfolio_balances(&state, &connectors, &portfolio).await;
+    let reporting = state
+        .config
+        .read()
+        .await
+        .trading
+        .reporting_currency
+        .clone();
+
+    let mut total = rust_decimal::Decimal::ZERO;
+    let mut by_exchange: std::collections::BTreeMap<String, rust_decimal::Decimal> =
+        std::collections::BTreeMap::new();
+    let mut assets = Vec::new();
+    let mut unpriced = Vec::new();
+    for balance in &balances {
+        let value =
+            state
+                .fx
+                .quote_to_reporting(&state.prices, balance.total, &balance.asset, &reporting);
+        match value {
+            Some(value) => {
+                total += value;
+                *by_exchange
+                    .entry(balance.exchange.to_string())
+                    .or_default() += value;
+                assets.push(serde_json::json!({
+                    "exchange": balance.exchange,
+                    "asset": balance.asset,
+                    "total": balance.total,
+                    "value": value,
+                }));
+            }
+            None => unpriced.push(serde_json::json!({
+                "exchange": balance.exchange,
+                "asset": balance.asset,
+                "total": balance.total,
+            })),
+        }
+    }
+
+    HttpResponse::Ok().json(serde_json::json!({
+        "reporting_currency": reporting,
+        "total": total,
+        "by_exchange": by_exchange,
+        "assets": assets,
+        "unpriced": unpriced,
+        "errors": errors,
+        "simulated": simulated,
+    }))
+}
+
+/// GET /api/pnl — per-day realized PnL with fees per exchange, plus open
+/// inventory marked to market for the unrealized side
+pub async fn get_pnl(
+    state: web::Data<Arc<AppState>>,
+    positions: web::Data<Arc<arb_core::positions::PositionTracker>>,
+    query: web::Query<StatsQuery>,
+) -> HttpResponse {
+    let mut trades = if state.store.enabled() {
+        let since = query
+            .from
+            .unwrap_or(chrono::DateTime::<chrono::Utc>::MIN_UTC);
+        state.store.trades_since(since, None, 100_000)
+    } else {
+        state.trades.lock().await.clone()
+    };
+    let to = query.to.unwrap_or_else(chrono::Utc::now);
+    trades.retain(|t| t.executed_at <= to);
+    if let Some(from) = query.from {
+        trades.retain(|t| t.executed_at >= from);
+    }
+
+    let reporting = state
+        .config
+        .read()
+        .await
+        .trading
+        .reporting_currency
+        .clone();
+    let calculator = arb_core::pnl::PnlCalculator::new(
+        state.prices.clone(),
+        state.fx.clone(),
+        positions.get_ref().clone(),
+    );
+    HttpResponse::Ok().json(calculator.report(&trades, &reporting))
+}
+
+/// GET /api/positions — tracked per-asset, per-exchange inventory and
+/// the cross-venue skew per asset
+pub async fn get_positions(
+    positions: web::Data<Arc<arb_core::positions::PositionTracker>>,
+) -> HttpResponse {
+    HttpResponse::Ok().json(serde_json::json!({
+        "positions": positions.snapshot(),
+        "skews": positions.skews(),
+    }))
+}
+
+/// GET /api/rebalance/events — the rebalancer's planned and executed
+/// transfers, newest last
+pub async fn get_rebalance_events(
+    rebalancer: web::Data<Arc<arb_core::rebalance::Rebalancer>>,
+) -> HttpResponse {
+    HttpResponse::Ok().json(rebalancer.events().await)
+}
+
+#[derive(Deserialize)]
+pub struct AuditQuery {
+    /// Most recent records to return (default 100)
+    pub limit: Option<usize>,
+}
+
+/// GET /api/audit — the most recent order request/response audit records,
+/// oldest first
+pub async fn get_audit(
+    audit: web::Data<Arc<arb_core::audit::OrderAuditLog>>,
+    query: web::Query<AuditQuery>,
+) -> HttpResponse {
+    let limit = query.limit.unwrap_or(100).min(1_000);
+    HttpResponse::Ok().json(audit.recent(limit).await)
+}
+
+#[derive(Deserialize)]
+pub struct SimulateRequest {
+    pub pair: String,
+    pub buy_exchange: arb_core::types::Exchange,
+    pub sell_exchange: arb_core::types::Exchange,
+    pub quantity: rust_decimal::Decimal,
+}
+
+/// Walk book levels accumulating up to `qty`, returning (filled, VWAP)
+fn walk_levels(
+    levels: &[arb_core::types::OrderBookLevel],
+    qty: rust_decimal::Decimal,
+) -> Option<(rust_decimal::Decimal, rust_decimal::Decimal)> {
+    use rust_decimal::Decimal;
+    let mut filled = Decimal::ZERO;
+    let mut notional = Decimal::ZERO;
+    for level in levels {
+        if filled >= qty {
+            break;
+        }
+        let take = level.qty.min(qty - filled);
+        filled += take;
+        notional += take * level.price;
+    }
+    if filled <= Decimal::ZERO {
+        return None;
+    }
+    Some((filled, notional / filled))
+}
+
+/// POST /api/simulate — walk both venues' current order books for a
+/// hypothetical buy/sell of the given size, returning expected fill
+/// prices, fees, slippage and net profit without placing any orders
+pub async fn simulate_execution(
+    connectors: web::Data<Vec<Arc<dyn arb_core::exchange::ExchangeConnector>>>,
+    body: web::Json<SimulateRequest>,
+) -> HttpResponse {
+    use rust_decimal::Decimal;
+
+    let Some(pair) = arb_core::types::TradingPair::parse(&body.pair) else {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": format!("invalid pair '{}'", body.pair),
+        }));
+    };
+    if body.quantity <= Decimal::ZERO {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "quantity must be positive",
+        }));
+    }
+    let Some(buy_connector) = connectors.iter().find(|c| c.exchange() == body.buy_exchange)
+    else {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": format!("no connector for {}", body.buy_exchange),
+        }));
+    };
+    let Some(sell_connector) = connectors.iter().find(|c| c.exchange() == body.sell_exchange)
+    else {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": format!("no connector for {}", body.sell_exchange),
+        }));
+    };
+
+    let (buy_book, sell_book) = tokio::join!(
+        buy_connector.get_order_book(&pair, 50),
+        sell_connector.get_order_book(&pair, 50),
+    );
+    let (buy_book, sell_book) = match (buy_book, sell_book) {
+        (Ok(b), Ok(s)) => (b, s),
+        (Err(e), _) | (_, Err(e)) => {
+            return HttpResponse::BadGateway().json(serde_json::json!({
+                "error": format!("could not fetch order books: {}", e),
+            }));
+        }
+    };
+
+    // First pass finds how much each book can absorb; the second re-walks
+    // both to the common fillable quantity so the VWAPs line up
+    let (Some((buy_filled, _)), Some((sell_filled, _))) = (
+        walk_levels(&buy_book.asks, body.quantity),
+        walk_levels(&sell_book.bids, body.quantity),
+    ) else {
+        return HttpResponse::BadGateway().json(serde_json::json!({
+            "error": "order book empty on one side",
+        }));
+    };
+    let filled_qty = buy_filled.min(sell_filled);
+    let (Some((_, buy_vwap)), Some((_, sell_vwap))) = (
+        walk_levels(&buy_book.asks, filled_qty),
+        walk_levels(&sell_book.bids, filled_qty),
+    ) else {
+        return HttpResponse::BadGateway().json(serde_json::json!({
+            "error": "order book empty on one side",
+        }));
+    };
+
+    let best_ask = buy_book.asks.first().map(|l| l.price).unwrap_or(buy_vwap);
+    let best_bid = sell_book.bids.first().map(|l| l.price).unwrap_or(sell_vwap);
+    let buy_slippage_bps = if best_ask > Decimal::ZERO {
+        (buy_vwap - best_ask) / best_ask * Decimal::new(10_000, 0)
+    } else {
+        Decimal::ZERO
+    };
+    let sell_slippage_bps = if best_bid > Decimal::ZERO {
+        (best_bid - sell_vwap) / best_bid * Decimal::new(10_000, 0)
+    } else {
+        Decimal::ZERO
+    };
+
+    let buy_fee_pct = buy_connector.fee_pct();
+    let sell_fee_pct = sell_connector.fee_pct();
+    let hundred = Decimal::new(100, 0);
+    let fees = filled_qty * buy_vwap * (buy_fee_pct / hundred)
+        + filled_qty * sell_vwap * (sell_fee_pct / hundred);
+    let gross_profit = filled_qty * (sell_vwap - buy_vwap);
+
+    HttpResponse::Ok().json(serde_json::json!({
+        "pair": body.pair,
+        "buy_exchange": body.buy_exchange,
+        "sell_exchange": body.sell_exchange,
+        "requested_qty": body.quantity,
+        "fillable_qty": filled_qty,
+        "buy_fill_price": buy_vwap,
+        "sell_fill_price": sell_vwap,
+        "buy_slippage_bps": buy_slippage_bps.round_dp(2),
+        "sell_slippage_bps": sell_slippage_bps.round_dp(2),
+        "fees": fees,
+        "gross_profit": gross_profit,
+        "net_profit": gross_profit - fees,
+    }))
+}
+
+/// GET /api/discrepancies — recent reconciliation mismatches between
+/// local trade records and exchange order history
+pub async fn get_discrepancies(state: web::Data<Arc<AppState>>) -> HttpResponse {
+    let discrepancies = state.discrepancies.lock().await;
+    HttpResponse::Ok().json(discrepancies.iter().collect::<Vec<_>>())
+}
+
+/// GET /api/risk — current daily loss against the limit and time until
+/// the accounting window resets
+pub async fn get_risk(
+    state: web::Data<Arc<AppState>>,
+    executor: web::Data<Arc<arb_core::OrderExecutor>>,
+) -> HttpResponse {
+    let config = state.config.read().await;
+    HttpResponse::Ok().json(serde_json::json!({
+        "daily_loss": executor.get_daily_loss().await,
+        "max_daily_loss": config.risk.max_daily_loss,
+        "seconds_until_reset": executor.seconds_until_loss_reset(),
+        "daily_reset_hour_utc": config.risk.daily_reset_hour_utc,
+        "circuit_breaker_open": executor.breaker_state().await,
+        "killed": executor.is_killed(),
+    }))
+}
+
+/// POST /api/risk/circuit-breaker/reset — close an open circuit breaker
+/// and resume trading
+pub async fn reset_circuit_breaker(
+    req: HttpRequest,
+    state: web::Data<Arc<AppState>>,
+    executor: web::Data<Arc<arb_core::OrderExecutor>>,
+) -> HttpResponse {
+    state
+        .record_action(
+            actor_from_request(&req),
+            "circuit_breaker_reset",
+            serde_json::json!({}),
+        )
+        .await;
+    executor.reset_breaker().await;
+    HttpResponse::Ok().json(serde_json::json!({ "status": "reset" }))
+}
+
+/// POST /api/engine/kill — halt trading immediately and cancel every
+/// open order on every venue. Trading stays down until /api/engine/rearm.
+pub async fn engine_kill(
+    req: HttpRequest,
+    state: web::Data<Arc<AppState>>,
+    executor: web::Data<Arc<arb_core::OrderExecutor>>,
+    connectors: web::Data<Vec<Arc<dyn arb_core::exchange::ExchangeConnector>>>,
+    orders: web::Data<Arc<arb_core::orders::OrderTracker>>,
+) -> HttpResponse {
+    state
+        .record_action(actor_from_request(&req), "engine_kill", serde_json::json!({}))
+        .await;
+    executor.kill();
+
+    // Cancel whatever is still working on the venues, per configured pair
+    let pairs: Vec<arb_core::types::TradingPair> = {
+        let config = state.config.read().await;
+        let mut names = config.trading.pairs.clone();
+        names.extend(config.stablecoin.pairs.iter().cloned());
+        names
+            .iter()
+            .filter_map(|p| arb_core::types::TradingPair::parse(p))
+            .collect()
+    };
+
+    let mut cancelled = Vec::new();
+    let mut errors = Vec::new();
+    for connector in connectors.iter() {
+        for pair in &pairs {
+            let open = match connector.get_open_orders(pair).await {
+                Ok(open) => open,
+                Err(e) => {
+                    errors.push(serde_json::json!({
+                        "exchange": connector.exchange(),
+                        "pair": pair.to_string(),
+                        "error": e.to_string(),
+                    }));
+                    continue;
+                }
             };
+            for order in open {
+                match connector.cancel_order(pair, &order.id).await {
+                    Ok(()) => {
+                        orders.mark_cancelled(connector.exchange(), &order.id);
+                        cancelled.push(serde_json::json!({
+                            "exchange": connector.exchange(),
+                            "order_id": order.id,
+                        }));
+                    }
+                    Err(e) => errors.push(serde_json::json!({
+                        "exchange": connector.exchange(),
+                        "order_id": order.id,
+                        "error": e.to_string(),
+                    })),
+                }
+            }
+        }
+    }
 
-            balances.push(arb_core::types::ExchangeBalance {
-                exchange,
-                asset: "BTC".to_string(),
-                free: rust_decimal::Decimal::new(5, 2), // 0.05 BTC
-                locked: rust_decimal::Decimal::ZERO,
-                total: rust_decimal::Decimal::new(5, 2),
-            });
-            balances.push(arb_core::types::ExchangeBalance {
-                exchange,
-                asset: "USDT".to_string(),
-                free: rust_decimal::Decimal::new(5000, 0),
-                locked: rust_decimal::Decimal::ZERO,
-                total: rust_decimal::Decimal::new(5000, 0),
-            });
+    HttpResponse::Ok().json(serde_json::json!({
+        "status": "killed",
+        "cancelled": cancelled,
+        "errors": errors,
+    }))
+}
+
+/// POST /api/engine/rearm — disengage the kill switch so trading can
+/// resume
+pub async fn engine_rearm(
+    req: HttpRequest,
+    state: web::Data<Arc<AppState>>,
+    executor: web::Data<Arc<arb_core::OrderExecutor>>,
+) -> HttpResponse {
+    state
+        .record_action(
+            actor_from_request(&req),
+            "engine_rearm",
+            serde_json::json!({}),
+        )
+        .await;
+    executor.rearm();
+    HttpResponse::Ok().json(serde_json::json!({ "status": "armed" }))
+}
+
+/// Engine lifecycle: flip the running/paused flags that gate the
+/// detector and executor. `action` is one of start/stop/pause/resume.
+async fn set_engine_state(
+    req: &HttpRequest,
+    state: &web::Data<Arc<AppState>>,
+    action: &str,
+) -> HttpResponse {
+    use std::sync::atomic::Ordering;
+    match action {
+        "start" => {
+            state.engine_running.store(true, Ordering::Relaxed);
+            state.engine_paused.store(false, Ordering::Relaxed);
         }
+        "stop" => {
+            state.engine_running.store(false, Ordering::Relaxed);
+            state.engine_paused.store(true, Ordering::Relaxed);
+        }
+        "pause" => state.engine_paused.store(true, Ordering::Relaxed),
+        "resume" => state.engine_paused.store(false, Ordering::Relaxed),
+        _ => unreachable!(),
     }
+    state
+        .record_action(
+            actor_from_request(req),
+            &format!("engine_{}", action),
+            serde_json::json!({}),
+        )
+        .await;
+    HttpResponse::Ok().json(serde_json::json!({
+        "status": action,
+        "running": state.engine_running.load(Ordering::Relaxed),
+        "paused": state.engine_paused.load(Ordering::Relaxed),
+    }))
+}
 
-    HttpResponse::Ok().json(balances)
+/// POST /api/engine/start
+pub async fn engine_start(req: HttpRequest, state: web::Data<Arc<AppState>>) -> HttpResponse {
+    set_engine_state(&req, &state, "start").await
+}
+
+/// POST /api/engine/stop
+pub async fn engine_stop(req: HttpRequest, state: web::Data<Arc<AppState>>) -> HttpResponse {
+    set_engine_state(&req, &state, "stop").await
+}
+
+/// POST /api/engine/pause
+pub async fn engine_pause(req: HttpRequest, state: web::Data<Arc<AppState>>) -> HttpResponse {
+    set_engine_state(&req, &state, "pause").await
+}
+
+/// POST /api/engine/resume
+pub async fn engine_resume(req: HttpRequest, state: web::Data<Arc<AppState>>) -> HttpResponse {
+    set_engine_state(&req, &state, "resume").await
+}
+
+/// Identify the acting operator from request credentials. Until API
+/// authentication lands, this is the (masked) bearer token, or "anonymous".
+pub fn actor_from_request(req: &HttpRequest) -> String {
+    match req
+        .headers()
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim_start_matches("Bearer ").trim())
+    {
+        Some(token) if token.len() > 4 => format!("token:{}...", &token[..4]),
+        Some(_) => "token".to_string(),
+        None => "anonymous".to_string(),
+    }
+}
+
+/// GET /api/audit/actions — journal of manual operator actions
+pub async fn get_audit_actions(state: web::Data<Arc<AppState>>) -> HttpResponse {
+    let log = state.audit_log.lock().await;
+    let list: Vec<_> = log.iter().cloned().collect();
+    HttpResponse::Ok().json(list)
 }
 
 #[derive(Deserialize)]
@@ -70,15 +1184,102 @@ pub struct ConfigUpdate {
     pub max_trade_qty: Option<f64>,
     pub simulation_mode: Option<bool>,
     pub scan_interval_ms: Option<u64>,
+    /// Risk limits
+    pub max_position: Option<f64>,
+    pub max_daily_loss: Option<f64>,
+    pub trade_cooldown_ms: Option<u64>,
+    /// "market" or "limit"
+    pub order_type: Option<String>,
+    /// Full replacement pair list, e.g. ["BTC/USDT", "ETH/USDT"]
+    pub pairs: Option<Vec<String>>,
+    /// Second-step token required when turning simulation_mode off
+    pub confirm_token: Option<String>,
 }
 
 /// POST /api/config — update engine configuration
 pub async fn update_config(
+    req: HttpRequest,
     state: web::Data<Arc<AppState>>,
+    executor: web::Data<Arc<arb_core::OrderExecutor>>,
     body: web::Json<ConfigUpdate>,
 ) -> HttpResponse {
+    // Validate before touching anything, so a bad request changes nothing
+    let reject = |reason: &str| {
+        HttpResponse::BadRequest().json(serde_json::json!({ "error": reason }))
+    };
+    if let Some(order_type) = &body.order_type {
+        if order_type != "market" && order_type != "limit" {
+            return reject("order_type must be \"market\" or \"limit\"");
+        }
+    }
+    if let Some(pairs) = &body.pairs {
+        if pairs.is_empty() {
+            return reject("pairs must not be empty");
+        }
+        for pair in pairs {
+            if arb_core::types::TradingPair::parse(pair).is_none() {
+                return reject(&format!("unparseable pair '{}'", pair));
+            }
+        }
+    }
+    if body.max_position.map(|v| v <= 0.0).unwrap_or(false) {
+        return reject("max_position must be positive");
+    }
+    if body.max_daily_loss.map(|v| v <= 0.0).unwrap_or(false) {
+        return reject("max_daily_loss must be positive");
+    }
+
+    let params = serde_json::json!({
+        "min_spread_pct": body.min_spread_pct,
+        "max_trade_qty": body.max_trade_qty,
+        "simulation_mode": body.simulation_mode,
+        "scan_interval_ms": body.scan_interval_ms,
+        "max_position": body.max_position,
+        "max_daily_loss": body.max_daily_loss,
+        "trade_cooldown_ms": body.trade_cooldown_ms,
+        "order_type": body.order_type,
+        "pairs": body.pairs,
+    });
+    state
+        .record_action(actor_from_request(&req), "config_update", params.clone())
+        .await;
+
+    // Only mention the fields the caller actually sent
+    let changed: Vec<String> = params
+        .as_object()
+        .map(|o| {
+            o.iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect()
+        })
+        .unwrap_or_default();
+    state.notifier.notify(
+        arb_core::notify::NotificationKind::ConfigChange,
+        "Config updated",
+        changed.join(" "),
+    );
+
     let mut config = state.config.write().await;
 
+    if let Some(max_position) = body.max_position {
+        config.risk.max_position = rust_decimal::Decimal::from_f64_retain(max_position)
+            .unwrap_or(config.risk.max_position);
+    }
+    if let Some(max_daily_loss) = body.max_daily_loss {
+        config.risk.max_daily_loss = rust_decimal::Decimal::from_f64_retain(max_daily_loss)
+            .unwrap_or(config.risk.max_daily_loss);
+    }
+    if let Some(cooldown) = body.trade_cooldown_ms {
+        config.risk.trade_cooldown_ms = cooldown;
+    }
+    if let Some(order_type) = &body.order_type {
+        config.trading.order_type = order_type.clone();
+    }
+    if let Some(pairs) = &body.pairs {
+        config.trading.pairs = pairs.clone();
+    }
+
     if let Some(spread) = body.min_spread_pct {
         config.engine.min_spread_pct = rust_decimal::Decimal::from_f64_retain(spread)
             .unwrap_or(config.engine.min_spread_pct);
@@ -88,12 +1289,51 @@ pub async fn update_config(
             .unwrap_or(config.trading.max_trade_qty);
     }
     if let Some(sim) = body.simulation_mode {
-        config.engine.simulation_mode = sim;
+        if !sim && config.engine.simulation_mode {
+            // Going live is a two-step operation: the first call issues a
+            // token, the second call must present it
+            let mut pending = state.live_confirm.lock().await;
+            let confirmed = matches!(
+                (&*pending, &body.confirm_token),
+                (Some((token, issued)), Some(presented))
+                    if token == presented && issued.elapsed().as_secs() < 60
+            );
+            if confirmed {
+                *pending = None;
+                config.engine.simulation_mode = false;
+                executor.set_simulation_mode(false);
+                tracing::error!(
+                    "simulation_mode disabled via API by {} — LIVE TRADING",
+                    actor_from_request(&req)
+                );
+                state
+                    .record_action(
+                        actor_from_request(&req),
+                        "live_trading_enabled",
+                        serde_json::json!({}),
+                    )
+                    .await;
+            } else {
+                let token = uuid::Uuid::new_v4().to_string();
+                *pending = Some((token.clone(), std::time::Instant::now()));
+                return HttpResponse::Accepted().json(serde_json::json!({
+                    "status": "confirmation_required",
+                    "message": "disabling simulation_mode sends real orders; repeat the call with this confirm_token within 60 seconds",
+                    "confirm_token": token,
+                }));
+            }
+        } else {
+            config.engine.simulation_mode = sim;
+            executor.set_simulation_mode(sim);
+        }
     }
     if let Some(interval) = body.scan_interval_ms {
         config.engine.scan_interval_ms = interval;
     }
 
+    // Push the new snapshot to the detector, executor and filters
+    let _ = state.config_tx.send(config.clone());
+
     HttpResponse::Ok().json(serde_json::json!({
         "status": "updated",
         "config": {
@@ -101,6 +1341,11 @@ pub async fn update_config(
             "max_trade_qty": config.trading.max_trade_qty.to_string(),
             "simulation_mode": config.engine.simulation_mode,
             "scan_interval_ms": config.engine.scan_interval_ms,
+            "max_position": config.risk.max_position.to_string(),
+            "max_daily_loss": config.risk.max_daily_loss.to_string(),
+            "trade_cooldown_ms": config.risk.trade_cooldown_ms,
+            "order_type": config.trading.order_type,
+            "pairs": config.trading.pairs,
         }
     }))
 }
@@ -110,10 +1355,54 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/api")
             .route("/prices", web::get().to(get_prices))
+            .route("/debug/prices", web::get().to(get_debug_prices))
             .route("/opportunities", web::get().to(get_opportunities))
             .route("/trades", web::get().to(get_trades))
+            .route("/trades/export", web::get().to(export_trades))
+            .route("/stats", web::get().to(get_stats))
+            .route("/fees/tiers", web::get().to(get_fee_tiers))
+            .route("/fx/rates", web::get().to(get_fx_rates))
+            .route("/cost-model", web::get().to(get_cost_model))
+            .route("/cost-model", web::post().to(update_cost_model))
+            .route("/funding", web::get().to(get_funding))
+            .route("/sla", web::get().to(get_venue_sla))
+            .route("/exchanges", web::get().to(get_exchanges))
+            .route("/events", web::get().to(get_events))
+            .route("/candles", web::get().to(get_candles))
+            .route("/spreads", web::get().to(get_spread_pairs))
+            .route("/spreads/{pair:.*}", web::get().to(get_spread_series))
+            .route("/reference/prices", web::get().to(get_reference_prices))
+            .route("/orders", web::get().to(get_orders))
+            .route("/orders/cancel", web::post().to(cancel_order))
+            .route("/account-events", web::get().to(get_account_events))
             .route("/status", web::get().to(get_status))
             .route("/portfolio", web::get().to(get_portfolio))
-            .route("/config", web::post().to(update_config)),
+            .route("/portfolio/value", web::get().to(get_portfolio_value))
+            .route("/positions", web::get().to(get_positions))
+            .route("/pnl", web::get().to(get_pnl))
+            .route("/rebalance/events", web::get().to(get_rebalance_events))
+            .route("/simulate", web::post().to(simulate_execution))
+            .route("/audit", web::get().to(get_audit))
+            .route("/discrepancies", web::get().to(get_discrepancies))
+            .route("/risk", web::get().to(get_risk))
+            .route(
+                "/risk/circuit-breaker/reset",
+                web::post().to(reset_circuit_breaker),
+            )
+            .route("/engine/start", web::post().to(engine_start))
+            .route("/engine/stop", web::post().to(engine_stop))
+            .route("/engine/pause", web::post().to(engine_pause))
+            .route("/engine/resume", web::post().to(engine_resume))
+            .route("/engine/kill", web::post().to(engine_kill))
+            .route("/engine/rearm", web::post().to(engine_rearm))
+            .route("/config", web::post().to(update_config))
+            .route("/audit/actions", web::get().to(get_audit_actions))
+            .route("/heartbeat", web::get().to(get_heartbeat))
+            .route("/fleet/status", web::get().to(crate::fleet::fleet_status))
+            .route("/fleet/trades", web::get().to(crate::fleet::fleet_trades))
+            .route("/fleet/portfolio", web::get().to(crate::fleet::fleet_portfolio)),
     );
+    // Orchestrator probes live at the root, outside /api
+    cfg.route("/healthz", web::get().to(healthz))
+        .route("/readyz", web::get().to(readyz));
 }
@@ -1,7 +1,9 @@
+use arb_core::costmodel::CostModel;
+use arb_core::fx::FxRateCache;
 use arb_core::types::*;
-use arb_core::Config;
-use dashmap::DashMap;
+use arb_core::{Config, PriceCache};
 use std::collections::VecDeque;
+use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::Instant;
 use tokio::sync::{Mutex, RwLock};
@@ -9,31 +11,102 @@ use tokio::sync::{Mutex, RwLock};
 /// Shared application state accessible from all API handlers
 pub struct AppState {
     pub config: RwLock<Config>,
-    pub prices: DashMap<(Exchange, String), Ticker>,
+    /// Broadcasts config snapshots to the detector, executor and filters
+    /// after API updates
+    pub config_tx: tokio::sync::watch::Sender<Config>,
+    /// Latest prices — the same cache the detector writes (owned by core)
+    pub prices: Arc<PriceCache>,
+    /// FX rates for non-USD quote normalization
+    pub fx: Arc<FxRateCache>,
+    /// Calibrated execution cost model (shared with the detector)
+    pub cost_model: Arc<CostModel>,
     pub opportunities: Mutex<VecDeque<ArbitrageOpportunity>>,
     pub trades: Mutex<Vec<TradeResult>>,
+    /// Recent account-level events (deposits, withdrawals, external trades)
+    pub account_events: Mutex<VecDeque<AccountEvent>>,
+    /// Recent reconciliation discrepancies
+    pub discrepancies: Mutex<VecDeque<ReconcileDiscrepancy>>,
+    /// Journal of manual operator actions (config changes, pause, kill, …)
+    pub audit_log: Mutex<VecDeque<OperatorAction>>,
+    audit_seq: AtomicU64,
     pub engine_running: AtomicBool,
+    /// Operator pause: detector and executor idle while set
+    pub engine_paused: Arc<AtomicBool>,
+    /// Whether this instance currently holds execution leadership
+    pub execution_enabled: Arc<AtomicBool>,
+    /// Stable identifier for this process (failover heartbeats)
+    pub instance_id: String,
+    /// False if any exchange API compatibility probe failed at startup
+    pub compat_probes_ok: AtomicBool,
     pub start_time: Instant,
     pub opportunities_count: AtomicU64,
     pub trades_count: AtomicU64,
     pub total_profit: Mutex<rust_decimal::Decimal>,
     /// WebSocket broadcast: list of senders for connected UI clients
     pub ws_clients: Mutex<Vec<tokio::sync::mpsc::UnboundedSender<String>>>,
+    /// Pending confirmation token for switching simulation_mode off
+    /// (token, issued-at) — live trading needs a second, confirmed call
+    pub live_confirm: Mutex<Option<(String, Instant)>>,
+    /// Optional SQLite persistence of trades and opportunities
+    pub store: Arc<arb_core::store::SqliteStore>,
+    /// Optional Redis mirror for external dashboards and read replicas
+    pub mirror: Arc<arb_core::mirror::RedisMirror>,
+    /// Optional NATS/Kafka event publishing
+    pub events: Arc<arb_core::events::EventBus>,
+    /// Optional webhook notifications
+    pub notifier: Arc<arb_core::notify::Notifier>,
+    /// Rolling per-venue latency tracker, snapshotted into EngineStatus
+    pub venue_sla: Arc<arb_core::sla::VenueSla>,
 }
 
 impl AppState {
-    pub fn new(config: Config) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        config: Config,
+        prices: Arc<PriceCache>,
+        fx: Arc<FxRateCache>,
+        cost_model: Arc<CostModel>,
+        execution_enabled: Arc<AtomicBool>,
+        engine_paused: Arc<AtomicBool>,
+        config_tx: tokio::sync::watch::Sender<Config>,
+        store: Arc<arb_core::store::SqliteStore>,
+        mirror: Arc<arb_core::mirror::RedisMirror>,
+        events: Arc<arb_core::events::EventBus>,
+        notifier: Arc<arb_core::notify::Notifier>,
+        venue_sla: Arc<arb_core::sla::VenueSla>,
+    ) -> Self {
         Self {
             config: RwLock::new(config),
-            prices: DashMap::new(),
+            config_tx,
+            prices,
+            fx,
+            cost_model,
             opportunities: Mutex::new(VecDeque::with_capacity(1000)),
             trades: Mutex::new(Vec::new()),
+            account_events: Mutex::new(VecDeque::with_capacity(1000)),
+            discrepancies: Mutex::new(VecDeque::new()),
+            audit_log: Mutex::new(VecDeque::with_capacity(1000)),
+            audit_seq: AtomicU64::new(0),
             engine_running: AtomicBool::new(false),
+            engine_paused,
+            execution_enabled,
+            instance_id: format!(
+                "arbiter-{}-{}",
+                std::process::id(),
+                chrono::Utc::now().timestamp()
+            ),
+            compat_probes_ok: AtomicBool::new(true),
             start_time: Instant::now(),
             opportunities_count: AtomicU64::new(0),
             trades_count: AtomicU64::new(0),
             total_profit: Mutex::new(rust_decimal::Decimal::ZERO),
             ws_clients: Mutex::new(Vec::new()),
+            live_confirm: Mutex::new(None),
+            store,
+            mirror,
+            events,
+            notifier,
+            venue_sla,
         }
     }
 
@@ -48,17 +121,28 @@ impl AppState {
         clients.retain(|tx| tx.send(json.clone()).is_ok());
     }
 
-    /// Add a price update
-    pub async fn update_price(&self, ticker: Ticker) {
-        let key = (ticker.exchange, ticker.pair.to_string());
-        self.prices.insert(key, ticker.clone());
-        self.broadcast(&WsMessage::Ticker(ticker)).await;
-    }
-
     /// Add a new opportunity
     pub async fn add_opportunity(&self, opp: ArbitrageOpportunity) {
         self.opportunities_count.fetch_add(1, Ordering::Relaxed);
         self.broadcast(&WsMessage::Opportunity(opp.clone())).await;
+        self.store.record_opportunity(&opp);
+        self.mirror.record_opportunity(&opp).await;
+        self.events.publish_opportunity(&opp);
+
+        // Telegram/webhook push for opportunities clearing the configured
+        // profit bar (0 disables)
+        let bar = self.config.read().await.telegram.min_opportunity_profit;
+        let profit = opp.potential_profit_reporting.unwrap_or(opp.potential_profit);
+        if bar > rust_decimal::Decimal::ZERO && profit >= bar {
+            self.notifier.notify(
+                arb_core::notify::NotificationKind::Opportunity,
+                &format!("Opportunity on {}", opp.pair),
+                format!(
+                    "{} {}→{}: spread {}%, est. profit {}",
+                    opp.pair, opp.buy_exchange, opp.sell_exchange, opp.net_spread_pct, profit
+                ),
+            );
+        }
 
         let mut opps = self.opportunities.lock().await;
         opps.push_back(opp);
@@ -73,9 +157,75 @@ impl AppState {
         self.trades_count.fetch_add(1, Ordering::Relaxed);
         *self.total_profit.lock().await += trade.net_profit;
         self.broadcast(&WsMessage::Trade(trade.clone())).await;
+        self.store.record_trade(&trade);
+        self.mirror.record_trade(&trade).await;
+        self.events.publish_trade(&trade);
+        self.notifier.notify(
+            arb_core::notify::NotificationKind::TradeExecuted,
+            "Trade executed",
+            format!(
+                "{} {} {}→{}: net {} ({:?})",
+                trade.quantity,
+                trade.pair,
+                trade.buy_exchange,
+                trade.sell_exchange,
+                trade.net_profit,
+                trade.status
+            ),
+        );
         self.trades.lock().await.push(trade);
     }
 
+    /// Add an account-level event
+    pub async fn add_account_event(&self, event: AccountEvent) {
+        self.broadcast(&WsMessage::AccountEvent(event.clone())).await;
+
+        let mut events = self.account_events.lock().await;
+        events.push_back(event);
+        // Keep only last 1000 events
+        while events.len() > 1000 {
+            events.pop_front();
+        }
+    }
+
+    /// Add a reconciliation discrepancy
+    pub async fn add_discrepancy(&self, discrepancy: ReconcileDiscrepancy) {
+        self.broadcast(&WsMessage::Discrepancy(discrepancy.clone()))
+            .await;
+
+        let mut discrepancies = self.discrepancies.lock().await;
+        discrepancies.push_back(discrepancy);
+        // Keep only last 1000 discrepancies
+        while discrepancies.len() > 1000 {
+            discrepancies.pop_front();
+        }
+    }
+
+    /// Record a manual operator action in the audit journal
+    pub async fn record_action(&self, actor: String, action: &str, params: serde_json::Value) {
+        let entry = OperatorAction {
+            id: self.audit_seq.fetch_add(1, Ordering::Relaxed) + 1,
+            actor,
+            action: action.to_string(),
+            params,
+            timestamp: chrono::Utc::now(),
+        };
+        tracing::info!(
+            "Operator action #{} by {}: {} {}",
+            entry.id,
+            entry.actor,
+            entry.action,
+            entry.params
+        );
+
+        let mut log = self.audit_log.lock().await;
+        log.push_back(entry);
+        // Keep only last 1000 actions until the persistent store lands
+        while log.len() > 1000 {
+            log.pop_front();
+        }
+    }
+
     /// Get engine status
     pub async fn get_status(&self) -> EngineStatus {
         let config = self.config.read().await;
@@ -94,20 +244,19 @@ impl AppState {
             .trading
             .pairs
             .iter()
-            .map(|p| {
-                let parts: Vec<&str> = p.split('/').collect();
-                TradingPair::new(parts[0], parts[1])
-            })
+            .filter_map(|p| TradingPair::parse(p))
             .collect();
 
         EngineStatus {
             running: self.engine_running.load(Ordering::Relaxed),
+            paused: self.engine_paused.load(Ordering::Relaxed),
             uptime_secs: self.start_time.elapsed().as_secs(),
             opportunities_found: self.opportunities_count.load(Ordering::Relaxed),
             trades_executed: self.trades_count.load(Ordering::Relaxed),
             total_profit: *self.total_profit.lock().await,
             active_exchanges,
             monitored_pairs,
+            latency: self.venue_sla.snapshot(),
         }
     }
 }
@@ -1,18 +1,59 @@
-use actix_web::{web, HttpRequest, HttpResponse};
+use actix_web::{web, HttpMessage, HttpRequest, HttpResponse};
 use actix_ws::Message;
 use futures_util::StreamExt;
+use rust_decimal::Decimal;
+use serde::Deserialize;
 use std::sync::Arc;
 use tokio::sync::mpsc;
-use tracing::info;
+use tracing::{info, warn};
 
+use crate::auth::Role;
 use crate::state::AppState;
 
+/// Control message from a WS client, e.g.
+/// `{"op":"set_params","min_spread_pct":0.2,"max_trade_qty":0.005}`
+#[derive(Debug, Deserialize)]
+struct ControlMessage {
+    op: String,
+    min_spread_pct: Option<f64>,
+    max_trade_qty: Option<f64>,
+}
+
+/// Apply a strategy-parameter control message with immediate effect
+async fn apply_control(state: &AppState, ctrl: &ControlMessage) {
+    let mut config = state.config.write().await;
+    if let Some(spread) = ctrl.min_spread_pct {
+        config.engine.min_spread_pct =
+            Decimal::from_f64_retain(spread).unwrap_or(config.engine.min_spread_pct);
+    }
+    if let Some(qty) = ctrl.max_trade_qty {
+        config.trading.max_trade_qty =
+            Decimal::from_f64_retain(qty).unwrap_or(config.trading.max_trade_qty);
+    }
+    let _ = state.config_tx.send(config.clone());
+    drop(config);
+
+    state
+        .record_action(
+            "ws-client".to_string(),
+            "ws_set_params",
+            serde_json::json!({
+                "min_spread_pct": ctrl.min_spread_pct,
+                "max_trade_qty": ctrl.max_trade_qty,
+            }),
+        )
+        .await;
+}
+
 /// WebSocket handler — streams real-time data to UI clients
 pub async fn ws_handler(
     req: HttpRequest,
     stream: web::Payload,
     state: web::Data<Arc<AppState>>,
 ) -> Result<HttpResponse, actix_web::Error> {
+    // The auth middleware resolved the presented key's role during the
+    // upgrade; only operator connections may drive the control channel
+    let operator = req.extensions().get::<Role>().copied() == Some(Role::Operator);
     let (response, mut session, msg_stream) = actix_ws::handle(&req, stream)?;
 
     // Create a channel for this client
@@ -29,8 +70,7 @@ pub async fn ws_handler(
     }
 
     // Send current prices on connect
-    for entry in state.prices.iter() {
-        let ticker = entry.value().clone();
+    for ticker in state.prices.all() {
         if let Ok(json) = serde_json::to_string(&arb_core::types::WsMessage::Ticker(ticker)) {
             let _ = session.text(json).await;
         }
@@ -48,13 +88,51 @@ pub async fn ws_handler(
 
     // Handle incoming messages from client using actix_web::rt (not tokio::spawn)
     // actix_ws MessageStream is not Send, so we use actix_web::rt::spawn instead
+    let state_for_ctrl = state.clone();
     actix_web::rt::spawn(async move {
         let mut msg_stream = msg_stream;
         while let Some(Ok(msg)) = msg_stream.next().await {
             match msg {
-                Message::Ping(bytes) => {
-                    if session.pong(&bytes).await.is_err() {
-                        break;
+                Message::Ping(bytes)
+                    if session.pong(&bytes).await.is_err() =>
+                {
+                    break;
+                }
+                // Control channel: dashboards tune strategy parameters over
+                // the socket instead of a REST round trip per tweak
+                Message::Text(text) => {
+                    if let Ok(ctrl) = serde_json::from_str::<ControlMessage>(&text) {
+                        if ctrl.op == "set_params" {
+                            if !operator {
+                                warn!("WS set_params rejected: operator API key required");
+                                let denial = serde_json::json!({
+                                    "type": "error",
+                                    "data": {
+                                        "op": "set_params",
+                                        "error": "operator API key required",
+                                    }
+                                });
+                                if session.text(denial.to_string()).await.is_err() {
+                                    break;
+                                }
+                                continue;
+                            }
+                            apply_control(&state_for_ctrl, &ctrl).await;
+
+                            let config = state_for_ctrl.config.read().await;
+                            let ack = serde_json::json!({
+                                "type": "ack",
+                                "data": {
+                                    "op": "set_params",
+                                    "min_spread_pct": config.engine.min_spread_pct.to_string(),
+                                    "max_trade_qty": config.trading.max_trade_qty.to_string(),
+                                }
+                            });
+                            drop(config);
+                            if session.text(ack.to_string()).await.is_err() {
+                                break;
+                            }
+                        }
                     }
                 }
                 Message::Close(_) => {
@@ -0,0 +1,84 @@
+use chrono::{Duration as ChronoDuration, Utc};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+use tracing::{info, warn};
+
+use crate::exchange::ExchangeConnector;
+use crate::types::AccountEvent;
+
+/// Polls account-level events (deposits, withdrawals, manual trades) on each
+/// exchange, so activity outside the bot is reflected in inventory tracking
+/// and reconciliation instead of appearing as unexplained balance drift.
+pub struct AccountEventMonitor {
+    connectors: Vec<Arc<dyn ExchangeConnector>>,
+    /// Seconds between polls
+    poll_secs: u64,
+    /// Channel to publish deduplicated events
+    event_tx: mpsc::UnboundedSender<AccountEvent>,
+    /// Event ids already published (exchange record ids)
+    seen: Mutex<HashSet<String>>,
+}
+
+impl AccountEventMonitor {
+    pub fn new(
+        connectors: Vec<Arc<dyn ExchangeConnector>>,
+        poll_secs: u64,
+        event_tx: mpsc::UnboundedSender<AccountEvent>,
+    ) -> Self {
+        Self {
+            connectors,
+            poll_secs: poll_secs.max(5),
+            event_tx,
+            seen: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Poll each exchange forever, publishing events not seen before
+    pub async fn start(&self) {
+        info!(
+            "Account event monitor started (poll interval {}s)",
+            self.poll_secs
+        );
+
+        // Look back an hour on the first poll so recent activity is captured
+        let mut since = Utc::now() - ChronoDuration::hours(1);
+
+        loop {
+            let poll_started = Utc::now();
+
+            for connector in &self.connectors {
+                match connector.get_account_events(since).await {
+                    Ok(events) => {
+                        let mut seen = self.seen.lock().await;
+                        for event in events {
+                            if seen.insert(event.id.clone()) {
+                                info!(
+                                    "Account event on {}: {:?} {} {}",
+                                    event.exchange, event.kind, event.amount, event.asset
+                                );
+                                let _ = self.event_tx.send(event);
+                            }
+                        }
+                        // Keep the dedup set bounded
+                        if seen.len() > 10_000 {
+                            seen.clear();
+                        }
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Failed to fetch account events from {}: {}",
+                            connector.exchange(),
+                            e
+                        );
+                    }
+                }
+            }
+
+            // Overlap the next window so events near the boundary aren't
+            // missed; the seen-set filters the duplicates
+            since = poll_started - ChronoDuration::seconds(self.poll_secs as i64);
+            tokio::time::sleep(tokio::time::Duration::from_secs(self.poll_secs)).await;
+        }
+    }
+}
@@ -1,90 +1,249 @@
-use chrono::Utc;
+use chrono::{DateTime, Timelike, Utc};
 use dashmap::DashMap;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
+use serde::Serialize;
+use serde_json::json;
+use std::collections::VecDeque;
 use std::sync::Arc;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 use tracing::{debug, info};
-use uuid::Uuid;
 
+use crate::candles::{Candle, CandleAggregator};
 use crate::config::Config;
+use crate::costmodel::CostModel;
 use crate::exchange::ExchangeConnector;
+use crate::filter::{FilterChain, FilterStats};
+use crate::fx::FxRateCache;
+use crate::prices::PriceCache;
+use crate::sla::VenueSla;
+use crate::spreads::{SpreadPoint, SpreadRecorder};
+use crate::strategy::{self, Strategy};
 use crate::types::*;
 
+/// How stale a price cache entry can be (ms) before the detector considers
+/// it no longer fresh
+const PRICE_FRESHNESS_MS: i64 = 5_000;
+
+/// Levels per side to request when sizing an opportunity against depth
+const ORDER_BOOK_DEPTH: u32 = 50;
+
+/// Mid prices kept per (exchange, pair) for the volatility feature
+const MID_HISTORY_LEN: usize = 120;
+
+/// Per-entry update tracking for cache diagnostics
+#[derive(Debug, Clone)]
+struct PriceUpdateStats {
+    first_seen: DateTime<Utc>,
+    updates: u64,
+}
+
+/// Diagnostics for one entry in the detector's internal price cache,
+/// exposed via GET /api/debug/prices
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceCacheDiagnostics {
+    pub exchange: Exchange,
+    pub pair: String,
+    pub bid: Decimal,
+    pub ask: Decimal,
+    pub last_update: DateTime<Utc>,
+    pub age_ms: i64,
+    pub updates: u64,
+    pub updates_per_sec: f64,
+    /// Whether the detector still considers this entry usable
+    pub fresh: bool,
+}
+
 /// Maintains latest prices and detects cross-exchange arbitrage opportunities
 pub struct ArbitrageDetector {
-    /// Latest ticker for each (exchange, pair)
-    prices: Arc<DashMap<(Exchange, String), Ticker>>,
+    /// Latest ticker for each (exchange, pair) — shared with the API layer
+    prices: Arc<PriceCache>,
     /// Connectors for each exchange
     connectors: Vec<Arc<dyn ExchangeConnector>>,
-    /// Configuration
+    /// Startup configuration snapshot (subscription topology is fixed at
+    /// start; per-tick decisions read the live channel)
     config: Config,
+    /// Live configuration; updated through POST /api/config at runtime
+    config_rx: watch::Receiver<Config>,
     /// Channel to send detected opportunities
     opportunity_tx: mpsc::UnboundedSender<ArbitrageOpportunity>,
-    /// Channel to broadcast tickers to the API layer
-    ticker_tx: mpsc::UnboundedSender<Ticker>,
+    /// Registered detection strategies (from `engine.strategies`)
+    strategies: Vec<Arc<dyn Strategy>>,
+    /// Ordered opportunity filter pipeline (from `filters.chain`)
+    filters: Arc<FilterChain>,
+    /// Calibrated execution cost model, charged in the actionability math
+    cost_model: Arc<CostModel>,
+    /// FX rates for normalizing profits quoted in non-reporting currencies
+    fx: Arc<FxRateCache>,
+    /// Rolling venue latency tracking; its score discounts opportunity
+    /// confidence on chronically slow venues
+    sla: Arc<VenueSla>,
+    /// Per-entry update counters for cache diagnostics
+    update_stats: Arc<DashMap<(Exchange, String), PriceUpdateStats>>,
+    /// Recent mid prices per (exchange, pair), feeding opportunity context
+    mid_history: Arc<DashMap<(Exchange, String), VecDeque<f64>>>,
+    /// Rolling gross-spread samples per pair, feeding the adaptive
+    /// threshold mode of the "min_spread" filter
+    spread_history: Arc<DashMap<String, VecDeque<f64>>>,
+    /// Epoch ms of the last volatility-circuit breach per pair; present
+    /// while the pair's opportunity generation is suspended
+    circuit_tripped: Arc<DashMap<String, i64>>,
+    /// Continuous per-pair spread time series, for GET /api/spreads
+    spread_recorder: Arc<SpreadRecorder>,
+    /// OHLCV candles built from the ticker stream, for GET /api/candles
+    candles: Arc<CandleAggregator>,
+    /// Operator pause: tickers still update caches, but nothing is
+    /// evaluated for opportunities while set
+    paused: Arc<std::sync::atomic::AtomicBool>,
 }
 
 impl ArbitrageDetector {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         connectors: Vec<Arc<dyn ExchangeConnector>>,
-        config: Config,
+        config_rx: watch::Receiver<Config>,
         opportunity_tx: mpsc::UnboundedSender<ArbitrageOpportunity>,
-        ticker_tx: mpsc::UnboundedSender<Ticker>,
+        prices: Arc<PriceCache>,
+        cost_model: Arc<CostModel>,
+        fx: Arc<FxRateCache>,
+        sla: Arc<VenueSla>,
+        reference: Arc<crate::reference::ReferencePriceCache>,
+        paused: Arc<std::sync::atomic::AtomicBool>,
     ) -> Self {
+        let config = config_rx.borrow().clone();
+        let strategies = strategy::build_strategies(&config, &connectors);
+        info!(
+            "Detector strategies: [{}]",
+            strategies
+                .iter()
+                .map(|s| s.name())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        let spread_history = Arc::new(DashMap::new());
+        let candles = Arc::new(CandleAggregator::from_config(&config.candles));
+        let filters = Arc::new(FilterChain::from_config(
+            &config,
+            config_rx.clone(),
+            spread_history.clone(),
+            reference,
+        ));
         Self {
-            prices: Arc::new(DashMap::new()),
+            prices,
             connectors,
             config,
+            config_rx,
             opportunity_tx,
-            ticker_tx,
+            strategies,
+            filters,
+            cost_model,
+            fx,
+            sla,
+            update_stats: Arc::new(DashMap::new()),
+            mid_history: Arc::new(DashMap::new()),
+            spread_history,
+            circuit_tripped: Arc::new(DashMap::new()),
+            spread_recorder: Arc::new(SpreadRecorder::new()),
+            candles,
+            paused,
         }
     }
 
     /// Start monitoring all pairs across all exchanges
     pub async fn start(&self) {
-        let pairs: Vec<TradingPair> = self
-            .config
-            .trading
-            .pairs
+        let mut pair_names = self.config.trading.pairs.clone();
+        if self.config.stablecoin.enabled {
+            pair_names.extend(self.config.stablecoin.pairs.iter().cloned());
+        }
+        let mut pairs: Vec<TradingPair> = pair_names
             .iter()
-            .map(|p| {
-                let parts: Vec<&str> = p.split('/').collect();
-                TradingPair::new(parts[0], parts[1])
+            .filter_map(|p| {
+                let pair = TradingPair::parse(p);
+                if pair.is_none() {
+                    tracing::warn!("Ignoring unparseable pair '{}' in config", p);
+                }
+                pair
             })
             .collect();
+        pairs.dedup();
 
         for pair in &pairs {
             for connector in &self.connectors {
                 let exchange = connector.exchange();
+                if !self.config.pair_enabled_on(&exchange, pair) {
+                    info!("Skipping {} on {} (disabled_pairs)", pair, exchange);
+                    continue;
+                }
                 info!("Subscribing to {} on {}", pair, exchange);
 
                 match connector.subscribe_ticker(pair).await {
                     Ok(mut rx) => {
                         let prices = self.prices.clone();
+                        let update_stats = self.update_stats.clone();
                         let opp_tx = self.opportunity_tx.clone();
-                        let tick_tx = self.ticker_tx.clone();
-                        let config = self.config.clone();
+                        let config_rx = self.config_rx.clone();
                         let all_connectors = self.connectors.clone();
+                        let strategies = self.strategies.clone();
+                        let filters = self.filters.clone();
+                        let cost_model = self.cost_model.clone();
+                        let fx = self.fx.clone();
+                        let sla = self.sla.clone();
+                        let mid_history = self.mid_history.clone();
+                        let spread_history = self.spread_history.clone();
+                        let circuit_tripped = self.circuit_tripped.clone();
+                        let spread_recorder = self.spread_recorder.clone();
+                        let candles = self.candles.clone();
+                        let paused = self.paused.clone();
                         let pair_str = pair.to_string();
 
                         tokio::spawn(async move {
                             while let Some(ticker) = rx.recv().await {
-                                // Update latest price
+                                // Update the shared price cache (also fans the
+                                // ticker out to API subscribers)
                                 let key = (ticker.exchange, pair_str.clone());
-                                prices.insert(key, ticker.clone());
-
-                                // Broadcast ticker to API
-                                let _ = tick_tx.send(ticker.clone());
+                                if let Some(previous) = prices.get(ticker.exchange, &pair_str) {
+                                    let gap_ms = (ticker.timestamp - previous.timestamp)
+                                        .num_milliseconds();
+                                    sla.record_ws_gap(ticker.exchange, gap_ms as f64);
+                                }
+                                // Exchange event time vs our clock — the lag a
+                                // cross-venue strategy actually trades against
+                                let lag_ms =
+                                    (Utc::now() - ticker.timestamp).num_milliseconds();
+                                sla.record_ws_lag(ticker.exchange, lag_ms as f64);
+                                prices.insert(ticker.clone());
+                                update_stats
+                                    .entry(key)
+                                    .and_modify(|s| s.updates += 1)
+                                    .or_insert_with(|| PriceUpdateStats {
+                                        first_seen: Utc::now(),
+                                        updates: 1,
+                                    });
 
-                                // Check for arbitrage opportunities
-                                Self::check_opportunities(
+                                // Run every registered strategy over the update
+                                if paused.load(std::sync::atomic::Ordering::Relaxed) {
+                                    continue;
+                                }
+                                let config = config_rx.borrow().clone();
+                                Self::process_ticker(
                                     &prices,
                                     &ticker,
+                                    &strategies,
+                                    &filters,
                                     &all_connectors,
                                     &config,
+                                    &cost_model,
+                                    &fx,
+                                    &sla,
+                                    &mid_history,
+                                    &spread_history,
+                                    &circuit_tripped,
+                                    &spread_recorder,
+                                    &candles,
                                     &opp_tx,
-                                );
+                                )
+                                .await;
                             }
                         });
                     }
@@ -97,126 +256,691 @@ impl ArbitrageDetector {
                         );
                     }
                 }
+
+                // Degraded-mode fallback: poll the REST ticker whenever the
+                // WS feed has been silent past ws_stale_secs, so detection
+                // keeps running at reduced fidelity instead of going blind
+                // on the venue
+                if let Some(cfg) = self.config.get_exchange(&exchange) {
+                    if cfg.rest_poll_fallback {
+                        let connector = connector.clone();
+                        let all_connectors = self.connectors.clone();
+                        let prices = self.prices.clone();
+                        let strategies = self.strategies.clone();
+                        let filters = self.filters.clone();
+                        let config_rx = self.config_rx.clone();
+                        let cost_model = self.cost_model.clone();
+                        let fx = self.fx.clone();
+                        let sla = self.sla.clone();
+                        let mid_history = self.mid_history.clone();
+                        let spread_history = self.spread_history.clone();
+                        let circuit_tripped = self.circuit_tripped.clone();
+                        let spread_recorder = self.spread_recorder.clone();
+                        let candles = self.candles.clone();
+                        let paused = self.paused.clone();
+                        let opp_tx = self.opportunity_tx.clone();
+                        let pair = pair.clone();
+                        let poll_ms = cfg.rest_poll_ms.max(200);
+                        let stale_ms = (cfg.ws_stale_secs.max(5) * 1000) as i64;
+
+                        tokio::spawn(async move {
+                            let mut degraded = false;
+                            loop {
+                                tokio::time::sleep(std::time::Duration::from_millis(poll_ms))
+                                    .await;
+
+                                let last = connector.last_ws_message_ms();
+                                let ws_silent = last == 0
+                                    || Utc::now().timestamp_millis() - last > stale_ms;
+                                if !ws_silent {
+                                    if degraded {
+                                        info!(
+                                            "{} WS feed recovered — ending REST polling for {}",
+                                            exchange, pair
+                                        );
+                                        degraded = false;
+                                    }
+                                    continue;
+                                }
+                                if !degraded {
+                                    tracing::warn!(
+                                        "{} WS feed silent — REST-polling {} every {}ms",
+                                        exchange, pair, poll_ms
+                                    );
+                                    degraded = true;
+                                }
+
+                                match connector.get_ticker(&pair).await {
+                                    Ok(ticker) => {
+                                        prices.insert(ticker.clone());
+                                        if paused.load(std::sync::atomic::Ordering::Relaxed) {
+                                            continue;
+                                        }
+                                        let config = config_rx.borrow().clone();
+                                        Self::process_ticker(
+                                            &prices,
+                                            &ticker,
+                                            &strategies,
+                                            &filters,
+                                            &all_connectors,
+                                            &config,
+                                            &cost_model,
+                                            &fx,
+                                            &sla,
+                                            &mid_history,
+                                            &spread_history,
+                                            &circuit_tripped,
+                                            &spread_recorder,
+                                            &candles,
+                                            &opp_tx,
+                                        )
+                                        .await;
+                                    }
+                                    Err(e) => debug!(
+                                        "REST fallback poll failed for {} on {}: {}",
+                                        pair, exchange, e
+                                    ),
+                                }
+                            }
+                        });
+                    }
+                }
             }
         }
     }
 
-    /// Compare latest ticker against all other exchanges for arbitrage
-    fn check_opportunities(
-        prices: &DashMap<(Exchange, String), Ticker>,
+    /// Feed one ticker through the full detection pipeline — price cache,
+    /// strategies, sizing and filters — exactly as the live subscription
+    /// loops do per update. The backtester calls this directly when
+    /// replaying recorded data.
+    pub async fn ingest_ticker(&self, ticker: &Ticker) {
+        self.prices.insert(ticker.clone());
+        let config = self.config_rx.borrow().clone();
+        Self::process_ticker(
+            &self.prices,
+            ticker,
+            &self.strategies,
+            &self.filters,
+            &self.connectors,
+            &config,
+            &self.cost_model,
+            &self.fx,
+            &self.sla,
+            &self.mid_history,
+            &self.spread_history,
+            &self.circuit_tripped,
+            &self.spread_recorder,
+            &self.candles,
+            &self.opportunity_tx,
+        )
+        .await;
+    }
+
+    /// Feed one ticker update through every registered strategy, then
+    /// depth-size and forward whatever candidates come back
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(name = "process_ticker", skip_all, fields(exchange = %incoming.exchange, pair = %incoming.pair))]
+    async fn process_ticker(
+        prices: &PriceCache,
         incoming: &Ticker,
+        strategies: &[Arc<dyn Strategy>],
+        filters: &FilterChain,
         connectors: &[Arc<dyn ExchangeConnector>],
         config: &Config,
+        cost_model: &CostModel,
+        fx: &FxRateCache,
+        sla: &VenueSla,
+        mid_history: &DashMap<(Exchange, String), VecDeque<f64>>,
+        spread_history: &DashMap<String, VecDeque<f64>>,
+        circuit_tripped: &DashMap<String, i64>,
+        spread_recorder: &SpreadRecorder,
+        candles: &CandleAggregator,
         opp_tx: &mpsc::UnboundedSender<ArbitrageOpportunity>,
     ) {
-        let pair_str = incoming.pair.to_string();
-        let exchanges = [Exchange::Bybit, Exchange::Bitget];
+        if config.candles.enabled {
+            candles.update(incoming);
+        }
 
-        for other_exchange in &exchanges {
-            if *other_exchange == incoming.exchange {
-                continue;
+        if let Some(mid) = incoming.mid_price().to_f64() {
+            let mut history = mid_history
+                .entry((incoming.exchange, incoming.pair.to_string()))
+                .or_default();
+            history.push_back(mid);
+            while history.len() > MID_HISTORY_LEN {
+                history.pop_front();
             }
+        }
 
-            let key = (*other_exchange, pair_str.clone());
-            if let Some(other_ticker) = prices.get(&key) {
-                // Direction 1: Buy on incoming exchange, sell on other
-                Self::evaluate_spread(
-                    incoming,
-                    &other_ticker,
-                    connectors,
-                    config,
-                    opp_tx,
+        // Sample the pair's cross-venue spread (both directions against
+        // every other venue) into the continuous time series, and feed the
+        // rolling distribution behind the adaptive threshold mode
+        {
+            let pair_str = incoming.pair.to_string();
+            for other_exchange in [Exchange::Bybit, Exchange::Bitget] {
+                if other_exchange == incoming.exchange {
+                    continue;
+                }
+                let Some(other) = prices.get(other_exchange, &pair_str) else {
+                    continue;
+                };
+                for (ask, bid) in [(incoming.ask, other.bid), (other.ask, incoming.bid)] {
+                    if ask <= Decimal::ZERO {
+                        continue;
+                    }
+                    if let Some(spread) = ((bid - ask) / ask * dec!(100)).to_f64() {
+                        spread_recorder.record(&pair_str, spread);
+                        if config.adaptive_threshold.enabled {
+                            let mut history =
+                                spread_history.entry(pair_str.clone()).or_default();
+                            history.push_back(spread);
+                            while history.len() > config.adaptive_threshold.window.max(2) {
+                                history.pop_front();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Volatility circuit: fast markets are where one leg fails, so a
+        // pair whose short-horizon volatility spikes past the cap stops
+        // generating opportunities until it has cooled down
+        if config.volatility_circuit.enabled {
+            let pair_str = incoming.pair.to_string();
+            let now_ms = Utc::now().timestamp_millis();
+            let breached = mid_history
+                .get(&(incoming.exchange, pair_str.clone()))
+                .and_then(|history| Self::realized_volatility_pct(&history))
+                .map(|vol| vol > config.volatility_circuit.max_volatility_pct)
+                .unwrap_or(false);
+
+            if breached {
+                if circuit_tripped.insert(pair_str.clone(), now_ms).is_none() {
+                    tracing::warn!(
+                        "Volatility circuit tripped for {} on {} — suspending opportunity generation",
+                        pair_str, incoming.exchange
+                    );
+                }
+                return;
+            }
+            if let Some(last_breach) = circuit_tripped.get(&pair_str).map(|e| *e) {
+                let cooldown_ms = (config.volatility_circuit.cooldown_secs * 1000) as i64;
+                if now_ms - last_breach < cooldown_ms {
+                    return;
+                }
+                circuit_tripped.remove(&pair_str);
+                info!(
+                    "Volatility circuit reset for {} — resuming opportunity generation",
+                    pair_str
                 );
+            }
+        }
 
-                // Direction 2: Buy on other exchange, sell on incoming
-                Self::evaluate_spread(
-                    &other_ticker,
-                    incoming,
+        for strategy in strategies {
+            for mut candidate in strategy.on_ticker(incoming, prices) {
+                candidate.strategy = strategy.name().to_string();
+                Self::finalize_and_send(
+                    candidate,
+                    prices,
+                    filters,
                     connectors,
                     config,
+                    cost_model,
+                    fx,
+                    sla,
+                    mid_history,
                     opp_tx,
-                );
+                )
+                .await;
             }
         }
     }
 
-    /// Evaluate a specific buy/sell direction for profitability
-    fn evaluate_spread(
-        buy_ticker: &Ticker,   // We buy at the ask price here
-        sell_ticker: &Ticker,  // We sell at the bid price here
+    /// Size a candidate against actual depth and send it on. Falls back to
+    /// the strategy's top-of-book sizing if either snapshot is unavailable.
+    #[allow(clippy::too_many_arguments)]
+    async fn finalize_and_send(
+        mut opp: ArbitrageOpportunity,
+        prices: &PriceCache,
+        filters: &FilterChain,
         connectors: &[Arc<dyn ExchangeConnector>],
         config: &Config,
+        cost_model: &CostModel,
+        fx: &FxRateCache,
+        sla: &VenueSla,
+        mid_history: &DashMap<(Exchange, String), VecDeque<f64>>,
         opp_tx: &mpsc::UnboundedSender<ArbitrageOpportunity>,
     ) {
-        let buy_price = buy_ticker.ask;
-        let sell_price = sell_ticker.bid;
-
-        if buy_price <= Decimal::ZERO || sell_price <= Decimal::ZERO {
-            return;
-        }
-
-        // Gross spread percentage
-        let spread_pct = ((sell_price - buy_price) / buy_price) * dec!(100);
-
-        // Get fees for both exchanges
         let buy_fee = connectors
             .iter()
-            .find(|c| c.exchange() == buy_ticker.exchange)
+            .find(|c| c.exchange() == opp.buy_exchange)
             .map(|c| c.fee_pct())
             .unwrap_or(dec!(0.1));
-
         let sell_fee = connectors
             .iter()
-            .find(|c| c.exchange() == sell_ticker.exchange)
+            .find(|c| c.exchange() == opp.sell_exchange)
             .map(|c| c.fee_pct())
             .unwrap_or(dec!(0.1));
 
-        // Net spread after fees on both sides
-        let total_fees = buy_fee + sell_fee;
-        let net_spread_pct = spread_pct - total_fees;
-
-        // Only report if net spread exceeds minimum threshold
-        if net_spread_pct > config.engine.min_spread_pct {
-            let quantity = config.trading.max_trade_qty;
-            let potential_profit = quantity * (sell_price - buy_price)
-                - quantity * buy_price * (buy_fee / dec!(100))
-                - quantity * sell_price * (sell_fee / dec!(100));
-
-            let opportunity = ArbitrageOpportunity {
-                id: Uuid::new_v4().to_string(),
-                pair: buy_ticker.pair.clone(),
-                buy_exchange: buy_ticker.exchange,
-                sell_exchange: sell_ticker.exchange,
-                buy_price,
-                sell_price,
-                spread_pct,
-                net_spread_pct,
-                potential_profit,
-                quantity,
-                detected_at: Utc::now(),
-                is_actionable: net_spread_pct > dec!(0),
-            };
+        // Gather every sizing constraint, take the most restrictive, and
+        // record all of them so post-trade analysis can see which one
+        // usually binds
+        let books = Self::fetch_books(&opp, connectors).await;
+        let depth_qty = books.as_ref().and_then(|(buy_book, sell_book)| {
+            Self::vwap_walk(
+                &buy_book.asks,
+                &sell_book.bids,
+                buy_fee,
+                sell_fee,
+                Decimal::MAX,
+            )
+            .map(|(qty, _, _, _)| qty)
+        });
+        let balance_qty = Self::balance_limit(&opp, connectors, config).await;
+        let risk_headroom_qty = config.max_position_for(&opp.pair);
+        let impact_qty = cost_model.impact_limit_qty(opp.net_spread_pct * dec!(100));
 
+        let mut quantity = config.max_trade_qty_for(&opp.pair);
+        let mut binding = "max_trade_qty";
+        for (name, limit) in [
+            ("depth", depth_qty),
+            ("balance", balance_qty),
+            ("risk_headroom", Some(risk_headroom_qty)),
+            ("impact", impact_qty),
+        ] {
+            if let Some(limit) = limit {
+                if limit < quantity {
+                    quantity = limit;
+                    binding = name;
+                }
+            }
+        }
+
+        if quantity < config.min_trade_qty_for(&opp.pair) {
             debug!(
-                "Opportunity: Buy {} @ {} on {}, Sell @ {} on {} | Spread: {}% (net: {}%)",
-                opportunity.pair,
-                buy_price,
-                buy_ticker.exchange,
-                sell_price,
-                sell_ticker.exchange,
-                spread_pct.round_dp(4),
-                net_spread_pct.round_dp(4),
+                "Sized quantity {} below min_trade_qty for {} ({} bound), skipping",
+                quantity, opp.pair, binding
             );
+            return;
+        }
 
-            let _ = opp_tx.send(opportunity);
+        // Re-derive the spread from the VWAPs the chosen quantity realizes
+        if let Some((qty, buy_price, sell_price, potential_profit)) =
+            books.as_ref().and_then(|(buy_book, sell_book)| {
+                Self::vwap_walk(&buy_book.asks, &sell_book.bids, buy_fee, sell_fee, quantity)
+            })
+        {
+            quantity = qty;
+            opp.buy_price = buy_price;
+            opp.sell_price = sell_price;
+            opp.potential_profit = potential_profit;
+            opp.spread_pct = ((sell_price - buy_price) / buy_price) * dec!(100);
+            opp.net_spread_pct = opp.spread_pct - buy_fee - sell_fee;
         }
+        opp.quantity = quantity;
+        opp.size_constraints = Some(SizeConstraints {
+            depth_qty,
+            balance_qty,
+            risk_headroom_qty,
+            impact_qty,
+            chosen_qty: quantity,
+            binding: binding.to_string(),
+        });
+
+        // Charge the calibrated execution cost before deciding actionability
+        let cost_pct = cost_model.penalty_bps(opp.quantity) / dec!(100);
+        opp.net_spread_pct -= cost_pct;
+
+        // Amortize the expected rebalancing transfer cost across the
+        // trades between rebalances
+        let mut transfer_cost_pct = None;
+        if config.transfer_costs.enabled && opp.quantity > Decimal::ZERO {
+            let fee_base = config.transfer_cost_for(&opp.pair.base);
+            if fee_base > Decimal::ZERO {
+                let trades = Decimal::from(config.transfer_costs.trades_per_rebalance.max(1));
+                let transfer_pct = fee_base / (opp.quantity * trades) * dec!(100);
+                opp.net_spread_pct -= transfer_pct;
+                transfer_cost_pct = Some(transfer_pct);
+            }
+        }
+        opp.is_actionable = opp.net_spread_pct > dec!(0);
+
+        // Quotes sourced from degraded REST polling carry less conviction
+        // than a live feed — mark the opportunity down so downstream
+        // consumers can treat it accordingly
+        let degraded = [opp.buy_exchange, opp.sell_exchange].iter().any(|exchange| {
+            prices
+                .get(*exchange, &opp.pair.to_string())
+                .map(|t| t.source == TickerSource::RestPoll)
+                .unwrap_or(false)
+        });
+        if degraded {
+            opp.confidence = dec!(0.5);
+        }
+
+        opp.potential_profit_reporting = fx.quote_to_reporting(
+            prices,
+            opp.potential_profit,
+            &opp.pair.quote,
+            &config.trading.reporting_currency,
+        );
+        // Chronically slow venues get deprioritized: the worse leg's SLA
+        // score discounts confidence
+        let venue_score = sla.score(opp.buy_exchange).min(sla.score(opp.sell_exchange));
+        if venue_score < 1.0 {
+            opp.confidence *= Decimal::from_f64_retain(venue_score).unwrap_or(Decimal::ONE);
+        }
+
+        opp.context = Self::build_context(&opp, prices, books.as_ref(), mid_history);
+        opp.context
+            .insert("venue_score".to_string(), json!(venue_score));
+        if let Some(transfer_pct) = transfer_cost_pct {
+            opp.context.insert(
+                "transfer_cost_pct".to_string(),
+                json!(transfer_pct.to_string()),
+            );
+        }
+
+        // Run the configured filter pipeline last, once every field the
+        // filters may inspect is final
+        if let Some(rejected_by) = filters.check(&opp, prices) {
+            debug!(
+                "Opportunity {} on {} rejected by '{}' filter",
+                opp.id, opp.pair, rejected_by
+            );
+            return;
+        }
+
+        let _ = opp_tx.send(opp);
+    }
+
+    /// Assemble the opportunity's feature map for downstream ML consumers:
+    /// per-leg feed lag and recent realized volatility, depth and imbalance
+    /// within 5 bps of the touch, time-of-day bucket, and whether either
+    /// feed was degraded
+    fn build_context(
+        opp: &ArbitrageOpportunity,
+        prices: &PriceCache,
+        books: Option<&(OrderBook, OrderBook)>,
+        mid_history: &DashMap<(Exchange, String), VecDeque<f64>>,
+    ) -> serde_json::Map<String, serde_json::Value> {
+        let mut context = serde_json::Map::new();
+        let now = Utc::now();
+        let pair_str = opp.pair.to_string();
+
+        for (leg, exchange) in [("buy", opp.buy_exchange), ("sell", opp.sell_exchange)] {
+            if let Some(ticker) = prices.get(exchange, &pair_str) {
+                context.insert(
+                    format!("{}_feed_lag_ms", leg),
+                    json!((now - ticker.timestamp).num_milliseconds()),
+                );
+            }
+            if let Some(volatility) = mid_history
+                .get(&(exchange, pair_str.clone()))
+                .and_then(|history| Self::realized_volatility_pct(&history))
+            {
+                context.insert(format!("{}_volatility_pct", leg), json!(volatility));
+            }
+        }
+
+        if let Some((buy_book, sell_book)) = books {
+            let ask_depth = Self::depth_within_bps(&buy_book.asks, opp.buy_price, dec!(5));
+            let bid_depth = Self::depth_within_bps(&sell_book.bids, opp.sell_price, dec!(5));
+            context.insert("ask_depth_5bps".to_string(), json!(ask_depth.to_f64()));
+            context.insert("bid_depth_5bps".to_string(), json!(bid_depth.to_f64()));
+            let total = ask_depth + bid_depth;
+            if total > Decimal::ZERO {
+                context.insert(
+                    "imbalance_5bps".to_string(),
+                    json!(((bid_depth - ask_depth) / total).to_f64()),
+                );
+            }
+        }
+
+        context.insert("hour_utc".to_string(), json!(now.hour()));
+        context.insert("degraded_feed".to_string(), json!(opp.confidence < dec!(1)));
+        context
+    }
+
+    /// Standard deviation of per-tick mid returns over the history window,
+    /// in percent; None with fewer than two samples
+    fn realized_volatility_pct(history: &VecDeque<f64>) -> Option<f64> {
+        if history.len() < 2 {
+            return None;
+        }
+        let returns: Vec<f64> = history
+            .iter()
+            .zip(history.iter().skip(1))
+            .filter(|(prev, _)| **prev != 0.0)
+            .map(|(prev, next)| (next - prev) / prev)
+            .collect();
+        if returns.is_empty() {
+            return None;
+        }
+        let n = returns.len() as f64;
+        let mean = returns.iter().sum::<f64>() / n;
+        let var = returns.iter().map(|r| (r - mean) * (r - mean)).sum::<f64>() / n;
+        Some(var.sqrt() * 100.0)
+    }
+
+    /// Total quantity quoted within `bps` of a reference price on one side
+    fn depth_within_bps(levels: &[OrderBookLevel], reference: Decimal, bps: Decimal) -> Decimal {
+        if reference <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+        let band = reference * bps / dec!(10000);
+        levels
+            .iter()
+            .filter(|level| (level.price - reference).abs() <= band)
+            .map(|level| level.qty)
+            .sum()
+    }
+
+    /// Fetch both venues' order books for an opportunity, or None if either
+    /// snapshot is unavailable
+    async fn fetch_books(
+        opp: &ArbitrageOpportunity,
+        connectors: &[Arc<dyn ExchangeConnector>],
+    ) -> Option<(OrderBook, OrderBook)> {
+        let buy_connector = connectors
+            .iter()
+            .find(|c| c.exchange() == opp.buy_exchange)?;
+        let sell_connector = connectors
+            .iter()
+            .find(|c| c.exchange() == opp.sell_exchange)?;
+
+        let buy_book = buy_connector
+            .get_order_book(&opp.pair, ORDER_BOOK_DEPTH)
+            .await
+            .ok()?;
+        let sell_book = sell_connector
+            .get_order_book(&opp.pair, ORDER_BOOK_DEPTH)
+            .await
+            .ok()?;
+        Some((buy_book, sell_book))
+    }
+
+    /// Largest quantity current balances could fund after the configured
+    /// reserve haircut: quote balance on the buy venue, and base inventory
+    /// on the sell venue unless margin covers the short. None (unconstrained) in simulation mode or when a balance
+    /// fetch fails — better to size on the remaining constraints than to
+    /// drop the opportunity.
+    async fn balance_limit(
+        opp: &ArbitrageOpportunity,
+        connectors: &[Arc<dyn ExchangeConnector>],
+        config: &Config,
+    ) -> Option<Decimal> {
+        if config.engine.simulation_mode || opp.buy_price <= Decimal::ZERO {
+            return None;
+        }
+        let buy_connector = connectors
+            .iter()
+            .find(|c| c.exchange() == opp.buy_exchange)?;
+        let quote_free: Decimal = buy_connector
+            .get_balances()
+            .await
+            .ok()?
+            .iter()
+            .find(|b| b.asset == opp.pair.quote)
+            .map(|b| b.free)
+            .unwrap_or(Decimal::ZERO);
+        let usable = (dec!(100) - config.risk.balance_reserve_pct).max(Decimal::ZERO) / dec!(100);
+        let mut limit = quote_free * usable / opp.buy_price;
+
+        let sell_on_margin = config
+            .get_exchange(&opp.sell_exchange)
+            .map(|cfg| cfg.margin_enabled)
+            .unwrap_or(false);
+        if !sell_on_margin {
+            let sell_connector = connectors
+                .iter()
+                .find(|c| c.exchange() == opp.sell_exchange)?;
+            let base_free: Decimal = sell_connector
+                .get_balances()
+                .await
+                .ok()?
+                .iter()
+                .find(|b| b.asset == opp.pair.base)
+                .map(|b| b.free)
+                .unwrap_or(Decimal::ZERO);
+            limit = limit.min(base_free * usable);
+        }
+        Some(limit)
+    }
+
+    /// Walk the buy-side asks and sell-side bids level by level, accumulating
+    /// quantity while the marginal level pair is still profitable after fees,
+    /// capped at `max_qty`. Returns (quantity, buy VWAP, sell VWAP, net
+    /// profit in quote currency).
+    fn vwap_walk(
+        asks: &[OrderBookLevel],
+        bids: &[OrderBookLevel],
+        buy_fee: Decimal,
+        sell_fee: Decimal,
+        max_qty: Decimal,
+    ) -> Option<(Decimal, Decimal, Decimal, Decimal)> {
+        let buy_fee_mult = dec!(1) + buy_fee / dec!(100);
+        let sell_fee_mult = dec!(1) - sell_fee / dec!(100);
+
+        let mut quantity = Decimal::ZERO;
+        let mut cost = Decimal::ZERO; // quote spent buying
+        let mut proceeds = Decimal::ZERO; // quote received selling
+        let mut ai = 0;
+        let mut bi = 0;
+        let mut ask_remaining = asks.first().map(|l| l.qty)?;
+        let mut bid_remaining = bids.first().map(|l| l.qty)?;
+
+        while ai < asks.len() && bi < bids.len() && quantity < max_qty {
+            let ask = &asks[ai];
+            let bid = &bids[bi];
+
+            // The marginal unit must still be profitable after both fees
+            if bid.price * sell_fee_mult <= ask.price * buy_fee_mult {
+                break;
+            }
+
+            let take = ask_remaining.min(bid_remaining).min(max_qty - quantity);
+            quantity += take;
+            cost += take * ask.price;
+            proceeds += take * bid.price;
+
+            ask_remaining -= take;
+            bid_remaining -= take;
+            if ask_remaining <= Decimal::ZERO {
+                ai += 1;
+                if ai < asks.len() {
+                    ask_remaining = asks[ai].qty;
+                }
+            }
+            if bid_remaining <= Decimal::ZERO {
+                bi += 1;
+                if bi < bids.len() {
+                    bid_remaining = bids[bi].qty;
+                }
+            }
+        }
+
+        if quantity <= Decimal::ZERO {
+            return None;
+        }
+
+        let buy_vwap = cost / quantity;
+        let sell_vwap = proceeds / quantity;
+        let net_profit = proceeds * sell_fee_mult - cost * buy_fee_mult;
+        Some((quantity, buy_vwap, sell_vwap, net_profit))
+    }
+
+    /// Per-stage pass/reject totals for the filter pipeline (for API)
+    pub fn filter_stats(&self) -> Vec<FilterStats> {
+        self.filters.stats()
+    }
+
+    /// Recorded spread series for a pair within the trailing window,
+    /// downsampled to at most `max_points` (for GET /api/spreads)
+    pub fn spread_series(
+        &self,
+        pair: &str,
+        window_ms: i64,
+        max_points: usize,
+    ) -> Vec<SpreadPoint> {
+        self.spread_recorder.query(pair, window_ms, max_points)
+    }
+
+    /// Pairs with recorded spread samples (for GET /api/spreads)
+    pub fn spread_pairs(&self) -> Vec<String> {
+        self.spread_recorder.pairs()
+    }
+
+    /// Recent OHLCV candles for a series, oldest first (for GET /api/candles)
+    pub fn candles(
+        &self,
+        exchange: Exchange,
+        pair: &str,
+        interval_secs: u32,
+        limit: usize,
+    ) -> Vec<Candle> {
+        self.candles.candles(exchange, pair, interval_secs, limit)
     }
 
     /// Get all current prices (for API)
     pub fn get_prices(&self) -> Vec<Ticker> {
+        self.prices.all()
+    }
+
+    /// Diagnostics for the internal price cache — last update time, update
+    /// rate and freshness per (exchange, pair). The API's own price map can
+    /// diverge from this one, so debugging goes straight to the source.
+    pub fn diagnostics(&self) -> Vec<PriceCacheDiagnostics> {
+        let now = Utc::now();
         self.prices
+            .map()
             .iter()
-            .map(|entry| entry.value().clone())
+            .map(|entry| {
+                let (exchange, pair) = entry.key().clone();
+                let ticker = entry.value();
+                let age_ms = (now - ticker.timestamp).num_milliseconds();
+                let (updates, updates_per_sec) = self
+                    .update_stats
+                    .get(entry.key())
+                    .map(|s| {
+                        let elapsed_secs =
+                            (now - s.first_seen).num_milliseconds().max(1) as f64 / 1000.0;
+                        (s.updates, s.updates as f64 / elapsed_secs)
+                    })
+                    .unwrap_or((0, 0.0));
+
+                PriceCacheDiagnostics {
+                    exchange,
+                    pair,
+                    bid: ticker.bid,
+                    ask: ticker.ask,
+                    last_update: ticker.timestamp,
+                    age_ms,
+                    updates,
+                    updates_per_sec,
+                    fresh: age_ms <= PRICE_FRESHNESS_MS,
+                }
+            })
             .collect()
     }
 }
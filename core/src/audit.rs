@@ -0,0 +1,126 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::io::Write;
+use tokio::sync::Mutex;
+use tracing::warn;
+
+use crate::config::AuditConfig;
+use crate::types::Exchange;
+
+/// Most recent records held in memory for GET /api/audit
+const AUDIT_RECENT_CAP: usize = 1_000;
+
+/// One outbound signed request and the raw venue response, as exposed via
+/// GET /api/audit and appended to the audit file
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderAuditRecord {
+    pub at: DateTime<Utc>,
+    pub exchange: Exchange,
+    /// REST path the request went to
+    pub endpoint: String,
+    pub request: serde_json::Value,
+    pub response: serde_json::Value,
+}
+
+/// Append-only audit trail of every outbound order request (placements,
+/// cancels, withdrawals) and the raw venue response — the evidence needed
+/// to dispute exchange-side issues and debug rejected orders.
+///
+/// Signatures and API keys travel in request headers and are never
+/// written; credential-looking body fields are redacted as a second line
+/// of defense.
+pub struct OrderAuditLog {
+    enabled: bool,
+    path: String,
+    recent: Mutex<VecDeque<OrderAuditRecord>>,
+}
+
+impl OrderAuditLog {
+    pub fn from_config(config: &AuditConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            path: config.path.clone(),
+            recent: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Append one request/response pair to the audit file and the
+    /// in-memory tail
+    pub async fn record(
+        &self,
+        exchange: Exchange,
+        endpoint: &str,
+        request: &serde_json::Value,
+        response: &serde_json::Value,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let record = OrderAuditRecord {
+            at: Utc::now(),
+            exchange,
+            endpoint: endpoint.to_string(),
+            request: redact(request.clone()),
+            response: redact(response.clone()),
+        };
+
+        match serde_json::to_string(&record) {
+            Ok(line) => {
+                let appended = std::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&self.path)
+                    .and_then(|mut f| writeln!(f, "{}", line));
+                if let Err(e) = appended {
+                    warn!("Could not append to audit log {}: {}", self.path, e);
+                }
+            }
+            Err(e) => warn!("Could not serialize audit record: {}", e),
+        }
+
+        let mut recent = self.recent.lock().await;
+        recent.push_back(record);
+        if recent.len() > AUDIT_RECENT_CAP {
+            recent.pop_front();
+        }
+    }
+
+    /// The most recent records, oldest first
+    pub async fn recent(&self, limit: usize) -> Vec<OrderAuditRecord> {
+        let recent = self.recent.lock().await;
+        recent
+            .iter()
+            .skip(recent.len().saturating_sub(limit))
+            .cloned()
+            .collect()
+    }
+}
+
+/// Replace any credential-looking field with "[redacted]", recursively
+fn redact(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.into_iter()
+                .map(|(key, val)| {
+                    let lower = key.to_lowercase();
+                    if lower.contains("sign")
+                        || lower.contains("secret")
+                        || lower.contains("passphrase")
+                        || lower.contains("apikey")
+                        || lower.contains("api_key")
+                    {
+                        (key, serde_json::Value::String("[redacted]".to_string()))
+                    } else {
+                        (key, redact(val))
+                    }
+                })
+                .collect(),
+        ),
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(redact).collect())
+        }
+        other => other,
+    }
+}
@@ -0,0 +1,123 @@
+use chrono::Utc;
+use dashmap::DashMap;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::VecDeque;
+
+use crate::types::{Exchange, Ticker};
+
+/// Candle intervals the aggregator maintains, in seconds
+pub const CANDLE_INTERVALS: &[u32] = &[1, 60];
+
+/// One OHLCV candle, as exposed via GET /api/candles
+#[derive(Debug, Clone, Serialize)]
+pub struct Candle {
+    /// Epoch milliseconds of the bucket start
+    pub ts_ms: i64,
+    pub open: Decimal,
+    pub high: Decimal,
+    pub low: Decimal,
+    pub close: Decimal,
+    /// Base volume traded during the bucket, derived from the ticker's
+    /// rolling 24h volume deltas (zero when the venue reports none)
+    pub volume: Decimal,
+}
+
+/// Builds 1s/1m OHLCV candles per (exchange, pair) from the incoming
+/// ticker stream, so the frontend can chart without consuming raw ticks
+pub struct CandleAggregator {
+    /// Candles kept per (exchange, pair, interval seconds)
+    series: DashMap<(Exchange, String, u32), VecDeque<Candle>>,
+    /// Last seen 24h volume per (exchange, pair), for per-bucket deltas
+    last_volume: DashMap<(Exchange, String), Decimal>,
+    /// Candles retained per 1s series
+    retention_1s: usize,
+    /// Candles retained per 1m series
+    retention_1m: usize,
+}
+
+impl CandleAggregator {
+    pub fn from_config(config: &crate::config::CandlesConfig) -> Self {
+        Self {
+            series: DashMap::new(),
+            last_volume: DashMap::new(),
+            retention_1s: config.retention_1s.max(2),
+            retention_1m: config.retention_1m.max(2),
+        }
+    }
+
+    fn retention(&self, interval_secs: u32) -> usize {
+        if interval_secs >= 60 {
+            self.retention_1m
+        } else {
+            self.retention_1s
+        }
+    }
+
+    /// Fold one ticker into every maintained interval
+    pub fn update(&self, ticker: &Ticker) {
+        let price = if ticker.bid > Decimal::ZERO && ticker.ask > Decimal::ZERO {
+            (ticker.bid + ticker.ask) / Decimal::TWO
+        } else {
+            ticker.last
+        };
+        if price <= Decimal::ZERO {
+            return;
+        }
+        let pair_str = ticker.pair.to_string();
+
+        // Per-bucket volume as the positive delta of the venue's rolling
+        // 24h figure; negative deltas (window roll-off) count as zero
+        let volume_key = (ticker.exchange, pair_str.clone());
+        let delta = match self.last_volume.insert(volume_key, ticker.volume_24h) {
+            Some(previous) if ticker.volume_24h > previous => ticker.volume_24h - previous,
+            _ => Decimal::ZERO,
+        };
+
+        let now_ms = Utc::now().timestamp_millis();
+        for &interval_secs in CANDLE_INTERVALS {
+            let interval_ms = interval_secs as i64 * 1_000;
+            let bucket_ts = now_ms / interval_ms * interval_ms;
+            let key = (ticker.exchange, pair_str.clone(), interval_secs);
+            let mut series = self.series.entry(key).or_default();
+            match series.back_mut() {
+                Some(candle) if candle.ts_ms == bucket_ts => {
+                    candle.high = candle.high.max(price);
+                    candle.low = candle.low.min(price);
+                    candle.close = price;
+                    candle.volume += delta;
+                }
+                _ => {
+                    series.push_back(Candle {
+                        ts_ms: bucket_ts,
+                        open: price,
+                        high: price,
+                        low: price,
+                        close: price,
+                        volume: delta,
+                    });
+                    let retention = self.retention(interval_secs);
+                    while series.len() > retention {
+                        series.pop_front();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Most recent candles for a series, oldest first, at most `limit`
+    pub fn candles(
+        &self,
+        exchange: Exchange,
+        pair: &str,
+        interval_secs: u32,
+        limit: usize,
+    ) -> Vec<Candle> {
+        let key = (exchange, pair.to_string(), interval_secs);
+        let Some(series) = self.series.get(&key) else {
+            return Vec::new();
+        };
+        let skip = series.len().saturating_sub(limit.max(1));
+        series.iter().skip(skip).cloned().collect()
+    }
+}
@@ -11,6 +11,104 @@ pub struct Config {
     pub exchanges: HashMap<String, ExchangeConfig>,
     pub trading: TradingConfig,
     pub risk: RiskConfig,
+    /// Retry policy for exchange REST calls
+    #[serde(default)]
+    pub retry: RetryConfig,
+    /// End-of-day flat-position policy
+    #[serde(default)]
+    pub flatten: FlattenConfig,
+    /// Slippage model for simulated fills
+    #[serde(default)]
+    pub slippage: SlippageConfig,
+    /// Canary sizing for newly traded pairs
+    #[serde(default)]
+    pub canary: CanaryConfig,
+    /// FX rates for non-USD quote normalization
+    #[serde(default)]
+    pub fx: FxConfig,
+    /// Execution cost model calibration
+    #[serde(default)]
+    pub cost_model: CostModelConfig,
+    /// Statistical (z-score) spread strategy parameters
+    #[serde(default)]
+    pub zscore: ZScoreConfig,
+    /// Funding-rate arbitrage (spot vs perpetual)
+    #[serde(default)]
+    pub funding: FundingConfig,
+    /// Multi-bot fleet aggregation
+    #[serde(default)]
+    pub aggregation: AggregationConfig,
+    /// Hot-standby failover
+    #[serde(default)]
+    pub failover: FailoverConfig,
+    /// Stablecoin pair arbitrage mode
+    #[serde(default)]
+    pub stablecoin: StablecoinConfig,
+    /// Opportunity filter pipeline
+    #[serde(default)]
+    pub filters: FiltersConfig,
+    /// Adaptive spread threshold mode
+    #[serde(default)]
+    pub adaptive_threshold: AdaptiveThresholdConfig,
+    /// Volatility circuit breaker for fast markets
+    #[serde(default)]
+    pub volatility_circuit: VolatilityCircuitConfig,
+    /// External reference-price sanity check
+    #[serde(default)]
+    pub reference: ReferenceConfig,
+    /// In-memory OHLCV candle aggregation for charting
+    #[serde(default)]
+    pub candles: CandlesConfig,
+    /// Automatic cross-exchange inventory rebalancing
+    #[serde(default)]
+    pub rebalance: RebalanceConfig,
+    /// Withdrawal/network transfer costs amortized into opportunity math
+    #[serde(default)]
+    pub transfer_costs: TransferCostsConfig,
+    /// Execution circuit breaker on consecutive bad trades
+    #[serde(default)]
+    pub circuit_breaker: CircuitBreakerConfig,
+    /// Append-only order request/response audit trail
+    #[serde(default)]
+    pub audit: AuditConfig,
+    /// Background reconciliation against exchange order history
+    #[serde(default)]
+    pub reconcile: ReconcileConfig,
+    /// Market data recording to disk for backtesting and post-mortems
+    #[serde(default)]
+    pub recorder: RecorderConfig,
+    /// SQLite persistence of trades and opportunities across restarts
+    #[serde(default)]
+    pub store: StoreConfig,
+    /// Redis mirroring of prices, opportunities and trades for external
+    /// dashboards and read replicas
+    #[serde(default)]
+    pub mirror: MirrorConfig,
+    /// Event-bus publishing (NATS/Kafka) of tickers, opportunities and
+    /// trades for downstream consumers
+    #[serde(default)]
+    pub events: EventsConfig,
+    /// Outbound webhook notifications for trades, breaker trips, feed
+    /// outages and risk-limit hits
+    #[serde(default)]
+    pub notify: NotifyConfig,
+    /// Telegram bot notifications and remote commands
+    #[serde(default)]
+    pub telegram: TelegramConfig,
+    /// Slack incoming-webhook notifications
+    #[serde(default)]
+    pub slack: SlackConfig,
+    /// OpenTelemetry span export for the trade pipeline
+    #[serde(default)]
+    pub otel: OtelConfig,
+    /// REST API hardening (authentication for mutating endpoints)
+    #[serde(default)]
+    pub api: ApiConfig,
+    /// Per-exchange symbol overrides for pairs whose exchange symbol is
+    /// not plain concatenation, keyed by exchange then pair, e.g.
+    /// `[symbol_overrides.bybit] "PEPE/USDT" = "1000PEPEUSDT"`
+    #[serde(default)]
+    pub symbol_overrides: HashMap<String, HashMap<String, String>>,
 }
 
 /// Engine settings
@@ -20,6 +118,27 @@ pub struct EngineConfig {
     pub scan_interval_ms: u64,
     pub simulation_mode: bool,
     pub api_port: u16,
+    /// How often to poll account-level events (deposits/withdrawals), seconds
+    #[serde(default = "default_account_poll_secs")]
+    pub account_poll_secs: u64,
+    /// Detection strategies to register, by name
+    #[serde(default = "default_strategies")]
+    pub strategies: Vec<String>,
+    /// Origins allowed to call the API from a browser; empty means none
+    /// unless `cors_allow_all` is set
+    #[serde(default)]
+    pub cors_allowed_origins: Vec<String>,
+    /// Explicit opt-in to the old wildcard CORS behavior
+    #[serde(default)]
+    pub cors_allow_all: bool,
+}
+
+fn default_strategies() -> Vec<String> {
+    vec!["cross_exchange".to_string()]
+}
+
+fn default_account_poll_secs() -> u64 {
+    30
 }
 
 /// Per-exchange configuration
@@ -30,7 +149,71 @@ pub struct ExchangeConfig {
     pub api_secret: String,
     /// Optional passphrase (some exchanges require this)
     pub passphrase: Option<String>,
+    /// Read the API key from this file instead (first line, trimmed) —
+    /// for Docker/Kubernetes mounted secrets. Takes precedence over the
+    /// inline value when set.
+    #[serde(default)]
+    pub api_key_file: Option<String>,
+    #[serde(default)]
+    pub api_secret_file: Option<String>,
+    #[serde(default)]
+    pub passphrase_file: Option<String>,
     pub fee_pct: Decimal,
+    /// Treat unexpected payload shapes as counted parse errors instead of
+    /// silently defaulting fields to zero
+    #[serde(default)]
+    pub strict_parse: bool,
+    /// Force a WebSocket reconnect if no message arrives for this many
+    /// seconds — a silently dead socket otherwise keeps serving stale prices
+    #[serde(default = "default_ws_stale_secs")]
+    pub ws_stale_secs: u64,
+    /// Fall back to REST ticker polling when the WS feed has been silent
+    /// past `ws_stale_secs`, so detection degrades instead of going blind
+    #[serde(default)]
+    pub rest_poll_fallback: bool,
+    /// REST fallback polling interval, ms (keep well inside rate limits)
+    #[serde(default = "default_rest_poll_ms")]
+    pub rest_poll_ms: u64,
+    /// Allow margin (borrow + sell) orders so the sell leg can execute
+    /// without inventory of the base asset
+    #[serde(default)]
+    pub margin_enabled: bool,
+    /// Daily borrow interest charged against margin sells, percent
+    #[serde(default = "default_borrow_rate_daily_pct")]
+    pub borrow_rate_daily_pct: Decimal,
+    /// Artificial order latency in simulation mode, ms (0 = instant fills)
+    #[serde(default)]
+    pub sim_latency_ms: u64,
+    /// Uniform random jitter added on top of sim_latency_ms, ms
+    #[serde(default)]
+    pub sim_latency_jitter_ms: u64,
+    /// Virtual starting balances for simulation mode, keyed by asset,
+    /// e.g. `sim_balances = { USDT = "10000", BTC = "0.1" }` — simulated
+    /// trades debit and credit these so paper trading hits the same
+    /// insufficient-balance and skew conditions live mode would. Leaving
+    /// every venue's map empty keeps the old unlimited-funds behavior.
+    #[serde(default)]
+    pub sim_balances: HashMap<String, Decimal>,
+    /// Pairs never traded on this venue, e.g. ["SOL/USDT"] — the detector
+    /// skips the subscription and the executor refuses the leg
+    #[serde(default)]
+    pub disabled_pairs: Vec<String>,
+    /// Allow the withdrawal endpoints to actually move funds — off by
+    /// default so a bad rebalance config can't drain an account
+    #[serde(default)]
+    pub allow_withdrawals: bool,
+}
+
+fn default_rest_poll_ms() -> u64 {
+    1_000
+}
+
+fn default_ws_stale_secs() -> u64 {
+    30
+}
+
+fn default_borrow_rate_daily_pct() -> Decimal {
+    Decimal::new(5, 2) // 0.05% per day
 }
 
 /// Trading parameters
@@ -40,6 +223,859 @@ pub struct TradingConfig {
     pub max_trade_qty: Decimal,
     pub min_trade_qty: Decimal,
     pub order_type: String,
+    /// Currency profits are normalized into for reporting and for the
+    /// `max_daily_loss` limit (pairs may be quoted in USDC/BTC/EUR/…)
+    #[serde(default = "default_reporting_currency")]
+    pub reporting_currency: String,
+    /// Per-pair overrides of the global thresholds, keyed by pair, e.g.
+    /// `[trading.pair_overrides."BTC/USDT"]` — unset fields fall back to
+    /// the globals
+    #[serde(default)]
+    pub pair_overrides: HashMap<String, PairOverrides>,
+    /// How long a GTC limit leg may sit unfilled before follow-up, ms
+    /// (0 disables the follow-up entirely)
+    #[serde(default = "default_limit_timeout_ms")]
+    pub limit_timeout_ms: u64,
+    /// What happens to a limit leg still open after the timeout: "market"
+    /// cancels and converts to a market order, "reprice" cancels and
+    /// re-places a limit at the current top of book
+    #[serde(default = "default_limit_timeout_action")]
+    pub limit_timeout_action: String,
+}
+
+fn default_limit_timeout_ms() -> u64 {
+    3_000
+}
+
+fn default_limit_timeout_action() -> String {
+    "market".to_string()
+}
+
+/// Per-pair overrides: BTC and a thin altcoin should not share one global
+/// spread threshold and size
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PairOverrides {
+    pub min_spread_pct: Option<Decimal>,
+    pub max_trade_qty: Option<Decimal>,
+    pub trade_cooldown_ms: Option<u64>,
+    pub order_type: Option<String>,
+}
+
+fn default_reporting_currency() -> String {
+    "USDT".to_string()
+}
+
+/// Adaptive spread threshold: when enabled, the "min_spread" filter
+/// requires a pair's gross spread to exceed mean + k·stddev of its own
+/// rolling spread distribution instead of the static `min_spread_pct`, so
+/// pairs with a wide baseline spread don't fire constantly
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AdaptiveThresholdConfig {
+    pub enabled: bool,
+    /// Spread samples kept per pair
+    pub window: usize,
+    /// Samples required before the dynamic threshold takes over from the
+    /// static one
+    pub min_samples: usize,
+    /// Standard deviations above the mean a spread must reach
+    pub k: f64,
+    /// Per-pair overrides of `k`
+    pub pair_k: HashMap<String, f64>,
+}
+
+impl Default for AdaptiveThresholdConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window: 300,
+            min_samples: 50,
+            k: 2.0,
+            pair_k: HashMap::new(),
+        }
+    }
+}
+
+/// Volatility circuit breaker: when a pair's short-horizon realized
+/// volatility (per-tick mid returns) spikes past the cap, opportunity
+/// generation for that pair is suspended until volatility has stayed
+/// below the cap for `cooldown_secs` — fast markets are where one leg
+/// fails and losses occur
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolatilityCircuitConfig {
+    pub enabled: bool,
+    /// Realized volatility (pct per tick) at which the circuit trips
+    pub max_volatility_pct: f64,
+    /// How long volatility must stay below the cap before the pair
+    /// resumes trading, seconds
+    pub cooldown_secs: u64,
+}
+
+impl Default for VolatilityCircuitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_volatility_pct: 1.0,
+            cooldown_secs: 60,
+        }
+    }
+}
+
+/// External reference-price sanity check: an independent index feed
+/// (CoinGecko) that opportunity prices are compared against, so a
+/// mis-parsed exchange field producing an absurd "spread" gets rejected
+/// instead of acted on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReferenceConfig {
+    pub enabled: bool,
+    /// Reference price provider ("coingecko")
+    pub provider: String,
+    pub refresh_secs: u64,
+    /// Reference prices older than this are treated as stale and unused
+    pub max_age_secs: u64,
+    /// "reference_price": reject when either leg's price deviates more
+    /// than this from the reference, percent
+    pub max_deviation_pct: Decimal,
+    /// Base asset → provider id, e.g. "BTC" = "bitcoin"
+    pub ids: HashMap<String, String>,
+}
+
+impl Default for ReferenceConfig {
+    fn default() -> Self {
+        let mut ids = HashMap::new();
+        ids.insert("BTC".to_string(), "bitcoin".to_string());
+        ids.insert("ETH".to_string(), "ethereum".to_string());
+        Self {
+            enabled: false,
+            provider: "coingecko".to_string(),
+            refresh_secs: 60,
+            max_age_secs: 300,
+            max_deviation_pct: Decimal::new(5, 0), // 5%
+            ids,
+        }
+    }
+}
+
+/// In-memory OHLCV candle aggregation built from the ticker stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandlesConfig {
+    pub enabled: bool,
+    /// 1s candles retained per (exchange, pair)
+    pub retention_1s: usize,
+    /// 1m candles retained per (exchange, pair)
+    pub retention_1m: usize,
+}
+
+impl Default for CandlesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            retention_1s: 600,
+            retention_1m: 1440,
+        }
+    }
+}
+
+/// Automatic cross-exchange rebalancing: when an asset's cross-venue skew
+/// exceeds its threshold, plan a transfer from the overweight venue to the
+/// underweight one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RebalanceConfig {
+    pub enabled: bool,
+    /// Log planned transfers without sending anything
+    pub dry_run: bool,
+    /// Skew check interval, seconds
+    pub check_secs: u64,
+    /// Skew (max venue minus min venue) past which an asset rebalances,
+    /// keyed by asset — unlisted assets are never rebalanced
+    pub max_skew: HashMap<String, Decimal>,
+    /// Smallest transfer worth sending, keyed by asset (network fees make
+    /// small transfers uneconomical)
+    pub min_transfer: HashMap<String, Decimal>,
+}
+
+impl Default for RebalanceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dry_run: true,
+            check_secs: 300,
+            max_skew: HashMap::new(),
+            min_transfer: HashMap::new(),
+        }
+    }
+}
+
+/// Per-asset transfer costs, amortized into `net_spread_pct` when enabled:
+/// strategies that need periodic rebalancing pay withdrawal and network
+/// fees that pure trading-fee math ignores
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TransferCostsConfig {
+    pub enabled: bool,
+    /// Venue withdrawal fee per asset, in asset units
+    pub withdrawal_fee: HashMap<String, Decimal>,
+    /// On-chain network fee per asset, in asset units
+    pub network_fee: HashMap<String, Decimal>,
+    /// Trades expected between rebalances — one transfer's cost is
+    /// spread across this many trades
+    pub trades_per_rebalance: u64,
+}
+
+impl Default for TransferCostsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            withdrawal_fee: HashMap::new(),
+            network_fee: HashMap::new(),
+            trades_per_rebalance: 50,
+        }
+    }
+}
+
+/// Execution circuit breaker: pause trading after consecutive failed or
+/// partially-filled trades, or when one trade's realized slippage blows
+/// past the threshold — keep a malfunctioning leg from compounding
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CircuitBreakerConfig {
+    pub enabled: bool,
+    /// Consecutive Failed/PartialFill results that trip the breaker
+    pub max_consecutive_failures: u32,
+    /// Realized slippage (percent vs. the detected prices) on a single
+    /// trade that trips the breaker immediately
+    pub max_slippage_pct: Decimal,
+    /// How long trading stays paused before resuming automatically,
+    /// seconds (a manual reset clears it sooner)
+    pub cooloff_secs: u64,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_consecutive_failures: 3,
+            max_slippage_pct: Decimal::new(5, 1), // 0.5%
+            cooloff_secs: 300,
+        }
+    }
+}
+
+/// Append-only audit trail of outbound order requests and raw venue
+/// responses, for disputing exchange-side issues and debugging rejects
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AuditConfig {
+    pub enabled: bool,
+    /// File the JSONL audit trail is appended to
+    pub path: String,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            path: "order_audit.jsonl".to_string(),
+        }
+    }
+}
+
+/// Background reconciliation of local trade records against each
+/// exchange's own order history, flagging orphan fills and phantom trades
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReconcileConfig {
+    pub enabled: bool,
+    /// Seconds between reconciliation passes
+    pub check_secs: u64,
+    /// How far back each pass looks, seconds
+    pub lookback_secs: u64,
+}
+
+impl Default for ReconcileConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            check_secs: 300,
+            lookback_secs: 3_600,
+        }
+    }
+}
+
+/// Recording of the live ticker stream to hourly rotating gzip JSONL
+/// files, for backtesting and post-mortem analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RecorderConfig {
+    pub enabled: bool,
+    /// Directory the hourly files are written into
+    pub dir: String,
+    /// "jsonl" (hourly gzip JSONL — the format replay and the backtester
+    /// read) or "parquet" (per-day Snappy Parquet for DuckDB/Polars)
+    pub format: String,
+    /// Recorded files older than this are deleted at rotation
+    /// (0 keeps everything)
+    pub retention_hours: u64,
+}
+
+impl Default for RecorderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            dir: "market_data".to_string(),
+            format: "jsonl".to_string(),
+            retention_hours: 72,
+        }
+    }
+}
+
+/// SQLite persistence of trades and opportunities, so history survives
+/// restarts and the API can serve queries past the in-memory windows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StoreConfig {
+    pub enabled: bool,
+    /// SQLite database file
+    pub path: String,
+}
+
+impl Default for StoreConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: "arbiter.db".to_string(),
+        }
+    }
+}
+
+/// Redis mirroring of live state (latest prices, recent opportunities,
+/// trade feed), so dashboards and read replicas can serve it from outside
+/// the trading engine's process
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MirrorConfig {
+    pub enabled: bool,
+    /// Redis connection URL
+    pub url: String,
+    /// Prefix for every key and channel this instance writes
+    pub key_prefix: String,
+}
+
+impl Default for MirrorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            url: "redis://127.0.0.1/".to_string(),
+            key_prefix: "arbiter".to_string(),
+        }
+    }
+}
+
+/// Event-bus publishing of tickers, opportunities and trades as JSON
+/// messages, for downstream analytics and alerting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EventsConfig {
+    pub enabled: bool,
+    /// "nats" or "kafka"
+    pub backend: String,
+    /// Broker address(es) — a NATS URL, or comma-separated Kafka
+    /// host:port pairs
+    pub servers: String,
+    /// Prefix for every topic this instance publishes to
+    pub topic_prefix: String,
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            backend: "nats".to_string(),
+            servers: "nats://127.0.0.1:4222".to_string(),
+            topic_prefix: "arbiter".to_string(),
+        }
+    }
+}
+
+/// Outbound webhook notifications: executed trades, circuit breaker
+/// trips, connector feed outages and risk-limit hits POSTed as JSON to
+/// each URL, retried with backoff and optionally HMAC-signed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotifyConfig {
+    pub enabled: bool,
+    /// Webhook endpoints, each receiving every notification
+    pub urls: Vec<String>,
+    /// HMAC-SHA256 key for the `X-Arbiter-Signature` header; empty sends
+    /// unsigned
+    pub secret: String,
+    /// Delivery attempts per URL before a notification is dropped
+    pub retry_attempts: u32,
+}
+
+impl Default for NotifyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            urls: Vec::new(),
+            secret: String::new(),
+            retry_attempts: 3,
+        }
+    }
+}
+
+/// Telegram bot: pushes trade executions, large opportunities and error
+/// alerts to a chat, and optionally accepts `/status`, `/pause` and
+/// `/resume` from that same chat
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TelegramConfig {
+    pub enabled: bool,
+    pub bot_token: String,
+    /// Chat the bot posts to; commands from any other chat are ignored
+    pub chat_id: String,
+    /// Accept /status, /pause and /resume from the configured chat
+    pub commands_enabled: bool,
+    /// Notify opportunities whose estimated profit (reporting currency)
+    /// reaches this; 0 disables opportunity pushes
+    pub min_opportunity_profit: Decimal,
+}
+
+impl Default for TelegramConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bot_token: String::new(),
+            chat_id: String::new(),
+            commands_enabled: false,
+            min_opportunity_profit: Decimal::ZERO,
+        }
+    }
+}
+
+/// Slack incoming-webhook notifications, sharing the notification
+/// dispatcher with the webhook and Telegram sinks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SlackConfig {
+    pub enabled: bool,
+    pub webhook_url: String,
+    /// Lowest severity posted: "info", "warning" or "critical"
+    pub min_severity: String,
+    /// Per-kind message templates keyed by notification kind (e.g.
+    /// "trade_executed"), with `{title}`, `{detail}`, `{kind}` and
+    /// `{severity}` placeholders; unset kinds use the default layout
+    pub templates: HashMap<String, String>,
+}
+
+impl Default for SlackConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            webhook_url: String::new(),
+            min_severity: "info".to_string(),
+            templates: HashMap::new(),
+        }
+    }
+}
+
+/// OpenTelemetry tracing: spans covering ticker → detection → execution →
+/// fill are exported over OTLP, with the opportunity id attached as an
+/// attribute so per-trade latency can be traced end to end
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OtelConfig {
+    pub enabled: bool,
+    /// OTLP gRPC endpoint
+    pub endpoint: String,
+    /// `service.name` resource attribute
+    pub service_name: String,
+}
+
+impl Default for OtelConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: "http://127.0.0.1:4317".to_string(),
+            service_name: "arbiter".to_string(),
+        }
+    }
+}
+
+/// REST API hardening. With no keys configured, mutating endpoints stay
+/// open (the historical behavior, for isolated deployments); otherwise
+/// every non-GET request must present one of the keys.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ApiConfig {
+    /// Operator keys — full access including config mutation and engine
+    /// control; also extendable via the `ARBITER_API_KEYS` env var
+    /// (comma-separated)
+    pub api_keys: Vec<String>,
+    /// Viewer keys — read-only access (prices, trades, status). Once any
+    /// are configured, reads require a key too; also extendable via
+    /// `ARBITER_VIEWER_KEYS`
+    pub viewer_api_keys: Vec<String>,
+    /// Per-IP requests per minute across the whole API (0 disables)
+    pub rate_limit_per_minute: u32,
+    /// Stricter per-IP budget for config mutation, order control and the
+    /// WS upgrade (0 disables)
+    pub sensitive_rate_limit_per_minute: u32,
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            api_keys: Vec::new(),
+            viewer_api_keys: Vec::new(),
+            rate_limit_per_minute: 600,
+            sensitive_rate_limit_per_minute: 60,
+        }
+    }
+}
+
+/// Opportunity filter pipeline: the named filters in `chain` run in order
+/// over every finalized opportunity, and each can be dropped or reordered
+/// without touching the detector
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FiltersConfig {
+    /// Filter names in pipeline order
+    pub chain: Vec<String>,
+    /// "staleness": reject when either leg's ticker is older than this
+    pub max_ticker_age_ms: i64,
+    /// "volatility": reject when either leg's recent realized volatility
+    /// (pct per tick) exceeds this
+    pub max_volatility_pct: f64,
+    /// "confidence": reject below this confidence floor
+    pub min_confidence: Decimal,
+    /// "blacklist": pairs never traded
+    pub blacklist: Vec<String>,
+    /// "persistence": spread must persist this long before acting
+    /// (0 disables)
+    pub persistence_ms: i64,
+    /// "min_profit": estimated profit floor, reporting currency
+    pub min_profit: Decimal,
+    /// "dedupe": re-emit an unchanged (pair, direction) at most this often
+    pub dedupe_interval_ms: i64,
+    /// "dedupe": spread change considered material enough to re-emit
+    /// immediately, percentage points
+    pub dedupe_spread_delta_pct: Decimal,
+}
+
+impl Default for FiltersConfig {
+    fn default() -> Self {
+        Self {
+            chain: vec![
+                "staleness".to_string(),
+                "reference_price".to_string(),
+                "volatility".to_string(),
+                "confidence".to_string(),
+                "blacklist".to_string(),
+                "min_spread".to_string(),
+                "persistence".to_string(),
+                "min_profit".to_string(),
+                "dedupe".to_string(),
+            ],
+            max_ticker_age_ms: 5_000,
+            max_volatility_pct: 0.5,
+            min_confidence: Decimal::new(5, 1), // 0.5
+            blacklist: Vec::new(),
+            persistence_ms: 0,
+            min_profit: Decimal::ZERO,
+            dedupe_interval_ms: 1_000,
+            dedupe_spread_delta_pct: Decimal::new(1, 2), // 0.01%
+        }
+    }
+}
+
+/// Stablecoin pair arbitrage: stable-vs-stable pairs trade inside a band
+/// of a few bps, so they run with much tighter spread thresholds, larger
+/// quantities and their own position limit than volatile pairs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StablecoinConfig {
+    pub enabled: bool,
+    /// Stable pairs to monitor in addition to `trading.pairs`
+    pub pairs: Vec<String>,
+    pub min_spread_pct: Decimal,
+    pub max_trade_qty: Decimal,
+    pub min_trade_qty: Decimal,
+    pub max_position: Decimal,
+}
+
+impl Default for StablecoinConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            pairs: vec!["USDC/USDT".to_string()],
+            min_spread_pct: Decimal::new(1, 2),     // 0.01%
+            max_trade_qty: Decimal::new(10_000, 0),
+            min_trade_qty: Decimal::new(100, 0),
+            max_position: Decimal::new(50_000, 0),
+        }
+    }
+}
+
+/// Retry policy for exchange REST calls
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetryConfig {
+    pub max_attempts: u32,
+    pub base_backoff_ms: u64,
+    pub max_backoff_ms: u64,
+    /// Also retry generic API errors (e.g. transient 5xx), not just
+    /// connection errors and rate limits
+    pub retry_api_errors: bool,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_backoff_ms: 250,
+            max_backoff_ms: 5000,
+            retry_api_errors: false,
+        }
+    }
+}
+
+/// End-of-day flat-position policy: at `time_utc`, unwind residual
+/// inventory back to target allocations with market orders so the bot
+/// goes home flat every day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FlattenConfig {
+    pub enabled: bool,
+    /// When to flatten, "HH:MM" UTC
+    pub time_utc: String,
+    /// Skip the unwind if its estimated cost (fees + half-spread) in quote
+    /// currency would exceed this cap
+    pub max_cost_usd: Decimal,
+    /// Target base-asset allocation per exchange; assets not listed
+    /// flatten to zero
+    pub targets: HashMap<String, Decimal>,
+}
+
+impl Default for FlattenConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            time_utc: "23:55".to_string(),
+            max_cost_usd: Decimal::new(25, 0), // $25
+            targets: HashMap::new(),
+        }
+    }
+}
+
+/// Slippage model applied to simulated fills so paper results better
+/// predict live performance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SlippageConfig {
+    /// "none", "fixed", "random" or "depth"
+    pub model: String,
+    /// Fixed model: slippage applied against each leg, basis points
+    pub fixed_bps: Decimal,
+    /// Random model: uniform slippage per leg between min_bps and max_bps
+    pub min_bps: Decimal,
+    pub max_bps: Decimal,
+    /// Depth model: basis points of slippage per unit of base quantity
+    pub bps_per_qty: Decimal,
+}
+
+impl Default for SlippageConfig {
+    fn default() -> Self {
+        Self {
+            model: "none".to_string(),
+            fixed_bps: Decimal::new(5, 0),
+            min_bps: Decimal::ZERO,
+            max_bps: Decimal::new(10, 0),
+            bps_per_qty: Decimal::new(100, 0),
+        }
+    }
+}
+
+/// Canary execution: trade newly added pairs at a reduced fraction of
+/// configured size for their first N trades, promoting to full size only
+/// if realized slippage and fill quality meet thresholds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CanaryConfig {
+    pub enabled: bool,
+    /// Fraction of normal size to trade during the canary period
+    pub size_fraction: Decimal,
+    /// Number of trades in the canary period
+    pub trades: u32,
+    /// Promote only if average realized slippage stays under this
+    pub max_avg_slippage_bps: Decimal,
+    /// Promote only if at least this share of trades filled fully
+    pub min_fill_rate_pct: Decimal,
+}
+
+impl Default for CanaryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            size_fraction: Decimal::new(1, 1), // 10%
+            trades: 10,
+            max_avg_slippage_bps: Decimal::new(20, 0),
+            min_fill_rate_pct: Decimal::new(90, 0),
+        }
+    }
+}
+
+/// FX rate source for normalizing non-USD quote currencies
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FxConfig {
+    /// "static", "ecb" or "openexchangerates"
+    pub provider: String,
+    pub refresh_secs: u64,
+    /// Rates older than this are treated as stale and refused
+    pub max_age_secs: u64,
+    /// openexchangerates app id
+    pub app_id: String,
+    /// Static provider: USD per one unit of each currency
+    pub rates: HashMap<String, Decimal>,
+}
+
+impl Default for FxConfig {
+    fn default() -> Self {
+        Self {
+            provider: "static".to_string(),
+            refresh_secs: 3600,
+            max_age_secs: 21600,
+            app_id: String::new(),
+            rates: HashMap::new(),
+        }
+    }
+}
+
+/// Execution cost model calibration job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostModelConfig {
+    /// Days of fills to fit against
+    pub window_days: u32,
+    /// How often to re-fit, seconds
+    pub refresh_secs: u64,
+}
+
+impl Default for CostModelConfig {
+    fn default() -> Self {
+        Self {
+            window_days: 7,
+            refresh_secs: 3600,
+        }
+    }
+}
+
+/// Statistical (z-score) spread strategy: signal only when the spread
+/// deviates from its rolling baseline by enough standard deviations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ZScoreConfig {
+    /// Rolling window length, in spread samples
+    pub window: usize,
+    /// Minimum samples before the baseline is trusted
+    pub min_samples: usize,
+    /// Z-score at which to signal entry
+    pub entry_z: f64,
+    /// Z-score below which the signal re-arms
+    pub exit_z: f64,
+}
+
+impl Default for ZScoreConfig {
+    fn default() -> Self {
+        Self {
+            window: 300,
+            min_samples: 50,
+            entry_z: 2.0,
+            exit_z: 0.5,
+        }
+    }
+}
+
+/// Funding-rate arbitrage: spot-buy / perp-short positions where funding
+/// yield exceeds fees
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FundingConfig {
+    pub enabled: bool,
+    /// How often to poll funding rates, seconds
+    pub poll_secs: u64,
+    /// Surface opportunities only above this annualized yield
+    pub min_annualized_yield_pct: Decimal,
+    /// Actually open both legs (live mode only); otherwise surface-only
+    pub auto_execute: bool,
+}
+
+impl Default for FundingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            poll_secs: 60,
+            min_annualized_yield_pct: Decimal::new(5, 0), // 5%
+            auto_execute: false,
+        }
+    }
+}
+
+/// Fleet aggregation: serve combined status/trades/portfolio views by
+/// querying peer Arbiter instances
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AggregationConfig {
+    /// Base URLs of peer instances, e.g. "http://10.0.0.2:8080"
+    pub peers: Vec<String>,
+    /// Per-peer request timeout, ms
+    pub timeout_ms: u64,
+}
+
+impl Default for AggregationConfig {
+    fn default() -> Self {
+        Self {
+            peers: Vec::new(),
+            timeout_ms: 2000,
+        }
+    }
+}
+
+/// Hot-standby failover: a standby instance consumes the same market data
+/// but only takes over execution when the primary's heartbeat disappears
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FailoverConfig {
+    pub enabled: bool,
+    /// "primary" or "standby"
+    pub role: String,
+    /// Base URL of the peer instance, e.g. "http://10.0.0.2:8080"
+    pub peer_url: String,
+    /// How often to check the peer's heartbeat, seconds
+    pub heartbeat_secs: u64,
+    /// Take over once the peer has been silent this long, seconds
+    pub takeover_after_secs: u64,
+}
+
+impl Default for FailoverConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            role: "primary".to_string(),
+            peer_url: String::new(),
+            heartbeat_secs: 2,
+            takeover_after_secs: 10,
+        }
+    }
+}
+
+fn default_balance_reserve_pct() -> Decimal {
+    Decimal::new(5, 0) // 5%
 }
 
 /// Risk management parameters
@@ -49,6 +1085,51 @@ pub struct RiskConfig {
     pub max_daily_loss: Decimal,
     pub max_concurrent_trades: u32,
     pub trade_cooldown_ms: u64,
+    /// Fraction of free balance held back when sizing against balances,
+    /// percent — covers fees, in-flight orders and venue dust rules
+    #[serde(default = "default_balance_reserve_pct")]
+    pub balance_reserve_pct: Decimal,
+    /// Cap on cumulative traded notional per venue (reporting currency);
+    /// None = uncapped. Stops a runaway stream of opportunities from
+    /// concentrating the entire bankroll on one venue.
+    #[serde(default)]
+    pub max_exposure_per_exchange: Option<Decimal>,
+    /// UTC hour at which the daily-loss accounting window resets — without
+    /// a reset, one bad day locks the executor permanently
+    #[serde(default)]
+    pub daily_reset_hour_utc: u32,
+    /// Maximum cumulative loss allowed per strategy (reporting currency),
+    /// keyed by strategy name — a bankroll for experimental strategies,
+    /// independent of the global daily loss limit. Unlisted strategies
+    /// are uncapped.
+    #[serde(default)]
+    pub strategy_budgets: HashMap<String, Decimal>,
+    /// When one leg fills and the other fails, immediately reverse the
+    /// filled leg with a market order instead of carrying naked
+    /// directional exposure
+    #[serde(default = "default_unwind_partial_fills")]
+    pub unwind_partial_fills: bool,
+    /// Re-attempts of a failed leg at progressively worse limit prices
+    /// before the unwind kicks in (0 disables) — often the second attempt
+    /// fills and saves the round trip
+    #[serde(default = "default_leg_retry_attempts")]
+    pub leg_retry_attempts: u32,
+    /// Total adverse price movement allowed across leg retries, in bps of
+    /// the original leg price
+    #[serde(default = "default_leg_retry_max_slippage_bps")]
+    pub leg_retry_max_slippage_bps: Decimal,
+}
+
+fn default_unwind_partial_fills() -> bool {
+    true
+}
+
+fn default_leg_retry_attempts() -> u32 {
+    2
+}
+
+fn default_leg_retry_max_slippage_bps() -> Decimal {
+    Decimal::new(10, 0) // 10 bps
 }
 
 impl Default for Config {
@@ -61,7 +1142,21 @@ impl Default for Config {
                 api_key: String::new(),
                 api_secret: String::new(),
                 passphrase: None,
+                api_key_file: None,
+                api_secret_file: None,
+                passphrase_file: None,
                 fee_pct: Decimal::new(1, 3), // 0.1%
+                strict_parse: false,
+                ws_stale_secs: default_ws_stale_secs(),
+                rest_poll_fallback: false,
+                rest_poll_ms: default_rest_poll_ms(),
+                margin_enabled: false,
+                borrow_rate_daily_pct: default_borrow_rate_daily_pct(),
+                sim_latency_ms: 0,
+                sim_latency_jitter_ms: 0,
+                sim_balances: HashMap::new(),
+                disabled_pairs: Vec::new(),
+                allow_withdrawals: false,
             },
         );
         exchanges.insert(
@@ -71,7 +1166,21 @@ impl Default for Config {
                 api_key: String::new(),
                 api_secret: String::new(),
                 passphrase: Some(String::new()),
+                api_key_file: None,
+                api_secret_file: None,
+                passphrase_file: None,
                 fee_pct: Decimal::new(1, 3), // 0.1%
+                strict_parse: false,
+                ws_stale_secs: default_ws_stale_secs(),
+                rest_poll_fallback: false,
+                rest_poll_ms: default_rest_poll_ms(),
+                margin_enabled: false,
+                borrow_rate_daily_pct: default_borrow_rate_daily_pct(),
+                sim_latency_ms: 0,
+                sim_latency_jitter_ms: 0,
+                sim_balances: HashMap::new(),
+                disabled_pairs: Vec::new(),
+                allow_withdrawals: false,
             },
         );
 
@@ -81,6 +1190,10 @@ impl Default for Config {
                 scan_interval_ms: 100,
                 simulation_mode: true,
                 api_port: 8080,
+                account_poll_secs: default_account_poll_secs(),
+                strategies: default_strategies(),
+                cors_allowed_origins: Vec::new(),
+                cors_allow_all: false,
             },
             exchanges,
             trading: TradingConfig {
@@ -88,29 +1201,278 @@ impl Default for Config {
                 max_trade_qty: Decimal::new(1, 2), // 0.01 BTC
                 min_trade_qty: Decimal::new(1, 4), // 0.0001 BTC
                 order_type: "market".to_string(),
+                reporting_currency: default_reporting_currency(),
+                pair_overrides: HashMap::new(),
+                limit_timeout_ms: default_limit_timeout_ms(),
+                limit_timeout_action: default_limit_timeout_action(),
             },
             risk: RiskConfig {
                 max_position: Decimal::new(1, 1), // 0.1 BTC
                 max_daily_loss: Decimal::new(100, 0), // $100
                 max_concurrent_trades: 3,
                 trade_cooldown_ms: 1000,
+                balance_reserve_pct: default_balance_reserve_pct(),
+                max_exposure_per_exchange: None,
+                daily_reset_hour_utc: 0,
+                strategy_budgets: HashMap::new(),
+                unwind_partial_fills: default_unwind_partial_fills(),
+                leg_retry_attempts: default_leg_retry_attempts(),
+                leg_retry_max_slippage_bps: default_leg_retry_max_slippage_bps(),
             },
+            retry: RetryConfig::default(),
+            flatten: FlattenConfig::default(),
+            slippage: SlippageConfig::default(),
+            canary: CanaryConfig::default(),
+            fx: FxConfig::default(),
+            cost_model: CostModelConfig::default(),
+            zscore: ZScoreConfig::default(),
+            funding: FundingConfig::default(),
+            aggregation: AggregationConfig::default(),
+            failover: FailoverConfig::default(),
+            stablecoin: StablecoinConfig::default(),
+            filters: FiltersConfig::default(),
+            adaptive_threshold: AdaptiveThresholdConfig::default(),
+            volatility_circuit: VolatilityCircuitConfig::default(),
+            reference: ReferenceConfig::default(),
+            candles: CandlesConfig::default(),
+            rebalance: RebalanceConfig::default(),
+            transfer_costs: TransferCostsConfig::default(),
+            circuit_breaker: CircuitBreakerConfig::default(),
+            audit: AuditConfig::default(),
+            reconcile: ReconcileConfig::default(),
+            recorder: RecorderConfig::default(),
+            store: StoreConfig::default(),
+            mirror: MirrorConfig::default(),
+            events: EventsConfig::default(),
+            notify: NotifyConfig::default(),
+            telegram: TelegramConfig::default(),
+            slack: SlackConfig::default(),
+            otel: OtelConfig::default(),
+            api: ApiConfig::default(),
+            symbol_overrides: HashMap::new(),
+        }
+    }
+}
+
+/// Layer `ARB__`-prefixed environment variables over the parsed TOML,
+/// so secrets and per-environment settings need not be baked into the
+/// file in containerized deployments. `__` separates path segments and
+/// segments are lowercased, e.g. `ARB__EXCHANGES__BYBIT__API_KEY` sets
+/// `exchanges.bybit.api_key`. Values are parsed as TOML (numbers, bools,
+/// arrays) and fall back to plain strings.
+fn apply_env_overrides(value: &mut toml::Value) {
+    for (key, raw) in std::env::vars() {
+        let Some(path) = key.strip_prefix("ARB__") else {
+            continue;
+        };
+        let segments: Vec<String> = path.split("__").map(str::to_lowercase).collect();
+        if segments.iter().any(|s| s.is_empty()) {
+            continue;
+        }
+        let parsed = toml::from_str::<toml::Value>(&format!("v = {}", raw))
+            .ok()
+            .and_then(|v| v.get("v").cloned())
+            .unwrap_or_else(|| toml::Value::String(raw.clone()));
+
+        let (last, parents) = segments.split_last().expect("segments checked non-empty");
+        let Some(root) = value.as_table_mut() else {
+            return;
+        };
+        match descend(root, parents) {
+            Some(table) => {
+                table.insert(last.clone(), parsed);
+                tracing::info!("Config override from env: {}", key);
+            }
+            None => {
+                tracing::warn!("Ignoring env override {}: path collides with a non-table", key);
+            }
+        }
+    }
+}
+
+/// Walk (creating as needed) nested tables down the given path
+fn descend<'a>(
+    table: &'a mut toml::map::Map<String, toml::Value>,
+    path: &[String],
+) -> Option<&'a mut toml::map::Map<String, toml::Value>> {
+    match path.split_first() {
+        None => Some(table),
+        Some((head, rest)) => {
+            let entry = table
+                .entry(head.clone())
+                .or_insert_with(|| toml::Value::Table(toml::map::Map::new()));
+            descend(entry.as_table_mut()?, rest)
+        }
+    }
+}
+
+/// Read a mounted secret file, trimmed of trailing whitespace
+fn read_secret_file(exchange: &str, field: &str, path: &Option<String>) -> Option<String> {
+    let path = path.as_deref()?;
+    match std::fs::read_to_string(path) {
+        Ok(contents) => Some(contents.trim().to_string()),
+        Err(e) => {
+            tracing::warn!(
+                "Could not read {} {} ({}): {}",
+                exchange,
+                field,
+                path,
+                e
+            );
+            None
         }
     }
 }
 
 impl Config {
     pub fn load(path: &str) -> Self {
-        match std::fs::read_to_string(path) {
-            Ok(contents) => toml::from_str(&contents).unwrap_or_else(|e| {
+        let mut value = match std::fs::read_to_string(path) {
+            Ok(contents) => toml::from_str::<toml::Value>(&contents).unwrap_or_else(|e| {
                 tracing::warn!("Failed to parse config file: {}. Using defaults.", e);
-                Self::default()
+                toml::Value::Table(toml::map::Map::new())
             }),
             Err(_) => {
                 tracing::info!("No config file found at {}. Using defaults.", path);
-                Self::default()
+                toml::Value::Table(toml::map::Map::new())
             }
+        };
+        apply_env_overrides(&mut value);
+        let mut config: Self = value.try_into().unwrap_or_else(|e| {
+            tracing::warn!("Failed to interpret config: {}. Using defaults.", e);
+            Self::default()
+        });
+        config.resolve_secret_files();
+        crate::types::set_symbol_overrides(&config.symbol_overrides);
+        config
+    }
+
+    /// Replace inline credentials with the contents of any configured
+    /// `*_file` counterparts, so mounted Docker/Kubernetes secrets work
+    /// without baking credentials into config.toml. An unreadable file
+    /// is logged and the inline value kept.
+    fn resolve_secret_files(&mut self) {
+        for (name, exchange) in self.exchanges.iter_mut() {
+            if let Some(secret) = read_secret_file(name, "api_key_file", &exchange.api_key_file) {
+                exchange.api_key = secret;
+            }
+            if let Some(secret) =
+                read_secret_file(name, "api_secret_file", &exchange.api_secret_file)
+            {
+                exchange.api_secret = secret;
+            }
+            if let Some(secret) =
+                read_secret_file(name, "passphrase_file", &exchange.passphrase_file)
+            {
+                exchange.passphrase = Some(secret);
+            }
+        }
+    }
+
+    /// True when `pair` runs under the stablecoin mode's thresholds
+    pub fn is_stablecoin_pair(&self, pair: &crate::types::TradingPair) -> bool {
+        self.stablecoin.enabled
+            && self
+                .stablecoin
+                .pairs
+                .iter()
+                .filter_map(|p| crate::types::TradingPair::parse(p))
+                .any(|p| p == *pair)
+    }
+
+    /// Per-pair override block for a pair, if configured
+    pub fn pair_overrides_for(&self, pair: &crate::types::TradingPair) -> Option<&PairOverrides> {
+        self.trading.pair_overrides.get(&pair.to_string())
+    }
+
+    /// Minimum net spread threshold for a pair (per-pair override wins,
+    /// stable pairs run tighter)
+    pub fn min_spread_pct_for(&self, pair: &crate::types::TradingPair) -> Decimal {
+        if let Some(pct) = self.pair_overrides_for(pair).and_then(|o| o.min_spread_pct) {
+            return pct;
+        }
+        if self.is_stablecoin_pair(pair) {
+            self.stablecoin.min_spread_pct
+        } else {
+            self.engine.min_spread_pct
+        }
+    }
+
+    /// Per-trade quantity cap for a pair (per-pair override wins, stable
+    /// pairs run larger)
+    pub fn max_trade_qty_for(&self, pair: &crate::types::TradingPair) -> Decimal {
+        if let Some(qty) = self.pair_overrides_for(pair).and_then(|o| o.max_trade_qty) {
+            return qty;
         }
+        if self.is_stablecoin_pair(pair) {
+            self.stablecoin.max_trade_qty
+        } else {
+            self.trading.max_trade_qty
+        }
+    }
+
+    /// Minimum viable quantity for a pair
+    pub fn min_trade_qty_for(&self, pair: &crate::types::TradingPair) -> Decimal {
+        if self.is_stablecoin_pair(pair) {
+            self.stablecoin.min_trade_qty
+        } else {
+            self.trading.min_trade_qty
+        }
+    }
+
+    /// Position limit for a pair (stable pairs carry their own)
+    pub fn max_position_for(&self, pair: &crate::types::TradingPair) -> Decimal {
+        if self.is_stablecoin_pair(pair) {
+            self.stablecoin.max_position
+        } else {
+            self.risk.max_position
+        }
+    }
+
+    /// Post-trade cooldown for a pair
+    pub fn trade_cooldown_ms_for(&self, pair: &crate::types::TradingPair) -> u64 {
+        self.pair_overrides_for(pair)
+            .and_then(|o| o.trade_cooldown_ms)
+            .unwrap_or(self.risk.trade_cooldown_ms)
+    }
+
+    /// False when the pair/exchange combination is excluded via the
+    /// venue's `disabled_pairs` list
+    pub fn pair_enabled_on(
+        &self,
+        exchange: &crate::types::Exchange,
+        pair: &crate::types::TradingPair,
+    ) -> bool {
+        self.get_exchange(exchange)
+            .map(|cfg| {
+                !cfg.disabled_pairs
+                    .iter()
+                    .filter_map(|p| crate::types::TradingPair::parse(p))
+                    .any(|p| p == *pair)
+            })
+            .unwrap_or(true)
+    }
+
+    /// Order type ("limit" or "market") used when executing a pair
+    pub fn order_type_for(&self, pair: &crate::types::TradingPair) -> &str {
+        self.pair_overrides_for(pair)
+            .and_then(|o| o.order_type.as_deref())
+            .unwrap_or(&self.trading.order_type)
+    }
+
+    /// Total cost of moving one unit of `asset` between venues
+    /// (withdrawal fee plus network fee), in asset units
+    pub fn transfer_cost_for(&self, asset: &str) -> Decimal {
+        self.transfer_costs
+            .withdrawal_fee
+            .get(asset)
+            .copied()
+            .unwrap_or(Decimal::ZERO)
+            + self
+                .transfer_costs
+                .network_fee
+                .get(asset)
+                .copied()
+                .unwrap_or(Decimal::ZERO)
     }
 
     pub fn get_exchange(&self, exchange: &crate::types::Exchange) -> Option<&ExchangeConfig> {
@@ -0,0 +1,174 @@
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::Serialize;
+use std::sync::RwLock;
+use tracing::info;
+
+use crate::config::CostModelConfig;
+use crate::types::{ArbitrageOpportunity, TradeResult};
+
+/// Calibrated execution-cost parameters. All costs are per trade (both
+/// legs combined) and expressed in basis points of notional.
+#[derive(Debug, Clone, Serialize)]
+pub struct CostModelParams {
+    /// Flat execution cost beyond fees (realized slippage)
+    pub slippage_bps: Decimal,
+    /// Additional cost per unit of base quantity (price impact)
+    pub impact_bps_per_qty: Decimal,
+    /// Average detection-to-fill latency, ms
+    pub latency_ms: i64,
+    /// When these values were last fitted (None = defaults)
+    pub calibrated_at: Option<DateTime<Utc>>,
+    /// True while a manual override pins these values
+    pub overridden: bool,
+}
+
+impl Default for CostModelParams {
+    fn default() -> Self {
+        Self {
+            slippage_bps: Decimal::ZERO,
+            impact_bps_per_qty: Decimal::ZERO,
+            latency_ms: 0,
+            calibrated_at: None,
+            overridden: false,
+        }
+    }
+}
+
+/// Execution cost model shared between the calibration job (which re-fits
+/// it from recorded fills) and the detector (which charges its penalty in
+/// the actionability math)
+pub struct CostModel {
+    params: RwLock<CostModelParams>,
+    window_days: i64,
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        Self::new(&CostModelConfig::default())
+    }
+}
+
+impl CostModel {
+    pub fn new(config: &CostModelConfig) -> Self {
+        Self {
+            params: RwLock::new(CostModelParams::default()),
+            window_days: config.window_days.max(1) as i64,
+        }
+    }
+
+    /// Current parameters (cheap snapshot)
+    pub fn current(&self) -> CostModelParams {
+        self.params.read().unwrap().clone()
+    }
+
+    /// Total expected execution cost for a trade of `quantity`, in basis
+    /// points of notional — what the detector subtracts from the spread
+    pub fn penalty_bps(&self, quantity: Decimal) -> Decimal {
+        let params = self.params.read().unwrap();
+        params.slippage_bps + params.impact_bps_per_qty * quantity
+    }
+
+    /// Largest quantity whose modeled execution cost still fits inside
+    /// `budget_bps` of spread, or None when the fitted impact is flat
+    /// (quantity is then unconstrained by the model)
+    pub fn impact_limit_qty(&self, budget_bps: Decimal) -> Option<Decimal> {
+        let params = self.params.read().unwrap();
+        if params.impact_bps_per_qty <= Decimal::ZERO {
+            return None;
+        }
+        Some(((budget_bps - params.slippage_bps) / params.impact_bps_per_qty).max(Decimal::ZERO))
+    }
+
+    /// Pin the parameters manually; calibration leaves them alone until
+    /// `clear_override` is called
+    pub fn set_override(&self, slippage_bps: Decimal, impact_bps_per_qty: Decimal) {
+        let mut params = self.params.write().unwrap();
+        params.slippage_bps = slippage_bps;
+        params.impact_bps_per_qty = impact_bps_per_qty;
+        params.overridden = true;
+        info!(
+            "Cost model manually overridden: slippage={} bps, impact={} bps/qty",
+            slippage_bps, impact_bps_per_qty
+        );
+    }
+
+    /// Release a manual override; the next calibration pass takes over
+    pub fn clear_override(&self) {
+        self.params.write().unwrap().overridden = false;
+        info!("Cost model override cleared");
+    }
+
+    /// Re-fit slippage/impact/latency from the trades inside the window,
+    /// matched back to the opportunities that triggered them. Slippage and
+    /// impact come from a least-squares fit of realized adverse move vs
+    /// quantity; latency is the mean detection-to-fill delay. A manual
+    /// override suppresses the update.
+    pub fn calibrate(&self, trades: &[TradeResult], opportunities: &[ArbitrageOpportunity]) {
+        if self.params.read().unwrap().overridden {
+            return;
+        }
+
+        let window_start = Utc::now() - Duration::days(self.window_days);
+        let mut samples: Vec<(Decimal, Decimal)> = Vec::new(); // (qty, slippage bps)
+        let mut latency_sum_ms: i64 = 0;
+
+        for trade in trades {
+            if trade.executed_at < window_start {
+                continue;
+            }
+            let Some(opp) = opportunities.iter().find(|o| o.id == trade.opportunity_id) else {
+                continue;
+            };
+            if opp.buy_price <= Decimal::ZERO || opp.sell_price <= Decimal::ZERO {
+                continue;
+            }
+
+            // Adverse move of both fills vs the detected prices
+            let buy_slip = (trade.buy_price - opp.buy_price) / opp.buy_price;
+            let sell_slip = (opp.sell_price - trade.sell_price) / opp.sell_price;
+            samples.push((trade.quantity, (buy_slip + sell_slip) * dec!(10000)));
+            latency_sum_ms += (trade.executed_at - opp.detected_at).num_milliseconds();
+        }
+
+        if samples.is_empty() {
+            return;
+        }
+
+        let n = Decimal::from(samples.len());
+        let mean_qty: Decimal = samples.iter().map(|(q, _)| *q).sum::<Decimal>() / n;
+        let mean_slip: Decimal = samples.iter().map(|(_, s)| *s).sum::<Decimal>() / n;
+
+        // Least-squares slope of slippage on quantity; flat model when the
+        // quantities don't vary
+        let var_qty: Decimal = samples
+            .iter()
+            .map(|(q, _)| (*q - mean_qty) * (*q - mean_qty))
+            .sum();
+        let impact = if var_qty > Decimal::ZERO {
+            let cov: Decimal = samples
+                .iter()
+                .map(|(q, s)| (*q - mean_qty) * (*s - mean_slip))
+                .sum();
+            (cov / var_qty).max(Decimal::ZERO)
+        } else {
+            Decimal::ZERO
+        };
+        let slippage = (mean_slip - impact * mean_qty).max(Decimal::ZERO);
+        let latency_ms = latency_sum_ms / samples.len() as i64;
+
+        let mut params = self.params.write().unwrap();
+        params.slippage_bps = slippage.round_dp(4);
+        params.impact_bps_per_qty = impact.round_dp(4);
+        params.latency_ms = latency_ms;
+        params.calibrated_at = Some(Utc::now());
+        info!(
+            "Cost model calibrated from {} fills: slippage={} bps, impact={} bps/qty, latency={}ms",
+            samples.len(),
+            params.slippage_bps,
+            params.impact_bps_per_qty,
+            params.latency_ms
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use chrono::{DateTime, Duration as ChronoDuration, TimeZone, Utc};
+use rust_decimal::Decimal;
+use tracing::{info, warn};
+
+use crate::config::Config;
+use crate::exchange::bitget::BITGET_REST_URL;
+use crate::exchange::bybit::BYBIT_REST_URL;
+use crate::recorder;
+use crate::types::{Exchange, MarketType, Ticker, TickerSource, TradingPair};
+
+/// Rows per request — both venues cap their kline endpoints at 1000
+const PAGE_LIMIT: usize = 1_000;
+
+/// Pause between paged requests so a long backfill stays well inside the
+/// public-endpoint rate limits
+const PAGE_DELAY_MS: u64 = 250;
+
+/// One parsed kline: close timestamp bucket start, close price, base volume
+struct Kline {
+    timestamp: DateTime<Utc>,
+    close: Decimal,
+    volume: Decimal,
+}
+
+/// Downloads historical 1-minute klines for the configured pairs from each
+/// enabled exchange's public market-data endpoints and writes them in the
+/// recorder's storage format (hourly gzip JSONL of `Ticker` lines), so
+/// backtests can cover periods from before live recording started.
+///
+/// Klines carry no bid/ask, so each row becomes a ticker with bid, ask and
+/// last all set to the close — backtests over downloaded data should lean
+/// on the slippage model to stand in for the spread.
+pub struct HistoricalDownloader {
+    config: Config,
+    client: reqwest::Client,
+}
+
+impl HistoricalDownloader {
+    pub fn new(config: Config) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Download every configured pair on every enabled venue over
+    /// `[start, end)`, returning how many tickers were written
+    pub async fn run(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> usize {
+        let dir = &self.config.recorder.dir;
+        if let Err(e) = std::fs::create_dir_all(dir) {
+            warn!("Download aborted: could not create {}: {}", dir, e);
+            return 0;
+        }
+        info!(
+            "Downloading historical klines {} — {} into {}",
+            start.format("%Y-%m-%d %H:%M"),
+            end.format("%Y-%m-%d %H:%M"),
+            dir
+        );
+
+        let mut written = 0usize;
+        for exchange in [Exchange::Bybit, Exchange::Bitget] {
+            if !self
+                .config
+                .get_exchange(&exchange)
+                .map(|cfg| cfg.enabled)
+                .unwrap_or(false)
+            {
+                continue;
+            }
+            for pair_str in &self.config.trading.pairs {
+                let Some(pair) = TradingPair::parse(pair_str) else {
+                    continue;
+                };
+                if !self.config.pair_enabled_on(&exchange, &pair) {
+                    continue;
+                }
+                match self.download_pair(exchange, &pair, start, end).await {
+                    Ok(count) => {
+                        info!("Downloaded {} klines for {} on {}", count, pair, exchange);
+                        written += count;
+                    }
+                    Err(e) => warn!("Download failed for {} on {}: {}", pair, exchange, e),
+                }
+            }
+        }
+        info!("Historical download complete: {} tickers written", written);
+        written
+    }
+
+    /// Page through one venue's kline history for one pair, appending each
+    /// page to the recorder files as it arrives
+    async fn download_pair(
+        &self,
+        exchange: Exchange,
+        pair: &TradingPair,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<usize, String> {
+        let mut cursor = start;
+        let mut total = 0usize;
+
+        while cursor < end {
+            let mut klines = match exchange {
+                Exchange::Bybit => self.fetch_bybit(pair, cursor, end).await?,
+                Exchange::Bitget => self.fetch_bitget(pair, cursor, end).await?,
+            };
+            if klines.is_empty() {
+                break;
+            }
+            klines.sort_by_key(|k| k.timestamp);
+            let last = klines.last().map(|k| k.timestamp).unwrap_or(cursor);
+
+            let tickers: Vec<Ticker> = klines
+                .into_iter()
+                .filter(|k| k.close > Decimal::ZERO)
+                .map(|k| Ticker {
+                    exchange,
+                    pair: pair.clone(),
+                    bid: k.close,
+                    ask: k.close,
+                    last: k.close,
+                    volume_24h: k.volume,
+                    timestamp: k.timestamp,
+                    source: TickerSource::RestPoll,
+                })
+                .collect();
+            total += recorder::append_tickers(&self.config.recorder.dir, &tickers)
+                .map_err(|e| e.to_string())?;
+
+            // Advance past the last row; a page that doesn't move the
+            // cursor means the venue has no more data for the range
+            if last < cursor {
+                break;
+            }
+            cursor = last + ChronoDuration::minutes(1);
+            tokio::time::sleep(std::time::Duration::from_millis(PAGE_DELAY_MS)).await;
+        }
+        Ok(total)
+    }
+
+    /// GET /v5/market/kline — rows are `[start_ms, o, h, l, c, vol, turnover]`
+    /// strings, newest first
+    async fn fetch_bybit(
+        &self,
+        pair: &TradingPair,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Kline>, String> {
+        let category = match pair.market {
+            MarketType::Spot => "spot",
+            MarketType::Perpetual => "linear",
+        };
+        let url = format!(
+            "{}/v5/market/kline?category={}&symbol={}&interval=1&start={}&end={}&limit={}",
+            BYBIT_REST_URL,
+            category,
+            pair.symbol_for(Exchange::Bybit),
+            start.timestamp_millis(),
+            end.timestamp_millis(),
+            PAGE_LIMIT
+        );
+        let data = self.fetch_json(&url).await?;
+        if data["retCode"].as_i64() != Some(0) {
+            return Err(format!("Bybit error: {}", data["retMsg"]));
+        }
+        let rows = data["result"]["list"].as_array().cloned().unwrap_or_default();
+        Ok(rows.iter().filter_map(parse_kline_row).collect())
+    }
+
+    /// GET the spot or mix candles endpoint — rows are
+    /// `[ts_ms, o, h, l, c, base_vol, ...]` strings
+    async fn fetch_bitget(
+        &self,
+        pair: &TradingPair,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<Kline>, String> {
+        let symbol = pair.symbol_for(Exchange::Bitget);
+        let url = match pair.market {
+            MarketType::Spot => format!(
+                "{}/api/v2/spot/market/candles?symbol={}&granularity=1min&startTime={}&endTime={}&limit={}",
+                BITGET_REST_URL,
+                symbol,
+                start.timestamp_millis(),
+                end.timestamp_millis(),
+                PAGE_LIMIT
+            ),
+            MarketType::Perpetual => format!(
+                "{}/api/v2/mix/market/candles?symbol={}&productType=USDT-FUTURES&granularity=1m&startTime={}&endTime={}&limit={}",
+                BITGET_REST_URL,
+                symbol,
+                start.timestamp_millis(),
+                end.timestamp_millis(),
+                PAGE_LIMIT
+            ),
+        };
+        let data = self.fetch_json(&url).await?;
+        if data["code"].as_str() != Some("00000") {
+            return Err(format!("Bitget error: {}", data["msg"]));
+        }
+        let rows = data["data"].as_array().cloned().unwrap_or_default();
+        Ok(rows.iter().filter_map(parse_kline_row).collect())
+    }
+
+    async fn fetch_json(&self, url: &str) -> Result<serde_json::Value, String> {
+        self.client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?
+            .json()
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Parse one `[ts_ms, open, high, low, close, volume, ...]` row — both
+/// venues use this shape with string-encoded numbers
+fn parse_kline_row(row: &serde_json::Value) -> Option<Kline> {
+    let cells = row.as_array()?;
+    let ts_ms: i64 = cells.first()?.as_str()?.parse().ok()?;
+    let close: Decimal = cells.get(4)?.as_str()?.parse().ok()?;
+    let volume: Decimal = cells.get(5)?.as_str()?.parse().ok()?;
+    Some(Kline {
+        timestamp: Utc.timestamp_millis_opt(ts_ms).single()?,
+        close,
+        volume,
+    })
+}
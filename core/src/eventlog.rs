@@ -0,0 +1,94 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use tokio::sync::broadcast;
+
+use crate::notify::{NotificationKind, Severity};
+
+/// Most recent events held in the ring buffer for GET /api/events
+const EVENT_LOG_CAP: usize = 1_000;
+
+/// Broadcast buffer for the live WS feed of events
+const EVENT_CHANNEL_CAP: usize = 256;
+
+/// One system event on the activity timeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SystemEvent {
+    /// Monotonic id, for cursoring with `since_id`
+    pub id: u64,
+    pub kind: NotificationKind,
+    pub severity: Severity,
+    pub title: String,
+    pub detail: String,
+    pub at: DateTime<Utc>,
+}
+
+/// Ring buffer of system events — connector disconnects and recoveries,
+/// risk-limit hits, circuit-breaker trips, config changes — so the
+/// dashboard can show an activity timeline rather than only trades.
+///
+/// Served via GET /api/events; each recorded event is also broadcast so
+/// the API layer can push it to WS clients as it happens.
+pub struct SystemEventLog {
+    events: Mutex<VecDeque<SystemEvent>>,
+    seq: AtomicU64,
+    tx: broadcast::Sender<SystemEvent>,
+}
+
+impl Default for SystemEventLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SystemEventLog {
+    pub fn new() -> Self {
+        let (tx, _) = broadcast::channel(EVENT_CHANNEL_CAP);
+        Self {
+            events: Mutex::new(VecDeque::new()),
+            seq: AtomicU64::new(0),
+            tx,
+        }
+    }
+
+    /// Append one event to the ring buffer and the live feed
+    pub fn record(&self, kind: NotificationKind, title: &str, detail: &str) {
+        let event = SystemEvent {
+            id: self.seq.fetch_add(1, Ordering::Relaxed) + 1,
+            kind,
+            severity: kind.severity(),
+            title: title.to_string(),
+            detail: detail.to_string(),
+            at: Utc::now(),
+        };
+        if let Ok(mut events) = self.events.lock() {
+            events.push_back(event.clone());
+            while events.len() > EVENT_LOG_CAP {
+                events.pop_front();
+            }
+        }
+        let _ = self.tx.send(event);
+    }
+
+    /// Subscribe to events as they are recorded
+    pub fn subscribe(&self) -> broadcast::Receiver<SystemEvent> {
+        self.tx.subscribe()
+    }
+
+    /// The most recent events, newest first, optionally only those after
+    /// `since_id`
+    pub fn recent(&self, limit: usize, since_id: Option<u64>) -> Vec<SystemEvent> {
+        let Ok(events) = self.events.lock() else {
+            return Vec::new();
+        };
+        events
+            .iter()
+            .rev()
+            .filter(|e| since_id.map(|id| e.id > id).unwrap_or(true))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
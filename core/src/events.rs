@@ -0,0 +1,137 @@
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+use tracing::{info, warn};
+
+use crate::config::EventsConfig;
+use crate::prices::PriceCache;
+use crate::types::{ArbitrageOpportunity, Ticker, TradeResult};
+
+/// Publishes engine events — tickers, opportunities, trades — as JSON to
+/// an external event bus (NATS or Kafka), so downstream analytics and
+/// alerting consume the stream instead of scraping the REST API.
+///
+/// Topics are `<prefix>.tickers`, `<prefix>.opportunities` and
+/// `<prefix>.trades`. Publishing goes through an internal channel to a
+/// backend task, so a slow or down broker never stalls the trading path;
+/// events that can't be delivered are dropped with a warning.
+pub struct EventBus {
+    config: EventsConfig,
+    tx: mpsc::UnboundedSender<(String, String)>,
+    rx: Mutex<Option<mpsc::UnboundedReceiver<(String, String)>>>,
+}
+
+impl EventBus {
+    pub fn from_config(config: &EventsConfig) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        Self {
+            config: config.clone(),
+            tx,
+            rx: Mutex::new(Some(rx)),
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    pub fn publish_ticker(&self, ticker: &Ticker) {
+        self.publish("tickers", ticker);
+    }
+
+    pub fn publish_opportunity(&self, opp: &ArbitrageOpportunity) {
+        self.publish("opportunities", opp);
+    }
+
+    pub fn publish_trade(&self, trade: &TradeResult) {
+        self.publish("trades", trade);
+    }
+
+    fn publish<T: serde::Serialize>(&self, kind: &str, event: &T) {
+        if !self.config.enabled {
+            return;
+        }
+        let Ok(json) = serde_json::to_string(event) else {
+            return;
+        };
+        let topic = format!("{}.{}", self.config.topic_prefix, kind);
+        let _ = self.tx.send((topic, json));
+    }
+
+    /// Start the backend dispatch task and mirror the ticker stream onto
+    /// the bus; no-op unless enabled
+    pub async fn start(&self, prices: Arc<PriceCache>) {
+        if !self.config.enabled {
+            return;
+        }
+        let Some(rx) = self.rx.lock().await.take() else {
+            return;
+        };
+        match self.config.backend.as_str() {
+            "nats" => {
+                let servers = self.config.servers.clone();
+                tokio::spawn(async move {
+                    dispatch_nats(servers, rx).await;
+                });
+            }
+            "kafka" => {
+                let servers = self.config.servers.clone();
+                // The kafka client is blocking; give it its own thread
+                std::thread::spawn(move || dispatch_kafka(servers, rx));
+            }
+            other => {
+                warn!("Event bus disabled: unknown backend '{}'", other);
+                return;
+            }
+        }
+        info!(
+            "Event bus started ({} → {}, prefix {})",
+            self.config.backend, self.config.servers, self.config.topic_prefix
+        );
+
+        let mut updates = prices.subscribe();
+        use tokio::sync::broadcast::error::RecvError;
+        loop {
+            match updates.recv().await {
+                Ok(ticker) => self.publish_ticker(&ticker),
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+}
+
+async fn dispatch_nats(servers: String, mut rx: mpsc::UnboundedReceiver<(String, String)>) {
+    let client = match async_nats::connect(&servers).await {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("Event bus disabled: could not connect to NATS {}: {}", servers, e);
+            return;
+        }
+    };
+    while let Some((topic, json)) = rx.recv().await {
+        if let Err(e) = client.publish(topic, json.into()).await {
+            warn!("NATS publish failed: {}", e);
+        }
+    }
+}
+
+fn dispatch_kafka(servers: String, mut rx: mpsc::UnboundedReceiver<(String, String)>) {
+    use kafka::producer::{Producer, Record, RequiredAcks};
+
+    let hosts: Vec<String> = servers.split(',').map(|h| h.trim().to_string()).collect();
+    let mut producer = match Producer::from_hosts(hosts)
+        .with_required_acks(RequiredAcks::One)
+        .create()
+    {
+        Ok(producer) => producer,
+        Err(e) => {
+            warn!("Event bus disabled: could not connect to Kafka {}: {}", servers, e);
+            return;
+        }
+    };
+    while let Some((topic, json)) = rx.blocking_recv() {
+        if let Err(e) = producer.send(&Record::from_value(&topic, json.as_bytes())) {
+            warn!("Kafka publish failed: {}", e);
+        }
+    }
+}
@@ -1,29 +1,186 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
 use futures_util::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
-use tracing::{debug, error, info, warn};
+use tracing::{error, info, warn};
 
+use crate::audit::OrderAuditLog;
 use crate::config::ExchangeConfig;
-use crate::exchange::{ExchangeConnector, ExchangeError};
+use crate::exchange::{
+    ms_to_utc, parse_levels, ExchangeConnector, ExchangeError, ParseErrorCounter, RetryPolicy,
+    TimeSync,
+};
 use crate::types::*;
 
 const BITGET_WS_URL: &str = "wss://ws.bitget.com/v2/ws/public";
-const BITGET_REST_URL: &str = "https://api.bitget.com";
+pub(crate) const BITGET_REST_URL: &str = "https://api.bitget.com";
+/// Bitget API version this connector targets
+const BITGET_API_VERSION: &str = "v2";
+/// Levels each side included in Bitget's depth checksum
+const CHECKSUM_DEPTH: usize = 25;
+/// Serve the locally maintained book only while it is this fresh (ms)
+const LOCAL_BOOK_FRESH_MS: i64 = 5_000;
+
+/// One side of the locally maintained book. Raw price/qty strings are kept
+/// alongside the parsed values because the checksum is computed over the
+/// exchange's exact string representation.
+#[derive(Debug, Clone)]
+struct RawLevel {
+    price_raw: String,
+    qty_raw: String,
+    qty: Decimal,
+}
+
+/// Locally maintained order book fed by the "books" depth channel
+#[derive(Debug, Default)]
+struct LocalBook {
+    /// Keyed by price; bids iterated descending, asks ascending
+    bids: BTreeMap<Decimal, RawLevel>,
+    asks: BTreeMap<Decimal, RawLevel>,
+}
+
+impl LocalBook {
+    fn apply(&mut self, data: &serde_json::Value, snapshot: bool) {
+        if snapshot {
+            self.bids.clear();
+            self.asks.clear();
+        }
+        apply_book_side(&mut self.bids, &data["bids"]);
+        apply_book_side(&mut self.asks, &data["asks"]);
+    }
+
+    /// CRC32 over the top 25 levels a side, bid and ask interleaved as
+    /// "price:qty:price:qty:…", matching Bitget's published formula. The
+    /// exchange transmits the sum as a signed 32-bit integer.
+    fn checksum(&self) -> i32 {
+        let bids: Vec<&RawLevel> = self.bids.values().rev().take(CHECKSUM_DEPTH).collect();
+        let asks: Vec<&RawLevel> = self.asks.values().take(CHECKSUM_DEPTH).collect();
+
+        let mut parts: Vec<&str> = Vec::with_capacity(CHECKSUM_DEPTH * 4);
+        for i in 0..CHECKSUM_DEPTH {
+            if let Some(bid) = bids.get(i) {
+                parts.push(&bid.price_raw);
+                parts.push(&bid.qty_raw);
+            }
+            if let Some(ask) = asks.get(i) {
+                parts.push(&ask.price_raw);
+                parts.push(&ask.qty_raw);
+            }
+        }
+        crc32fast::hash(parts.join(":").as_bytes()) as i32
+    }
+
+    /// Snapshot as the shared OrderBook type
+    fn to_order_book(&self, pair: &TradingPair) -> OrderBook {
+        OrderBook {
+            exchange: Exchange::Bitget,
+            pair: pair.clone(),
+            bids: self
+                .bids
+                .iter()
+                .rev()
+                .map(|(price, lvl)| OrderBookLevel {
+                    price: *price,
+                    qty: lvl.qty,
+                })
+                .collect(),
+            asks: self
+                .asks
+                .iter()
+                .map(|(price, lvl)| OrderBookLevel {
+                    price: *price,
+                    qty: lvl.qty,
+                })
+                .collect(),
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// Apply `[["price","qty"], …]` deltas to one book side; qty 0 removes
+fn apply_book_side(side: &mut BTreeMap<Decimal, RawLevel>, levels: &serde_json::Value) {
+    let Some(arr) = levels.as_array() else {
+        return;
+    };
+    for lvl in arr {
+        let (Some(price_raw), Some(qty_raw)) = (lvl[0].as_str(), lvl[1].as_str()) else {
+            continue;
+        };
+        let (Ok(price), Ok(qty)) = (price_raw.parse::<Decimal>(), qty_raw.parse::<Decimal>())
+        else {
+            continue;
+        };
+        if qty.is_zero() {
+            side.remove(&price);
+        } else {
+            side.insert(
+                price,
+                RawLevel {
+                    price_raw: price_raw.to_string(),
+                    qty_raw: qty_raw.to_string(),
+                    qty,
+                },
+            );
+        }
+    }
+}
 
 pub struct BitgetConnector {
     config: ExchangeConfig,
     client: reqwest::Client,
+    parse_errors: Arc<ParseErrorCounter>,
+    retry: RetryPolicy,
+    time_sync: Arc<TimeSync>,
+    /// Epoch ms of the last WS message received (0 = none yet)
+    last_ws_message: Arc<AtomicI64>,
+    /// Checksum-validated books maintained from the depth channel
+    local_books: Arc<DashMap<String, (OrderBook, i64)>>,
+    /// Depth checksum mismatches since startup (data-integrity metric)
+    checksum_failures: Arc<AtomicU64>,
+    /// WS reconnect attempts since startup
+    ws_reconnects: Arc<AtomicU64>,
+    /// Order request/response audit trail, when one is attached
+    audit: Option<Arc<OrderAuditLog>>,
 }
 
 impl BitgetConnector {
-    pub fn new(config: ExchangeConfig) -> Self {
+    pub fn new(
+        config: ExchangeConfig,
+        retry: RetryPolicy,
+        audit: Option<Arc<OrderAuditLog>>,
+    ) -> Self {
         Self {
             config,
             client: reqwest::Client::new(),
+            parse_errors: Arc::new(ParseErrorCounter::default()),
+            retry,
+            time_sync: Arc::new(TimeSync::default()),
+            last_ws_message: Arc::new(AtomicI64::new(0)),
+            local_books: Arc::new(DashMap::new()),
+            checksum_failures: Arc::new(AtomicU64::new(0)),
+            ws_reconnects: Arc::new(AtomicU64::new(0)),
+            audit,
+        }
+    }
+
+    /// Record an outbound mutating request and the raw venue response on
+    /// the audit trail, when one is attached
+    async fn audit_record(
+        &self,
+        endpoint: &str,
+        request: &serde_json::Value,
+        response: &serde_json::Value,
+    ) {
+        if let Some(audit) = &self.audit {
+            audit
+                .record(Exchange::Bitget, endpoint, request, response)
+                .await;
         }
     }
 
@@ -39,26 +196,698 @@ impl BitgetConnector {
         mac.update(prehash.as_bytes());
         base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes())
     }
-}
 
-/// Bitget V2 WebSocket ticker response
-#[derive(Debug, Deserialize)]
-struct BitgetWsResponse {
-    data: Option<Vec<BitgetTickerData>>,
-}
+    // REST operation bodies — called through the shared retry policy
+    async fn fetch_ticker(&self, pair: &TradingPair) -> Result<Ticker, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bitget);
+        let url = match pair.market {
+            MarketType::Spot => format!(
+                "{}/api/v2/spot/market/tickers?symbol={}",
+                BITGET_REST_URL, symbol
+            ),
+            MarketType::Perpetual => format!(
+                "{}/api/v2/mix/market/ticker?productType=USDT-FUTURES&symbol={}",
+                BITGET_REST_URL, symbol
+            ),
+        };
+
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::Connection(e.to_string()))?;
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| ExchangeError::Parse(e.to_string()))?;
+
+        let item = &data["data"][0];
+        let strict = self.config.strict_parse;
+        if strict && !item.is_object() {
+            self.parse_errors
+                .record(Exchange::Bitget, "unexpected tickers response shape");
+            return Err(ExchangeError::Parse(
+                "unexpected tickers response shape".to_string(),
+            ));
+        }
 
-#[derive(Debug, Deserialize)]
-struct BitgetTickerData {
-    #[serde(rename = "instId")]
-    inst_id: Option<String>,
-    #[serde(rename = "bestBid")]
-    best_bid: Option<String>,
-    #[serde(rename = "bestAsk")]
-    best_ask: Option<String>,
-    #[serde(rename = "lastPr")]
-    last_price: Option<String>,
-    #[serde(rename = "baseVolume")]
-    base_volume: Option<String>,
+        Ok(Ticker {
+            exchange: Exchange::Bitget,
+            pair: pair.clone(),
+            bid: self.parse_errors.parse_field(
+                strict, Exchange::Bitget, "bestBid",
+                // Spot tickers use bestBid/bestAsk, mix tickers bidPr/askPr
+                item["bestBid"].as_str().or_else(|| item["bidPr"].as_str()), true,
+            ),
+            ask: self.parse_errors.parse_field(
+                strict, Exchange::Bitget, "bestAsk",
+                item["bestAsk"].as_str().or_else(|| item["askPr"].as_str()), true,
+            ),
+            last: self.parse_errors.parse_field(
+                strict, Exchange::Bitget, "lastPr", item["lastPr"].as_str(), true,
+            ),
+            volume_24h: self.parse_errors.parse_field(
+                strict, Exchange::Bitget, "baseVolume", item["baseVolume"].as_str(), false,
+            ),
+            timestamp: Utc::now(),
+            source: TickerSource::RestPoll,
+        })
+    }
+
+    async fn fetch_order_book(
+        &self,
+        pair: &TradingPair,
+        depth: u32,
+    ) -> Result<OrderBook, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bitget);
+        let url = format!(
+            "{}/api/v2/spot/market/orderbook?symbol={}&limit={}",
+            BITGET_REST_URL, symbol, depth
+        );
+
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::Connection(e.to_string()))?;
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| ExchangeError::Parse(e.to_string()))?;
+
+        Ok(OrderBook {
+            exchange: Exchange::Bitget,
+            pair: pair.clone(),
+            bids: parse_levels(&data["data"]["bids"]),
+            asks: parse_levels(&data["data"]["asks"]),
+            timestamp: Utc::now(),
+        })
+    }
+
+    async fn fetch_funding_rate(&self, pair: &TradingPair) -> Result<FundingRate, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bitget);
+        let url = format!(
+            "{}/api/v2/mix/market/current-fund-rate?productType=USDT-FUTURES&symbol={}",
+            BITGET_REST_URL, symbol
+        );
+
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::Connection(e.to_string()))?;
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| ExchangeError::Parse(e.to_string()))?;
+
+        let rate: Decimal = data["data"][0]["fundingRate"]
+            .as_str()
+            .and_then(|r| r.parse().ok())
+            .ok_or_else(|| ExchangeError::Parse("missing fundingRate".to_string()))?;
+
+        Ok(FundingRate {
+            exchange: Exchange::Bitget,
+            pair: pair.clone(),
+            rate,
+            interval_hours: 8,
+            timestamp: Utc::now(),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn submit_order(
+        &self,
+        pair: &TradingPair,
+        side: OrderSide,
+        order_type: OrderType,
+        quantity: Decimal,
+        price: Option<Decimal>,
+        margin: bool,
+        client_order_id: &str,
+    ) -> Result<Order, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bitget);
+
+        let mut body = serde_json::json!({
+            "symbol": symbol,
+            "side": match side { OrderSide::Buy => "buy", OrderSide::Sell => "sell" },
+            "orderType": match order_type { OrderType::Market => "market", OrderType::Limit => "limit" },
+            "size": quantity.to_string(),
+            "force": "gtc",
+            "clientOid": client_order_id,
+        });
+
+        // Perpetual orders go through the mix API and need margin settings
+        if pair.market == MarketType::Perpetual {
+            body["productType"] = serde_json::Value::String("USDT-FUTURES".to_string());
+            body["marginMode"] = serde_json::Value::String("crossed".to_string());
+            body["marginCoin"] = serde_json::Value::String(pair.quote.clone());
+        }
+
+        // Crossed-margin orders auto-borrow the sold asset when needed
+        if margin {
+            body["loanType"] = serde_json::Value::String("autoLoan".to_string());
+        }
+
+        if let Some(p) = price {
+            body["price"] = serde_json::Value::String(p.to_string());
+        }
+
+        let path = if margin {
+            "/api/v2/margin/crossed/place-order"
+        } else {
+            match pair.market {
+                MarketType::Spot => "/api/v2/spot/trade/place-order",
+                MarketType::Perpetual => "/api/v2/mix/order/place-order",
+            }
+        };
+
+        let data = self.signed_post(path, &body).await?;
+
+        if data["code"].as_str() == Some("00000") {
+            let now = Utc::now();
+            Ok(Order {
+                id: data["data"]["orderId"]
+                    .as_str()
+                    .unwrap_or("unknown")
+                    .to_string(),
+                client_order_id: data["data"]["clientOid"]
+                    .as_str()
+                    .filter(|s| !s.is_empty())
+                    .map(String::from)
+                    .or_else(|| Some(client_order_id.to_string())),
+                exchange: Exchange::Bitget,
+                pair: pair.clone(),
+                side,
+                order_type,
+                price,
+                quantity,
+                filled_qty: Decimal::ZERO,
+                status: OrderStatus::New,
+                created_at: now,
+                updated_at: now,
+            })
+        } else {
+            Err(ExchangeError::OrderFailed(
+                data["msg"]
+                    .as_str()
+                    .unwrap_or("Unknown error")
+                    .to_string(),
+            ))
+        }
+    }
+
+    /// Normalize a Bitget order-list payload onto `Order`
+    fn parse_order_rows(pair: &TradingPair, list: &serde_json::Value) -> Vec<Order> {
+        list.as_array()
+            .unwrap_or(&vec![])
+            .iter()
+            .filter_map(|row| {
+                Some(Order {
+                    id: row["orderId"].as_str()?.to_string(),
+                    client_order_id: row["clientOid"]
+                        .as_str()
+                        .filter(|s| !s.is_empty())
+                        .map(String::from),
+                    exchange: Exchange::Bitget,
+                    pair: pair.clone(),
+                    side: match row["side"].as_str()? {
+                        "buy" => OrderSide::Buy,
+                        _ => OrderSide::Sell,
+                    },
+                    order_type: match row["orderType"].as_str() {
+                        Some("limit") => OrderType::Limit,
+                        _ => OrderType::Market,
+                    },
+                    price: row["price"]
+                        .as_str()
+                        .or_else(|| row["priceAvg"].as_str())
+                        .and_then(|p| p.parse().ok()),
+                    quantity: row["size"].as_str()?.parse().ok()?,
+                    filled_qty: row["baseVolume"]
+                        .as_str()
+                        .and_then(|q| q.parse().ok())
+                        .unwrap_or(Decimal::ZERO),
+                    status: match row["status"].as_str() {
+                        Some("partially_filled") | Some("partial_fill") => {
+                            OrderStatus::PartiallyFilled
+                        }
+                        Some("filled") | Some("full_fill") => OrderStatus::Filled,
+                        Some("cancelled") | Some("canceled") => OrderStatus::Cancelled,
+                        Some("rejected") => OrderStatus::Rejected,
+                        _ => OrderStatus::New,
+                    },
+                    created_at: ms_to_utc(&row["cTime"]),
+                    updated_at: ms_to_utc(&row["uTime"]),
+                })
+            })
+            .collect()
+    }
+
+    async fn fetch_open_orders(&self, pair: &TradingPair) -> Result<Vec<Order>, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bitget);
+        let (data, list) = match pair.market {
+            MarketType::Spot => {
+                let data = self
+                    .signed_get(
+                        "/api/v2/spot/trade/unfilled-orders",
+                        &format!("symbol={}", symbol),
+                    )
+                    .await?;
+                let list = data["data"].clone();
+                (data, list)
+            }
+            MarketType::Perpetual => {
+                let data = self
+                    .signed_get(
+                        "/api/v2/mix/order/orders-pending",
+                        &format!("symbol={}&productType=USDT-FUTURES", symbol),
+                    )
+                    .await?;
+                let list = data["data"]["entrustedList"].clone();
+                (data, list)
+            }
+        };
+
+        if data["code"].as_str() != Some("00000") {
+            return Err(ExchangeError::Api(
+                data["msg"].as_str().unwrap_or("Unknown error").to_string(),
+            ));
+        }
+
+        Ok(Self::parse_order_rows(pair, &list))
+    }
+
+    async fn fetch_order_history(
+        &self,
+        pair: &TradingPair,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<Order>, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bitget);
+        let start_ms = since.timestamp_millis();
+        let (data, list) = match pair.market {
+            MarketType::Spot => {
+                let data = self
+                    .signed_get(
+                        "/api/v2/spot/trade/history-orders",
+                        &format!("symbol={}&startTime={}", symbol, start_ms),
+                    )
+                    .await?;
+                let list = data["data"].clone();
+                (data, list)
+            }
+            MarketType::Perpetual => {
+                let data = self
+                    .signed_get(
+                        "/api/v2/mix/order/orders-history",
+                        &format!(
+                            "symbol={}&productType=USDT-FUTURES&startTime={}",
+                            symbol, start_ms
+                        ),
+                    )
+                    .await?;
+                let list = data["data"]["entrustedList"].clone();
+                (data, list)
+            }
+        };
+
+        if data["code"].as_str() != Some("00000") {
+            return Err(ExchangeError::Api(
+                data["msg"].as_str().unwrap_or("Unknown error").to_string(),
+            ));
+        }
+
+        Ok(Self::parse_order_rows(pair, &list))
+    }
+
+    async fn fetch_order_fill(
+        &self,
+        pair: &TradingPair,
+        order_id: &str,
+    ) -> Result<OrderFill, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bitget);
+        let row = match pair.market {
+            MarketType::Spot => {
+                let data = self
+                    .signed_get(
+                        "/api/v2/spot/trade/orderInfo",
+                        &format!("orderId={}", order_id),
+                    )
+                    .await?;
+                if data["code"].as_str() != Some("00000") {
+                    return Err(ExchangeError::Api(
+                        data["msg"].as_str().unwrap_or("Unknown error").to_string(),
+                    ));
+                }
+                data["data"]
+                    .as_array()
+                    .and_then(|l| l.first())
+                    .cloned()
+                    .ok_or_else(|| {
+                        ExchangeError::Api(format!("Order {} not found on Bitget", order_id))
+                    })?
+            }
+            MarketType::Perpetual => {
+                let data = self
+                    .signed_get(
+                        "/api/v2/mix/order/detail",
+                        &format!(
+                            "symbol={}&productType=USDT-FUTURES&orderId={}",
+                            symbol, order_id
+                        ),
+                    )
+                    .await?;
+                if data["code"].as_str() != Some("00000") {
+                    return Err(ExchangeError::Api(
+                        data["msg"].as_str().unwrap_or("Unknown error").to_string(),
+                    ));
+                }
+                data["data"].clone()
+            }
+        };
+
+        let avg_price: Decimal = row["priceAvg"]
+            .as_str()
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(Decimal::ZERO);
+        let filled_qty: Decimal = row["baseVolume"]
+            .as_str()
+            .and_then(|q| q.parse().ok())
+            .unwrap_or(Decimal::ZERO);
+
+        // Spot fees arrive as a JSON-encoded `feeDetail` string keyed by
+        // fee currency; perp fees as a plain `fee` field. Buy-side fees
+        // charged in the base asset are normalized into quote.
+        let mut fee = row["fee"]
+            .as_str()
+            .and_then(|f| f.parse::<Decimal>().ok())
+            .map(|f| f.abs())
+            .unwrap_or(Decimal::ZERO);
+        if fee == Decimal::ZERO {
+            if let Some(detail) = row["feeDetail"]
+                .as_str()
+                .and_then(|raw| serde_json::from_str::<serde_json::Value>(raw).ok())
+            {
+                if let Some(entries) = detail.as_object() {
+                    for (currency, entry) in entries {
+                        let total: Decimal = entry["totalFee"]
+                            .as_str()
+                            .and_then(|f| f.parse().ok())
+                            .or_else(|| {
+                                entry["totalFee"]
+                                    .as_f64()
+                                    .and_then(|f| Decimal::try_from(f).ok())
+                            })
+                            .unwrap_or(Decimal::ZERO)
+                            .abs();
+                        if currency.eq_ignore_ascii_case(&pair.base) {
+                            fee += total * avg_price;
+                        } else {
+                            fee += total;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(OrderFill {
+            order_id: order_id.to_string(),
+            avg_price,
+            filled_qty,
+            fee,
+        })
+    }
+
+    async fn do_cancel_order(
+        &self,
+        pair: &TradingPair,
+        order_id: &str,
+    ) -> Result<(), ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bitget);
+        let (path, body) = match pair.market {
+            MarketType::Spot => (
+                "/api/v2/spot/trade/cancel-order",
+                serde_json::json!({ "symbol": symbol, "orderId": order_id }),
+            ),
+            MarketType::Perpetual => (
+                "/api/v2/mix/order/cancel-order",
+                serde_json::json!({
+                    "symbol": symbol,
+                    "productType": "USDT-FUTURES",
+                    "orderId": order_id,
+                }),
+            ),
+        };
+
+        let data = self.signed_post(path, &body).await?;
+
+        if data["code"].as_str() == Some("00000") {
+            Ok(())
+        } else {
+            Err(ExchangeError::OrderFailed(
+                data["msg"]
+                    .as_str()
+                    .unwrap_or("Unknown error")
+                    .to_string(),
+            ))
+        }
+    }
+
+    async fn do_withdraw(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        address: &str,
+        network: &str,
+    ) -> Result<String, ExchangeError> {
+        let body = serde_json::json!({
+            "coin": asset,
+            "transferType": "on_chain",
+            "address": address,
+            "chain": network,
+            "size": amount.to_string(),
+        });
+
+        let data = self
+            .signed_post("/api/v2/spot/wallet/withdrawal", &body)
+            .await?;
+
+        if data["code"].as_str() == Some("00000") {
+            Ok(data["data"]["orderId"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string())
+        } else {
+            Err(ExchangeError::Api(
+                data["msg"].as_str().unwrap_or("Unknown error").to_string(),
+            ))
+        }
+    }
+
+    async fn fetch_deposit_address(
+        &self,
+        asset: &str,
+        network: &str,
+    ) -> Result<DepositAddress, ExchangeError> {
+        let data = self
+            .signed_get(
+                "/api/v2/spot/wallet/deposit-address",
+                &format!("coin={}&chain={}", asset, network),
+            )
+            .await?;
+
+        if data["code"].as_str() != Some("00000") {
+            return Err(ExchangeError::Api(
+                data["msg"].as_str().unwrap_or("Unknown error").to_string(),
+            ));
+        }
+
+        let entry = &data["data"];
+        Ok(DepositAddress {
+            exchange: Exchange::Bitget,
+            asset: asset.to_string(),
+            network: entry["chain"].as_str().unwrap_or(network).to_string(),
+            address: entry["address"].as_str().unwrap_or_default().to_string(),
+            tag: entry["tag"]
+                .as_str()
+                .filter(|t| !t.is_empty())
+                .map(|t| t.to_string()),
+        })
+    }
+
+    /// Signed POST against the Bitget private REST API
+    async fn signed_post(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> Result<serde_json::Value, ExchangeError> {
+        let timestamp = self.time_sync.now_ms();
+        let body_str = serde_json::to_string(body).unwrap();
+        let signature = self.sign_request(timestamp, "POST", path, &body_str);
+
+        let url = format!("{}{}", BITGET_REST_URL, path);
+
+        let resp = match self
+            .client
+            .post(&url)
+            .header("ACCESS-KEY", &self.config.api_key)
+            .header("ACCESS-SIGN", &signature)
+            .header("ACCESS-TIMESTAMP", timestamp.to_string())
+            .header(
+                "ACCESS-PASSPHRASE",
+                self.config.passphrase.as_deref().unwrap_or(""),
+            )
+            .header("Content-Type", "application/json")
+            .body(body_str)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                self.audit_record(path, body, &serde_json::json!({ "transport_error": e.to_string() }))
+                    .await;
+                return Err(ExchangeError::Connection(e.to_string()));
+            }
+        };
+
+        let data: serde_json::Value = match resp.json().await {
+            Ok(data) => data,
+            Err(e) => {
+                self.audit_record(path, body, &serde_json::json!({ "parse_error": e.to_string() }))
+                    .await;
+                return Err(ExchangeError::Parse(e.to_string()));
+            }
+        };
+        self.audit_record(path, body, &data).await;
+        Ok(data)
+    }
+
+    /// Signed GET against the Bitget private REST API
+    async fn signed_get(
+        &self,
+        path: &str,
+        query: &str,
+    ) -> Result<serde_json::Value, ExchangeError> {
+        let timestamp = self.time_sync.now_ms();
+        // Bitget signs the full request path including the query string
+        let request_path = if query.is_empty() {
+            path.to_string()
+        } else {
+            format!("{}?{}", path, query)
+        };
+        let signature = self.sign_request(timestamp, "GET", &request_path, "");
+
+        let url = format!("{}{}", BITGET_REST_URL, request_path);
+
+        let resp = self
+            .client
+            .get(&url)
+            .header("ACCESS-KEY", &self.config.api_key)
+            .header("ACCESS-SIGN", &signature)
+            .header("ACCESS-TIMESTAMP", timestamp.to_string())
+            .header(
+                "ACCESS-PASSPHRASE",
+                self.config.passphrase.as_deref().unwrap_or(""),
+            )
+            .send()
+            .await
+            .map_err(|e| ExchangeError::Connection(e.to_string()))?;
+
+        resp.json()
+            .await
+            .map_err(|e| ExchangeError::Parse(e.to_string()))
+    }
+
+    async fn fetch_balances(&self) -> Result<Vec<ExchangeBalance>, ExchangeError> {
+        let data = self.signed_get("/api/v2/spot/account/assets", "").await?;
+
+        let balances = data["data"]
+            .as_array()
+            .unwrap_or(&vec![])
+            .iter()
+            .filter_map(|b| {
+                let free: Decimal = b["available"].as_str()?.parse().ok()?;
+                let locked: Decimal = b["frozen"].as_str()?.parse().ok()?;
+                if free + locked > Decimal::ZERO {
+                    Some(ExchangeBalance {
+                        exchange: Exchange::Bitget,
+                        asset: b["coin"].as_str()?.to_string(),
+                        free,
+                        locked,
+                        total: free + locked,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        Ok(balances)
+    }
+
+    async fn fetch_account_events(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<AccountEvent>, ExchangeError> {
+        let start_ms = since.timestamp_millis();
+        let end_ms = Utc::now().timestamp_millis();
+        let mut events = Vec::new();
+
+        // Deposits
+        let data = self
+            .signed_get(
+                "/api/v2/spot/wallet/deposit-records",
+                &format!("startTime={}&endTime={}", start_ms, end_ms),
+            )
+            .await?;
+        if let Some(rows) = data["data"].as_array() {
+            for row in rows {
+                let amount: Decimal =
+                    row["size"].as_str().unwrap_or("0").parse().unwrap_or(Decimal::ZERO);
+                events.push(AccountEvent {
+                    id: format!("bitget-dep-{}", row["orderId"].as_str().unwrap_or("")),
+                    exchange: Exchange::Bitget,
+                    kind: AccountEventKind::Deposit,
+                    asset: row["coin"].as_str().unwrap_or("").to_string(),
+                    amount,
+                    timestamp: ms_to_utc(&row["uTime"]),
+                });
+            }
+        }
+
+        // Withdrawals
+        let data = self
+            .signed_get(
+                "/api/v2/spot/wallet/withdrawal-records",
+                &format!("startTime={}&endTime={}", start_ms, end_ms),
+            )
+            .await?;
+        if let Some(rows) = data["data"].as_array() {
+            for row in rows {
+                let amount: Decimal =
+                    row["size"].as_str().unwrap_or("0").parse().unwrap_or(Decimal::ZERO);
+                events.push(AccountEvent {
+                    id: format!("bitget-wd-{}", row["orderId"].as_str().unwrap_or("")),
+                    exchange: Exchange::Bitget,
+                    kind: AccountEventKind::Withdrawal,
+                    asset: row["coin"].as_str().unwrap_or("").to_string(),
+                    amount: -amount,
+                    timestamp: ms_to_utc(&row["uTime"]),
+                });
+            }
+        }
+
+        // Bitget's fills endpoint requires a symbol per query, so external
+        // trades are picked up by per-pair reconciliation rather than here.
+
+        Ok(events)
+    }
 }
 
 #[async_trait]
@@ -73,16 +902,35 @@ impl ExchangeConnector for BitgetConnector {
     ) -> Result<mpsc::UnboundedReceiver<Ticker>, ExchangeError> {
         let symbol = pair.symbol_for(Exchange::Bitget);
         let url = BITGET_WS_URL.to_string();
+        let inst_type = match pair.market {
+            MarketType::Spot => "SPOT",
+            MarketType::Perpetual => "USDT-FUTURES",
+        };
         let pair_clone = pair.clone();
+        // The depth channel rides on the same connection so the local book
+        // can be maintained and checksum-validated alongside the ticker
         let subscribe_msg = serde_json::json!({
             "op": "subscribe",
             "args": [{
-                "instType": "SPOT",
+                "instType": inst_type,
                 "channel": "ticker",
                 "instId": symbol
+            }, {
+                "instType": inst_type,
+                "channel": "books",
+                "instId": symbol
             }]
         });
 
+        let strict = self.config.strict_parse;
+        let parse_errors = self.parse_errors.clone();
+        let stale_secs = self.config.ws_stale_secs.max(5);
+        let stale_window = std::time::Duration::from_secs(stale_secs);
+        let last_msg = self.last_ws_message.clone();
+        let reconnects = self.ws_reconnects.clone();
+        let local_books = self.local_books.clone();
+        let checksum_failures = self.checksum_failures.clone();
+
         let (tx, rx) = mpsc::unbounded_channel();
 
         tokio::spawn(async move {
@@ -94,7 +942,7 @@ impl ExchangeConnector for BitgetConnector {
 
                         // Send subscription
                         let sub_text = serde_json::to_string(&subscribe_msg).unwrap();
-                        if let Err(e) = write.send(Message::Text(sub_text.into())).await {
+                        if let Err(e) = write.send(Message::Text(sub_text)).await {
                             error!("Failed to subscribe on Bitget: {}", e);
                             break;
                         }
@@ -114,8 +962,25 @@ impl ExchangeConnector for BitgetConnector {
                         });
 
                         let mut msg_count: u64 = 0;
+                        let mut book = LocalBook::default();
+
+                        loop {
+                            let msg = match tokio::time::timeout(stale_window, read.next()).await {
+                                Ok(Some(m)) => m,
+                                // Stream ended — reconnect
+                                Ok(None) => break,
+                                // No traffic within the stale window: the socket
+                                // may be silently dead, so force a reconnect
+                                Err(_) => {
+                                    error!(
+                                        "[Bitget] No WS message for {}s on {} — stale feed, forcing reconnect",
+                                        stale_secs, symbol
+                                    );
+                                    break;
+                                }
+                            };
+                            last_msg.store(Utc::now().timestamp_millis(), Ordering::Relaxed);
 
-                        while let Some(msg) = read.next().await {
                             match msg {
                                 Ok(Message::Text(text)) => {
                                     // Skip pong responses and subscription confirmations
@@ -132,6 +997,47 @@ impl ExchangeConnector for BitgetConnector {
                                     if let Ok(json) = serde_json::from_str::<serde_json::Value>(&text) {
                                         let data_arr = &json["data"];
 
+                                        // Depth channel: maintain the local book
+                                        // and validate it against the exchange
+                                        // checksum on every frame
+                                        if json["arg"]["channel"].as_str() == Some("books") {
+                                            let snapshot =
+                                                json["action"].as_str() == Some("snapshot");
+                                            let Some(frame) =
+                                                data_arr.as_array().and_then(|a| a.first())
+                                            else {
+                                                continue;
+                                            };
+                                            book.apply(frame, snapshot);
+
+                                            if let Some(expected) =
+                                                frame["checksum"].as_i64().map(|c| c as i32)
+                                            {
+                                                let actual = book.checksum();
+                                                if actual != expected {
+                                                    let n = checksum_failures
+                                                        .fetch_add(1, Ordering::Relaxed)
+                                                        + 1;
+                                                    warn!(
+                                                        "[Bitget] Book checksum mismatch on {} (local {:#x}, exchange {:#x}, {} total) — resyncing",
+                                                        symbol, actual, expected, n
+                                                    );
+                                                    // Reconnecting replays a fresh
+                                                    // snapshot, which resyncs the book
+                                                    break;
+                                                }
+                                            }
+
+                                            local_books.insert(
+                                                symbol.clone(),
+                                                (
+                                                    book.to_order_book(&pair_clone),
+                                                    Utc::now().timestamp_millis(),
+                                                ),
+                                            );
+                                            continue;
+                                        }
+
                                         // On first message, log field names from first data element
                                         if msg_count == 1 {
                                             if let Some(arr) = data_arr.as_array() {
@@ -148,29 +1054,42 @@ impl ExchangeConnector for BitgetConnector {
                                         if let Some(arr) = data_arr.as_array() {
                                             for item in arr {
                                                 // Use correct Bitget V2 field names: bidPr, askPr, lastPr, baseVolume
-                                                let bid_str = item["bidPr"].as_str()
-                                                    .or_else(|| item["bestBid"].as_str())
-                                                    .unwrap_or("0");
-                                                let ask_str = item["askPr"].as_str()
-                                                    .or_else(|| item["bestAsk"].as_str())
-                                                    .unwrap_or("0");
-                                                let last_str = item["lastPr"].as_str()
-                                                    .or_else(|| item["last"].as_str())
-                                                    .unwrap_or("0");
-                                                let vol_str = item["baseVolume"].as_str()
-                                                    .or_else(|| item["baseVol"].as_str())
-                                                    .unwrap_or("0");
-
-                                                let bid: Decimal = bid_str.parse().unwrap_or(Decimal::ZERO);
-                                                let ask: Decimal = ask_str.parse().unwrap_or(Decimal::ZERO);
-                                                let last: Decimal = last_str.parse().unwrap_or(Decimal::ZERO);
-                                                let vol: Decimal = vol_str.parse().unwrap_or(Decimal::ZERO);
+                                                // Ticker frames are full snapshots, so every field is required
+                                                let bid = parse_errors.parse_field(
+                                                    strict, Exchange::Bitget, "bidPr",
+                                                    item["bidPr"].as_str().or_else(|| item["bestBid"].as_str()),
+                                                    true,
+                                                );
+                                                let ask = parse_errors.parse_field(
+                                                    strict, Exchange::Bitget, "askPr",
+                                                    item["askPr"].as_str().or_else(|| item["bestAsk"].as_str()),
+                                                    true,
+                                                );
+                                                let last = parse_errors.parse_field(
+                                                    strict, Exchange::Bitget, "lastPr",
+                                                    item["lastPr"].as_str().or_else(|| item["last"].as_str()),
+                                                    true,
+                                                );
+                                                let vol = parse_errors.parse_field(
+                                                    strict, Exchange::Bitget, "baseVolume",
+                                                    item["baseVolume"].as_str().or_else(|| item["baseVol"].as_str()),
+                                                    false,
+                                                );
 
                                                 if msg_count <= 3 {
                                                     info!("[Bitget] Parsed: bid={} ask={} last={} vol={}", bid, ask, last, vol);
                                                 }
 
                                                 if bid > Decimal::ZERO && ask > Decimal::ZERO {
+                                                    // Stamp with the exchange's event time
+                                                    // (per-item ts, or the frame's) so lag
+                                                    // measurement sees the venue's clock
+                                                    let event_time = item["ts"]
+                                                        .as_str()
+                                                        .and_then(|s| s.parse::<i64>().ok())
+                                                        .or_else(|| json["ts"].as_i64())
+                                                        .and_then(DateTime::from_timestamp_millis)
+                                                        .unwrap_or_else(Utc::now);
                                                     let ticker = Ticker {
                                                         exchange: Exchange::Bitget,
                                                         pair: pair_clone.clone(),
@@ -178,7 +1097,8 @@ impl ExchangeConnector for BitgetConnector {
                                                         ask,
                                                         last,
                                                         volume_24h: vol,
-                                                        timestamp: Utc::now(),
+                                                        timestamp: event_time,
+                                                        source: TickerSource::Ws,
                                                     };
                                                     if msg_count <= 3 {
                                                         info!("[Bitget] ✅ Emitting ticker: {} bid={} ask={}", ticker.pair, ticker.bid, ticker.ask);
@@ -188,10 +1108,17 @@ impl ExchangeConnector for BitgetConnector {
                                                         return;
                                                     }
                                                 } else if msg_count <= 3 {
-                                                    warn!("[Bitget] ⚠️ bid={} ask={} (zero) | bid_raw='{}' ask_raw='{}'", bid, ask, bid_str, ask_str);
+                                                    warn!("[Bitget] ⚠️ bid={} ask={} (zero) — waiting for data", bid, ask);
                                                 }
                                             }
+                                        } else if strict {
+                                            parse_errors.record(
+                                                Exchange::Bitget,
+                                                "payload has no data array",
+                                            );
                                         }
+                                    } else if strict {
+                                        parse_errors.record(Exchange::Bitget, "message is not valid JSON");
                                     }
                                 }
                                 Ok(Message::Ping(data)) => {
@@ -212,6 +1139,7 @@ impl ExchangeConnector for BitgetConnector {
                         error!("Failed to connect to Bitget WS: {}", e);
                     }
                 }
+                reconnects.fetch_add(1, Ordering::Relaxed);
                 warn!("Bitget WS disconnected, reconnecting in 1s...");
                 tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
             }
@@ -221,50 +1149,37 @@ impl ExchangeConnector for BitgetConnector {
     }
 
     async fn get_ticker(&self, pair: &TradingPair) -> Result<Ticker, ExchangeError> {
+        self.retry
+            .run("Bitget get_ticker", || self.fetch_ticker(pair))
+            .await
+    }
+
+    async fn get_order_book(
+        &self,
+        pair: &TradingPair,
+        depth: u32,
+    ) -> Result<OrderBook, ExchangeError> {
+        // Prefer the checksum-validated local book while it is fresh
         let symbol = pair.symbol_for(Exchange::Bitget);
-        let url = format!(
-            "{}/api/v2/spot/market/tickers?symbol={}",
-            BITGET_REST_URL, symbol
-        );
+        if let Some(entry) = self.local_books.get(&symbol) {
+            let (book, updated_ms) = entry.value();
+            if Utc::now().timestamp_millis() - updated_ms <= LOCAL_BOOK_FRESH_MS {
+                let mut book = book.clone();
+                book.bids.truncate(depth as usize);
+                book.asks.truncate(depth as usize);
+                return Ok(book);
+            }
+        }
 
-        let resp = self
-            .client
-            .get(&url)
-            .send()
+        self.retry
+            .run("Bitget get_order_book", || self.fetch_order_book(pair, depth))
             .await
-            .map_err(|e| ExchangeError::Connection(e.to_string()))?;
+    }
 
-        let data: serde_json::Value = resp
-            .json()
+    async fn get_funding_rate(&self, pair: &TradingPair) -> Result<FundingRate, ExchangeError> {
+        self.retry
+            .run("Bitget get_funding_rate", || self.fetch_funding_rate(pair))
             .await
-            .map_err(|e| ExchangeError::Parse(e.to_string()))?;
-
-        let item = &data["data"][0];
-        Ok(Ticker {
-            exchange: Exchange::Bitget,
-            pair: pair.clone(),
-            bid: item["bestBid"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(Decimal::ZERO),
-            ask: item["bestAsk"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(Decimal::ZERO),
-            last: item["lastPr"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(Decimal::ZERO),
-            volume_24h: item["baseVolume"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(Decimal::ZERO),
-            timestamp: Utc::now(),
-        })
     }
 
     async fn place_order(
@@ -274,40 +1189,150 @@ impl ExchangeConnector for BitgetConnector {
         order_type: OrderType,
         quantity: Decimal,
         price: Option<Decimal>,
-    ) -> Result<String, ExchangeError> {
-        let symbol = pair.symbol_for(Exchange::Bitget);
-        let timestamp = Utc::now().timestamp_millis();
+    ) -> Result<Order, ExchangeError> {
+        // One id across all retry attempts, so a retried request after a
+        // timeout is deduplicated by the venue instead of double-filling
+        let client_order_id = super::new_client_order_id();
+        self.retry
+            .run("Bitget place_order", || {
+                self.submit_order(pair, side, order_type, quantity, price, false, &client_order_id)
+            })
+            .await
+    }
 
-        let mut body = serde_json::json!({
-            "symbol": symbol,
-            "side": match side { OrderSide::Buy => "buy", OrderSide::Sell => "sell" },
-            "orderType": match order_type { OrderType::Market => "market", OrderType::Limit => "limit" },
-            "size": quantity.to_string(),
-            "force": "gtc",
-        });
+    async fn place_margin_order(
+        &self,
+        pair: &TradingPair,
+        side: OrderSide,
+        order_type: OrderType,
+        quantity: Decimal,
+        price: Option<Decimal>,
+    ) -> Result<Order, ExchangeError> {
+        let client_order_id = super::new_client_order_id();
+        self.retry
+            .run("Bitget place_margin_order", || {
+                self.submit_order(pair, side, order_type, quantity, price, true, &client_order_id)
+            })
+            .await
+    }
 
-        if let Some(p) = price {
-            body["price"] = serde_json::Value::String(p.to_string());
+    async fn get_open_orders(&self, pair: &TradingPair) -> Result<Vec<Order>, ExchangeError> {
+        self.retry
+            .run("Bitget get_open_orders", || self.fetch_open_orders(pair))
+            .await
+    }
+
+    async fn get_order_history(
+        &self,
+        pair: &TradingPair,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<Order>, ExchangeError> {
+        self.retry
+            .run("Bitget get_order_history", || {
+                self.fetch_order_history(pair, since)
+            })
+            .await
+    }
+
+    async fn cancel_order(
+        &self,
+        pair: &TradingPair,
+        order_id: &str,
+    ) -> Result<(), ExchangeError> {
+        self.retry
+            .run("Bitget cancel_order", || self.do_cancel_order(pair, order_id))
+            .await
+    }
+
+    async fn get_order_fill(
+        &self,
+        pair: &TradingPair,
+        order_id: &str,
+    ) -> Result<OrderFill, ExchangeError> {
+        self.retry
+            .run("Bitget get_order_fill", || self.fetch_order_fill(pair, order_id))
+            .await
+    }
+
+    async fn withdraw(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        address: &str,
+        network: &str,
+    ) -> Result<String, ExchangeError> {
+        if !self.config.allow_withdrawals {
+            return Err(ExchangeError::Api(
+                "Withdrawals disabled for Bitget (set allow_withdrawals)".to_string(),
+            ));
         }
+        self.retry
+            .run("Bitget withdraw", || {
+                self.do_withdraw(asset, amount, address, network)
+            })
+            .await
+    }
 
-        let body_str = serde_json::to_string(&body).unwrap();
-        let path = "/api/v2/spot/trade/place-order";
-        let signature = self.sign_request(timestamp, "POST", path, &body_str);
+    async fn get_deposit_address(
+        &self,
+        asset: &str,
+        network: &str,
+    ) -> Result<DepositAddress, ExchangeError> {
+        self.retry
+            .run("Bitget get_deposit_address", || {
+                self.fetch_deposit_address(asset, network)
+            })
+            .await
+    }
 
-        let url = format!("{}{}", BITGET_REST_URL, path);
+    async fn get_balances(&self) -> Result<Vec<ExchangeBalance>, ExchangeError> {
+        self.retry
+            .run("Bitget get_balances", || self.fetch_balances())
+            .await
+    }
+
+    async fn get_account_events(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<AccountEvent>, ExchangeError> {
+        self.retry
+            .run("Bitget get_account_events", || self.fetch_account_events(since))
+            .await
+    }
+
+    fn fee_pct(&self) -> Decimal {
+        self.config.fee_pct
+    }
+
+    fn parse_error_count(&self) -> u64 {
+        self.parse_errors.count()
+    }
+
+    fn last_ws_message_ms(&self) -> i64 {
+        self.last_ws_message.load(Ordering::Relaxed)
+    }
+
+    fn checksum_failure_count(&self) -> u64 {
+        self.checksum_failures.load(Ordering::Relaxed)
+    }
+
+    fn ws_reconnect_count(&self) -> u64 {
+        self.ws_reconnects.load(Ordering::Relaxed)
+    }
+
+    fn api_version(&self) -> &'static str {
+        BITGET_API_VERSION
+    }
+
+    async fn probe_compatibility(&self) -> Result<(), ExchangeError> {
+        let url = format!(
+            "{}/api/v2/spot/market/tickers?symbol=BTCUSDT",
+            BITGET_REST_URL
+        );
 
         let resp = self
             .client
-            .post(&url)
-            .header("ACCESS-KEY", &self.config.api_key)
-            .header("ACCESS-SIGN", &signature)
-            .header("ACCESS-TIMESTAMP", timestamp.to_string())
-            .header(
-                "ACCESS-PASSPHRASE",
-                self.config.passphrase.as_deref().unwrap_or(""),
-            )
-            .header("Content-Type", "application/json")
-            .body(body_str)
+            .get(&url)
             .send()
             .await
             .map_err(|e| ExchangeError::Connection(e.to_string()))?;
@@ -317,38 +1342,34 @@ impl ExchangeConnector for BitgetConnector {
             .await
             .map_err(|e| ExchangeError::Parse(e.to_string()))?;
 
-        if data["code"].as_str() == Some("00000") {
-            Ok(data["data"]["orderId"]
-                .as_str()
-                .unwrap_or("unknown")
-                .to_string())
-        } else {
-            Err(ExchangeError::OrderFailed(
-                data["msg"]
-                    .as_str()
-                    .unwrap_or("Unknown error")
-                    .to_string(),
-            ))
+        if data["code"].as_str() != Some("00000") {
+            return Err(ExchangeError::Api(format!(
+                "Bitget {} probe failed: code={}",
+                BITGET_API_VERSION, data["code"]
+            )));
         }
-    }
 
-    async fn get_balances(&self) -> Result<Vec<ExchangeBalance>, ExchangeError> {
-        let timestamp = Utc::now().timestamp_millis();
-        let path = "/api/v2/spot/account/assets";
-        let signature = self.sign_request(timestamp, "GET", path, "");
+        // Check the fields the ticker path depends on are still present
+        let item = &data["data"][0];
+        for field in ["symbol", "bestBid", "bestAsk", "lastPr", "baseVolume"] {
+            if item[field].as_str().is_none() {
+                return Err(ExchangeError::Api(format!(
+                    "Bitget {} probe failed: missing field '{}'",
+                    BITGET_API_VERSION, field
+                )));
+            }
+        }
 
-        let url = format!("{}{}", BITGET_REST_URL, path);
+        Ok(())
+    }
 
+    async fn sync_server_time(&self) -> Result<(), ExchangeError> {
+        let url = format!("{}/api/v2/public/time", BITGET_REST_URL);
+
+        let local_before = Utc::now().timestamp_millis();
         let resp = self
             .client
             .get(&url)
-            .header("ACCESS-KEY", &self.config.api_key)
-            .header("ACCESS-SIGN", &signature)
-            .header("ACCESS-TIMESTAMP", timestamp.to_string())
-            .header(
-                "ACCESS-PASSPHRASE",
-                self.config.passphrase.as_deref().unwrap_or(""),
-            )
             .send()
             .await
             .map_err(|e| ExchangeError::Connection(e.to_string()))?;
@@ -357,32 +1378,15 @@ impl ExchangeConnector for BitgetConnector {
             .json()
             .await
             .map_err(|e| ExchangeError::Parse(e.to_string()))?;
+        let local_after = Utc::now().timestamp_millis();
 
-        let balances = data["data"]
-            .as_array()
-            .unwrap_or(&vec![])
-            .iter()
-            .filter_map(|b| {
-                let free: Decimal = b["available"].as_str()?.parse().ok()?;
-                let locked: Decimal = b["frozen"].as_str()?.parse().ok()?;
-                if free + locked > Decimal::ZERO {
-                    Some(ExchangeBalance {
-                        exchange: Exchange::Bitget,
-                        asset: b["coin"].as_str()?.to_string(),
-                        free,
-                        locked,
-                        total: free + locked,
-                    })
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        Ok(balances)
-    }
+        let server_ms = data["data"]["serverTime"]
+            .as_str()
+            .and_then(|s| s.parse::<i64>().ok())
+            .ok_or_else(|| ExchangeError::Parse("missing server time in response".to_string()))?;
 
-    fn fee_pct(&self) -> Decimal {
-        self.config.fee_pct
+        self.time_sync.update(server_ms, local_before, local_after);
+        tracing::debug!("Bitget clock offset: {}ms", self.time_sync.offset_ms());
+        Ok(())
     }
 }
@@ -1,66 +1,719 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use futures_util::{SinkExt, StreamExt};
 use rust_decimal::Decimal;
-use serde::Deserialize;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
-use tracing::{debug, error, info, warn};
+use tracing::{error, info, warn};
 
+use crate::audit::OrderAuditLog;
 use crate::config::ExchangeConfig;
-use crate::exchange::{ExchangeConnector, ExchangeError};
+use crate::exchange::{
+    ms_to_utc, parse_levels, ExchangeConnector, ExchangeError, ParseErrorCounter, RetryPolicy,
+    TimeSync,
+};
 use crate::types::*;
 
 const BYBIT_WS_URL: &str = "wss://stream.bybit.com/v5/public/spot";
-const BYBIT_REST_URL: &str = "https://api.bybit.com";
+const BYBIT_WS_LINEAR_URL: &str = "wss://stream.bybit.com/v5/public/linear";
+pub(crate) const BYBIT_REST_URL: &str = "https://api.bybit.com";
+/// Bybit API version this connector targets
+const BYBIT_API_VERSION: &str = "v5";
+/// Window (ms) within which a signed request is valid on Bybit's side
+const BYBIT_RECV_WINDOW: &str = "5000";
 
 pub struct BybitConnector {
     config: ExchangeConfig,
     client: reqwest::Client,
+    parse_errors: Arc<ParseErrorCounter>,
+    retry: RetryPolicy,
+    time_sync: Arc<TimeSync>,
+    /// Epoch ms of the last WS message received (0 = none yet)
+    last_ws_message: Arc<AtomicI64>,
+    /// WS reconnect attempts since startup
+    ws_reconnects: Arc<AtomicU64>,
+    /// Order request/response audit trail, when one is attached
+    audit: Option<Arc<OrderAuditLog>>,
 }
 
 impl BybitConnector {
-    pub fn new(config: ExchangeConfig) -> Self {
+    pub fn new(
+        config: ExchangeConfig,
+        retry: RetryPolicy,
+        audit: Option<Arc<OrderAuditLog>>,
+    ) -> Self {
         Self {
             config,
             client: reqwest::Client::new(),
+            parse_errors: Arc::new(ParseErrorCounter::default()),
+            retry,
+            time_sync: Arc::new(TimeSync::default()),
+            last_ws_message: Arc::new(AtomicI64::new(0)),
+            ws_reconnects: Arc::new(AtomicU64::new(0)),
+            audit,
         }
     }
 
-    fn sign_request(&self, timestamp: i64, query: &str) -> String {
+    /// Record an outbound mutating request and the raw venue response on
+    /// the audit trail, when one is attached
+    async fn audit_record(
+        &self,
+        endpoint: &str,
+        request: &serde_json::Value,
+        response: &serde_json::Value,
+    ) {
+        if let Some(audit) = &self.audit {
+            audit
+                .record(Exchange::Bybit, endpoint, request, response)
+                .await;
+        }
+    }
+
+    /// Sign a Bybit V5 request payload — the query string for GET requests,
+    /// the JSON body for POST requests — per the V5 auth scheme:
+    /// HMAC-SHA256 over timestamp + api_key + recv_window + payload
+    fn sign_payload(&self, timestamp: i64, payload: &str) -> String {
         use hmac::{Hmac, Mac};
         use sha2::Sha256;
         type HmacSha256 = Hmac<Sha256>;
 
-        let payload = format!("{}{}{}", timestamp, &self.config.api_key, query);
+        let prehash = format!(
+            "{}{}{}{}",
+            timestamp, &self.config.api_key, BYBIT_RECV_WINDOW, payload
+        );
         let mut mac =
             HmacSha256::new_from_slice(self.config.api_secret.as_bytes()).expect("HMAC init");
-        mac.update(payload.as_bytes());
+        mac.update(prehash.as_bytes());
         hex::encode(mac.finalize().into_bytes())
     }
-}
 
-/// Bybit V5 WebSocket response envelope
-#[derive(Debug, Deserialize)]
-struct BybitWsResponse {
-    topic: Option<String>,
-    #[serde(rename = "type")]
-    msg_type: Option<String>,
-    data: Option<BybitTickerData>,
-}
+    /// Bybit V5 product category for a pair's market
+    fn category(pair: &TradingPair) -> &'static str {
+        match pair.market {
+            MarketType::Spot => "spot",
+            MarketType::Perpetual => "linear",
+        }
+    }
+
+    // REST operation bodies — called through the shared retry policy
+    async fn fetch_ticker(&self, pair: &TradingPair) -> Result<Ticker, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bybit);
+        let url = format!(
+            "{}/v5/market/tickers?category={}&symbol={}",
+            BYBIT_REST_URL,
+            Self::category(pair),
+            symbol
+        );
+
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::Connection(e.to_string()))?;
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| ExchangeError::Parse(e.to_string()))?;
+
+        let item = &data["result"]["list"][0];
+        let strict = self.config.strict_parse;
+        if strict && !item.is_object() {
+            self.parse_errors
+                .record(Exchange::Bybit, "unexpected tickers response shape");
+            return Err(ExchangeError::Parse(
+                "unexpected tickers response shape".to_string(),
+            ));
+        }
+
+        Ok(Ticker {
+            exchange: Exchange::Bybit,
+            pair: pair.clone(),
+            bid: self.parse_errors.parse_field(
+                strict, Exchange::Bybit, "bid1Price", item["bid1Price"].as_str(), true,
+            ),
+            ask: self.parse_errors.parse_field(
+                strict, Exchange::Bybit, "ask1Price", item["ask1Price"].as_str(), true,
+            ),
+            last: self.parse_errors.parse_field(
+                strict, Exchange::Bybit, "lastPrice", item["lastPrice"].as_str(), true,
+            ),
+            volume_24h: self.parse_errors.parse_field(
+                strict, Exchange::Bybit, "volume24h", item["volume24h"].as_str(), false,
+            ),
+            timestamp: Utc::now(),
+            source: TickerSource::RestPoll,
+        })
+    }
+
+    async fn fetch_order_book(
+        &self,
+        pair: &TradingPair,
+        depth: u32,
+    ) -> Result<OrderBook, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bybit);
+        let url = format!(
+            "{}/v5/market/orderbook?category={}&symbol={}&limit={}",
+            BYBIT_REST_URL,
+            Self::category(pair),
+            symbol,
+            depth
+        );
+
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::Connection(e.to_string()))?;
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| ExchangeError::Parse(e.to_string()))?;
+
+        Ok(OrderBook {
+            exchange: Exchange::Bybit,
+            pair: pair.clone(),
+            bids: parse_levels(&data["result"]["b"]),
+            asks: parse_levels(&data["result"]["a"]),
+            timestamp: Utc::now(),
+        })
+    }
+
+    async fn fetch_funding_rate(&self, pair: &TradingPair) -> Result<FundingRate, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bybit);
+        let url = format!(
+            "{}/v5/market/tickers?category=linear&symbol={}",
+            BYBIT_REST_URL, symbol
+        );
+
+        let resp = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::Connection(e.to_string()))?;
+
+        let data: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| ExchangeError::Parse(e.to_string()))?;
+
+        let item = &data["result"]["list"][0];
+        let rate: Decimal = item["fundingRate"]
+            .as_str()
+            .and_then(|r| r.parse().ok())
+            .ok_or_else(|| ExchangeError::Parse("missing fundingRate".to_string()))?;
+
+        Ok(FundingRate {
+            exchange: Exchange::Bybit,
+            pair: pair.clone(),
+            rate,
+            interval_hours: 8,
+            timestamp: Utc::now(),
+        })
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn submit_order(
+        &self,
+        pair: &TradingPair,
+        side: OrderSide,
+        order_type: OrderType,
+        quantity: Decimal,
+        price: Option<Decimal>,
+        margin: bool,
+        client_order_id: &str,
+    ) -> Result<Order, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bybit);
+
+        let mut body = serde_json::json!({
+            "category": Self::category(pair),
+            "symbol": symbol,
+            "side": match side { OrderSide::Buy => "Buy", OrderSide::Sell => "Sell" },
+            "orderType": match order_type { OrderType::Market => "Market", OrderType::Limit => "Limit" },
+            "qty": quantity.to_string(),
+            "orderLinkId": client_order_id,
+        });
+
+        // Unified-account margin: auto-borrows the sold asset when needed
+        if margin {
+            body["isLeverage"] = serde_json::Value::from(1);
+        }
+
+        if let Some(p) = price {
+            body["price"] = serde_json::Value::String(p.to_string());
+            body["timeInForce"] = serde_json::Value::String("GTC".to_string());
+        }
+
+        let data = self.signed_post("/v5/order/create", &body).await?;
+
+        if data["retCode"].as_i64() == Some(0) {
+            let now = Utc::now();
+            Ok(Order {
+                id: data["result"]["orderId"]
+                    .as_str()
+                    .unwrap_or("unknown")
+                    .to_string(),
+                client_order_id: data["result"]["orderLinkId"]
+                    .as_str()
+                    .filter(|s| !s.is_empty())
+                    .map(String::from)
+                    .or_else(|| Some(client_order_id.to_string())),
+                exchange: Exchange::Bybit,
+                pair: pair.clone(),
+                side,
+                order_type,
+                price,
+                quantity,
+                filled_qty: Decimal::ZERO,
+                status: OrderStatus::New,
+                created_at: now,
+                updated_at: now,
+            })
+        } else {
+            Err(ExchangeError::OrderFailed(
+                data["retMsg"]
+                    .as_str()
+                    .unwrap_or("Unknown error")
+                    .to_string(),
+            ))
+        }
+    }
+
+    /// Normalize a Bybit order-list payload onto `Order`
+    fn parse_order_rows(pair: &TradingPair, list: &serde_json::Value) -> Vec<Order> {
+        list.as_array()
+            .unwrap_or(&vec![])
+            .iter()
+            .filter_map(|row| {
+                Some(Order {
+                    id: row["orderId"].as_str()?.to_string(),
+                    client_order_id: row["orderLinkId"]
+                        .as_str()
+                        .filter(|s| !s.is_empty())
+                        .map(String::from),
+                    exchange: Exchange::Bybit,
+                    pair: pair.clone(),
+                    side: match row["side"].as_str()? {
+                        "Buy" => OrderSide::Buy,
+                        _ => OrderSide::Sell,
+                    },
+                    order_type: match row["orderType"].as_str() {
+                        Some("Limit") => OrderType::Limit,
+                        _ => OrderType::Market,
+                    },
+                    price: row["price"].as_str().and_then(|p| p.parse().ok()),
+                    quantity: row["qty"].as_str()?.parse().ok()?,
+                    filled_qty: row["cumExecQty"]
+                        .as_str()
+                        .and_then(|q| q.parse().ok())
+                        .unwrap_or(Decimal::ZERO),
+                    status: match row["orderStatus"].as_str() {
+                        Some("PartiallyFilled") => OrderStatus::PartiallyFilled,
+                        Some("Filled") => OrderStatus::Filled,
+                        Some("Cancelled") | Some("PartiallyFilledCanceled") => {
+                            OrderStatus::Cancelled
+                        }
+                        Some("Rejected") => OrderStatus::Rejected,
+                        _ => OrderStatus::New,
+                    },
+                    created_at: ms_to_utc(&row["createdTime"]),
+                    updated_at: ms_to_utc(&row["updatedTime"]),
+                })
+            })
+            .collect()
+    }
+
+    async fn fetch_open_orders(&self, pair: &TradingPair) -> Result<Vec<Order>, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bybit);
+        let data = self
+            .signed_get(
+                "/v5/order/realtime",
+                &format!("category={}&symbol={}", Self::category(pair), symbol),
+            )
+            .await?;
+
+        if data["retCode"].as_i64() != Some(0) {
+            return Err(ExchangeError::Api(
+                data["retMsg"].as_str().unwrap_or("Unknown error").to_string(),
+            ));
+        }
+
+        Ok(Self::parse_order_rows(pair, &data["result"]["list"]))
+    }
+
+    async fn fetch_order_history(
+        &self,
+        pair: &TradingPair,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<Order>, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bybit);
+        let data = self
+            .signed_get(
+                "/v5/order/history",
+                &format!(
+                    "category={}&symbol={}&startTime={}",
+                    Self::category(pair),
+                    symbol,
+                    since.timestamp_millis()
+                ),
+            )
+            .await?;
+
+        if data["retCode"].as_i64() != Some(0) {
+            return Err(ExchangeError::Api(
+                data["retMsg"].as_str().unwrap_or("Unknown error").to_string(),
+            ));
+        }
+
+        Ok(Self::parse_order_rows(pair, &data["result"]["list"]))
+    }
+
+    async fn fetch_order_fill(
+        &self,
+        pair: &TradingPair,
+        order_id: &str,
+    ) -> Result<OrderFill, ExchangeError> {
+        let symbol = pair.symbol_for(Exchange::Bybit);
+        let category = Self::category(pair);
+
+        // Working orders live on the realtime endpoint; recently completed
+        // ones move to history within seconds
+        let mut row = None;
+        for endpoint in ["/v5/order/realtime", "/v5/order/history"] {
+            let data = self
+                .signed_get(
+                    endpoint,
+                    &format!(
+                        "category={}&symbol={}&orderId={}",
+                        category, symbol, order_id
+                    ),
+                )
+                .await?;
+            if data["retCode"].as_i64() != Some(0) {
+                return Err(ExchangeError::Api(
+                    data["retMsg"].as_str().unwrap_or("Unknown error").to_string(),
+                ));
+            }
+            if let Some(found) = data["result"]["list"].as_array().and_then(|l| l.first()) {
+                row = Some(found.clone());
+                break;
+            }
+        }
+        let row = row.ok_or_else(|| {
+            ExchangeError::Api(format!("Order {} not found on Bybit", order_id))
+        })?;
+
+        let avg_price: Decimal = row["avgPrice"]
+            .as_str()
+            .and_then(|p| p.parse().ok())
+            .unwrap_or(Decimal::ZERO);
+        let filled_qty: Decimal = row["cumExecQty"]
+            .as_str()
+            .and_then(|q| q.parse().ok())
+            .unwrap_or(Decimal::ZERO);
+        let mut fee: Decimal = row["cumExecFee"]
+            .as_str()
+            .and_then(|f| f.parse().ok())
+            .unwrap_or(Decimal::ZERO);
+        // Spot buys are charged in the base asset — normalize into quote
+        if category == "spot" && row["side"].as_str() == Some("Buy") {
+            fee *= avg_price;
+        }
+
+        Ok(OrderFill {
+            order_id: order_id.to_string(),
+            avg_price,
+            filled_qty,
+            fee: fee.abs(),
+        })
+    }
+
+    async fn do_cancel_order(
+        &self,
+        pair: &TradingPair,
+        order_id: &str,
+    ) -> Result<(), ExchangeError> {
+        let body = serde_json::json!({
+            "category": Self::category(pair),
+            "symbol": pair.symbol_for(Exchange::Bybit),
+            "orderId": order_id,
+        });
+
+        let data = self.signed_post("/v5/order/cancel", &body).await?;
+
+        if data["retCode"].as_i64() == Some(0) {
+            Ok(())
+        } else {
+            Err(ExchangeError::OrderFailed(
+                data["retMsg"]
+                    .as_str()
+                    .unwrap_or("Unknown error")
+                    .to_string(),
+            ))
+        }
+    }
+
+    async fn do_withdraw(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        address: &str,
+        network: &str,
+    ) -> Result<String, ExchangeError> {
+        let body = serde_json::json!({
+            "coin": asset,
+            "chain": network,
+            "address": address,
+            "amount": amount.to_string(),
+            "timestamp": self.time_sync.now_ms(),
+        });
+
+        let data = self.signed_post("/v5/asset/withdraw/create", &body).await?;
+
+        if data["retCode"].as_i64() == Some(0) {
+            Ok(data["result"]["id"].as_str().unwrap_or_default().to_string())
+        } else {
+            Err(ExchangeError::Api(
+                data["retMsg"]
+                    .as_str()
+                    .unwrap_or("Unknown error")
+                    .to_string(),
+            ))
+        }
+    }
+
+    async fn fetch_deposit_address(
+        &self,
+        asset: &str,
+        network: &str,
+    ) -> Result<DepositAddress, ExchangeError> {
+        let data = self
+            .signed_get(
+                "/v5/asset/deposit/query-address",
+                &format!("coin={}&chainType={}", asset, network),
+            )
+            .await?;
+
+        if data["retCode"].as_i64() != Some(0) {
+            return Err(ExchangeError::Api(
+                data["retMsg"]
+                    .as_str()
+                    .unwrap_or("Unknown error")
+                    .to_string(),
+            ));
+        }
+
+        let chains = data["result"]["chains"].as_array();
+        let entry = chains
+            .and_then(|chains| {
+                chains
+                    .iter()
+                    .find(|c| c["chain"].as_str() == Some(network))
+                    .or_else(|| chains.first())
+            })
+            .ok_or_else(|| {
+                ExchangeError::Api(format!("No deposit address for {} on {}", asset, network))
+            })?;
+
+        Ok(DepositAddress {
+            exchange: Exchange::Bybit,
+            asset: asset.to_string(),
+            network: entry["chain"].as_str().unwrap_or(network).to_string(),
+            address: entry["addressDeposit"].as_str().unwrap_or_default().to_string(),
+            tag: entry["tagDeposit"]
+                .as_str()
+                .filter(|t| !t.is_empty())
+                .map(|t| t.to_string()),
+        })
+    }
+
+    /// Signed GET against the Bybit private REST API
+    async fn signed_get(
+        &self,
+        path: &str,
+        query: &str,
+    ) -> Result<serde_json::Value, ExchangeError> {
+        let timestamp = self.time_sync.now_ms();
+        let signature = self.sign_payload(timestamp, query);
+
+        let url = if query.is_empty() {
+            format!("{}{}", BYBIT_REST_URL, path)
+        } else {
+            format!("{}{}?{}", BYBIT_REST_URL, path, query)
+        };
+
+        let resp = self
+            .client
+            .get(&url)
+            .header("X-BAPI-API-KEY", &self.config.api_key)
+            .header("X-BAPI-SIGN", &signature)
+            .header("X-BAPI-TIMESTAMP", timestamp.to_string())
+            .header("X-BAPI-RECV-WINDOW", BYBIT_RECV_WINDOW)
+            .send()
+            .await
+            .map_err(|e| ExchangeError::Connection(e.to_string()))?;
 
-/// Bybit V5 spot ticker fields — full names as documented
-#[derive(Debug, Deserialize)]
-struct BybitTickerData {
-    symbol: Option<String>,
-    #[serde(rename = "bid1Price")]
-    bid1_price: Option<String>,
-    #[serde(rename = "ask1Price")]
-    ask1_price: Option<String>,
-    #[serde(rename = "lastPrice")]
-    last_price: Option<String>,
-    #[serde(rename = "volume24h")]
-    volume_24h: Option<String>,
+        resp.json()
+            .await
+            .map_err(|e| ExchangeError::Parse(e.to_string()))
+    }
+
+    /// Signed POST against the Bybit private REST API
+    async fn signed_post(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> Result<serde_json::Value, ExchangeError> {
+        let timestamp = self.time_sync.now_ms();
+        let body_str = serde_json::to_string(body).unwrap();
+        let signature = self.sign_payload(timestamp, &body_str);
+
+        let url = format!("{}{}", BYBIT_REST_URL, path);
+
+        let resp = match self
+            .client
+            .post(&url)
+            .header("X-BAPI-API-KEY", &self.config.api_key)
+            .header("X-BAPI-SIGN", &signature)
+            .header("X-BAPI-TIMESTAMP", timestamp.to_string())
+            .header("X-BAPI-RECV-WINDOW", BYBIT_RECV_WINDOW)
+            .header("Content-Type", "application/json")
+            .body(body_str)
+            .send()
+            .await
+        {
+            Ok(resp) => resp,
+            Err(e) => {
+                self.audit_record(path, body, &serde_json::json!({ "transport_error": e.to_string() }))
+                    .await;
+                return Err(ExchangeError::Connection(e.to_string()));
+            }
+        };
+
+        let data: serde_json::Value = match resp.json().await {
+            Ok(data) => data,
+            Err(e) => {
+                self.audit_record(path, body, &serde_json::json!({ "parse_error": e.to_string() }))
+                    .await;
+                return Err(ExchangeError::Parse(e.to_string()));
+            }
+        };
+        self.audit_record(path, body, &data).await;
+        Ok(data)
+    }
+
+    async fn fetch_balances(&self) -> Result<Vec<ExchangeBalance>, ExchangeError> {
+        let data = self
+            .signed_get("/v5/account/wallet-balance", "accountType=UNIFIED")
+            .await?;
+
+        let coins = data["result"]["list"][0]["coin"]
+            .as_array()
+            .unwrap_or(&vec![])
+            .iter()
+            .filter_map(|c| {
+                let free: Decimal = c["availableToWithdraw"].as_str()?.parse().ok()?;
+                let locked: Decimal = c["locked"].as_str()?.parse().ok()?;
+                if free + locked > Decimal::ZERO {
+                    Some(ExchangeBalance {
+                        exchange: Exchange::Bybit,
+                        asset: c["coin"].as_str()?.to_string(),
+                        free,
+                        locked,
+                        total: free + locked,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        Ok(coins)
+    }
+
+    async fn fetch_account_events(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<AccountEvent>, ExchangeError> {
+        let start_ms = since.timestamp_millis();
+        let mut events = Vec::new();
+
+        // Deposits
+        let data = self
+            .signed_get(
+                "/v5/asset/deposit/query-record",
+                &format!("startTime={}", start_ms),
+            )
+            .await?;
+        if let Some(rows) = data["result"]["rows"].as_array() {
+            for row in rows {
+                let amount: Decimal =
+                    row["amount"].as_str().unwrap_or("0").parse().unwrap_or(Decimal::ZERO);
+                events.push(AccountEvent {
+                    id: format!("bybit-dep-{}", row["txID"].as_str().unwrap_or("")),
+                    exchange: Exchange::Bybit,
+                    kind: AccountEventKind::Deposit,
+                    asset: row["coin"].as_str().unwrap_or("").to_string(),
+                    amount,
+                    timestamp: ms_to_utc(&row["successAt"]),
+                });
+            }
+        }
+
+        // Withdrawals
+        let data = self
+            .signed_get(
+                "/v5/asset/withdraw/query-record",
+                &format!("startTime={}", start_ms),
+            )
+            .await?;
+        if let Some(rows) = data["result"]["rows"].as_array() {
+            for row in rows {
+                let amount: Decimal =
+                    row["amount"].as_str().unwrap_or("0").parse().unwrap_or(Decimal::ZERO);
+                events.push(AccountEvent {
+                    id: format!("bybit-wd-{}", row["withdrawId"].as_str().unwrap_or("")),
+                    exchange: Exchange::Bybit,
+                    kind: AccountEventKind::Withdrawal,
+                    asset: row["coin"].as_str().unwrap_or("").to_string(),
+                    amount: -amount,
+                    timestamp: ms_to_utc(&row["updateTime"]),
+                });
+            }
+        }
+
+        // Spot fills. The bot's own fills are included too — reconciliation
+        // is responsible for matching those against our trade history.
+        let data = self
+            .signed_get(
+                "/v5/execution/list",
+                &format!("category=spot&startTime={}", start_ms),
+            )
+            .await?;
+        if let Some(rows) = data["result"]["list"].as_array() {
+            for row in rows {
+                let qty: Decimal =
+                    row["execQty"].as_str().unwrap_or("0").parse().unwrap_or(Decimal::ZERO);
+                let amount = if row["side"].as_str() == Some("Sell") { -qty } else { qty };
+                events.push(AccountEvent {
+                    id: format!("bybit-exec-{}", row["execId"].as_str().unwrap_or("")),
+                    exchange: Exchange::Bybit,
+                    kind: AccountEventKind::ExternalTrade,
+                    asset: row["symbol"].as_str().unwrap_or("").to_string(),
+                    amount,
+                    timestamp: ms_to_utc(&row["execTime"]),
+                });
+            }
+        }
+
+        Ok(events)
+    }
 }
 
 #[async_trait]
@@ -74,13 +727,24 @@ impl ExchangeConnector for BybitConnector {
         pair: &TradingPair,
     ) -> Result<mpsc::UnboundedReceiver<Ticker>, ExchangeError> {
         let symbol = pair.symbol_for(Exchange::Bybit);
-        let url = BYBIT_WS_URL.to_string();
+        // Spot and linear perps stream from different public endpoints
+        let url = match pair.market {
+            MarketType::Spot => BYBIT_WS_URL.to_string(),
+            MarketType::Perpetual => BYBIT_WS_LINEAR_URL.to_string(),
+        };
         let pair_clone = pair.clone();
         let subscribe_msg = serde_json::json!({
             "op": "subscribe",
             "args": [format!("tickers.{}", symbol)]
         });
 
+        let strict = self.config.strict_parse;
+        let parse_errors = self.parse_errors.clone();
+        let stale_secs = self.config.ws_stale_secs.max(5);
+        let stale_window = std::time::Duration::from_secs(stale_secs);
+        let last_msg = self.last_ws_message.clone();
+        let reconnects = self.ws_reconnects.clone();
+
         let (tx, rx) = mpsc::unbounded_channel();
 
         tokio::spawn(async move {
@@ -92,7 +756,7 @@ impl ExchangeConnector for BybitConnector {
 
                         // Send subscription message
                         let sub_text = serde_json::to_string(&subscribe_msg).unwrap();
-                        if let Err(e) = write.send(Message::Text(sub_text.into())).await {
+                        if let Err(e) = write.send(Message::Text(sub_text)).await {
                             error!("Failed to subscribe on Bybit: {}", e);
                             continue; // retry connection instead of killing the loop
                         }
@@ -107,7 +771,7 @@ impl ExchangeConnector for BybitConnector {
                                 let ping_msg = serde_json::json!({"op": "ping"});
                                 let mut w = ping_writer.lock().await;
                                 if w.send(Message::Text(
-                                    serde_json::to_string(&ping_msg).unwrap().into(),
+                                    serde_json::to_string(&ping_msg).unwrap(),
                                 ))
                                 .await
                                 .is_err()
@@ -123,7 +787,23 @@ impl ExchangeConnector for BybitConnector {
                         let mut last_bid = Decimal::ZERO;
                         let mut last_ask = Decimal::ZERO;
 
-                        while let Some(msg) = read.next().await {
+                        loop {
+                            let msg = match tokio::time::timeout(stale_window, read.next()).await {
+                                Ok(Some(m)) => m,
+                                // Stream ended — reconnect
+                                Ok(None) => break,
+                                // No traffic within the stale window: the socket
+                                // may be silently dead, so force a reconnect
+                                Err(_) => {
+                                    error!(
+                                        "[Bybit] No WS message for {}s on {} — stale feed, forcing reconnect",
+                                        stale_secs, symbol
+                                    );
+                                    break;
+                                }
+                            };
+                            last_msg.store(Utc::now().timestamp_millis(), Ordering::Relaxed);
+
                             match msg {
                                 Ok(Message::Text(text)) => {
                                     // Skip pong/subscription confirmations
@@ -136,6 +816,15 @@ impl ExchangeConnector for BybitConnector {
                                     // Parse as raw JSON Value — avoids all field naming issues
                                     if let Ok(json) = serde_json::from_str::<serde_json::Value>(&text) {
                                         let data = &json["data"];
+                                        if !data.is_object() {
+                                            if strict {
+                                                parse_errors.record(
+                                                    Exchange::Bybit,
+                                                    "payload has no data object",
+                                                );
+                                            }
+                                            continue;
+                                        }
 
                                         // On first message, log ALL available field names
                                         if msg_count == 1 {
@@ -145,18 +834,29 @@ impl ExchangeConnector for BybitConnector {
                                             }
                                         }
 
-                                        let last_str = data["lastPrice"].as_str().unwrap_or("0");
-                                        let vol_str = data["volume24h"].as_str().unwrap_or("0");
-                                        let last: Decimal = last_str.parse().unwrap_or(Decimal::ZERO);
-                                        let vol: Decimal = vol_str.parse().unwrap_or(Decimal::ZERO);
+                                        let last = parse_errors.parse_field(
+                                            strict, Exchange::Bybit, "lastPrice",
+                                            data["lastPrice"].as_str(), true,
+                                        );
+                                        let vol = parse_errors.parse_field(
+                                            strict, Exchange::Bybit, "volume24h",
+                                            data["volume24h"].as_str(), false,
+                                        );
 
-                                        // Try bid/ask fields — update tracked values if present
+                                        // Try bid/ask fields — update tracked values if present.
+                                        // These are delta fields, so a missing value is normal;
+                                        // only an unparseable value counts as a strict error.
                                         if let Some(b) = data["bid1Price"].as_str()
                                             .or_else(|| data["bidPrice"].as_str())
                                             .or_else(|| data["bid1"].as_str())
                                         {
                                             if let Ok(v) = b.parse::<Decimal>() {
                                                 if v > Decimal::ZERO { last_bid = v; }
+                                            } else if strict {
+                                                parse_errors.record(
+                                                    Exchange::Bybit,
+                                                    &format!("unparseable bid1Price value '{}'", b),
+                                                );
                                             }
                                         }
                                         if let Some(a) = data["ask1Price"].as_str()
@@ -165,6 +865,11 @@ impl ExchangeConnector for BybitConnector {
                                         {
                                             if let Ok(v) = a.parse::<Decimal>() {
                                                 if v > Decimal::ZERO { last_ask = v; }
+                                            } else if strict {
+                                                parse_errors.record(
+                                                    Exchange::Bybit,
+                                                    &format!("unparseable ask1Price value '{}'", a),
+                                                );
                                             }
                                         }
 
@@ -177,6 +882,13 @@ impl ExchangeConnector for BybitConnector {
                                         }
 
                                         if bid > Decimal::ZERO && ask > Decimal::ZERO {
+                                            // Stamp with the exchange's event time so
+                                            // downstream lag measurement sees the venue's
+                                            // clock; fall back to receive time
+                                            let event_time = json["ts"]
+                                                .as_i64()
+                                                .and_then(DateTime::from_timestamp_millis)
+                                                .unwrap_or_else(Utc::now);
                                             let ticker = Ticker {
                                                 exchange: Exchange::Bybit,
                                                 pair: pair_clone.clone(),
@@ -184,7 +896,8 @@ impl ExchangeConnector for BybitConnector {
                                                 ask,
                                                 last,
                                                 volume_24h: vol,
-                                                timestamp: Utc::now(),
+                                                timestamp: event_time,
+                                                source: TickerSource::Ws,
                                             };
                                             if msg_count <= 3 {
                                                 info!("[Bybit] ✅ Emitting ticker: {} bid={} ask={}", ticker.pair, ticker.bid, ticker.ask);
@@ -196,6 +909,8 @@ impl ExchangeConnector for BybitConnector {
                                         } else if msg_count <= 5 {
                                             warn!("[Bybit] ⚠️ bid={} ask={} (zero) — waiting for data", bid, ask);
                                         }
+                                    } else if strict {
+                                        parse_errors.record(Exchange::Bybit, "message is not valid JSON");
                                     }
                                 }
                                 Ok(Message::Ping(data)) => {
@@ -216,6 +931,7 @@ impl ExchangeConnector for BybitConnector {
                         error!("Failed to connect to Bybit WS: {}", e);
                     }
                 }
+                reconnects.fetch_add(1, Ordering::Relaxed);
                 warn!("Bybit WS disconnected, reconnecting in 1s...");
                 tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
             }
@@ -225,50 +941,25 @@ impl ExchangeConnector for BybitConnector {
     }
 
     async fn get_ticker(&self, pair: &TradingPair) -> Result<Ticker, ExchangeError> {
-        let symbol = pair.symbol_for(Exchange::Bybit);
-        let url = format!(
-            "{}/v5/market/tickers?category=spot&symbol={}",
-            BYBIT_REST_URL, symbol
-        );
-
-        let resp = self
-            .client
-            .get(&url)
-            .send()
+        self.retry
+            .run("Bybit get_ticker", || self.fetch_ticker(pair))
             .await
-            .map_err(|e| ExchangeError::Connection(e.to_string()))?;
+    }
 
-        let data: serde_json::Value = resp
-            .json()
+    async fn get_order_book(
+        &self,
+        pair: &TradingPair,
+        depth: u32,
+    ) -> Result<OrderBook, ExchangeError> {
+        self.retry
+            .run("Bybit get_order_book", || self.fetch_order_book(pair, depth))
             .await
-            .map_err(|e| ExchangeError::Parse(e.to_string()))?;
+    }
 
-        let item = &data["result"]["list"][0];
-        Ok(Ticker {
-            exchange: Exchange::Bybit,
-            pair: pair.clone(),
-            bid: item["bid1Price"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(Decimal::ZERO),
-            ask: item["ask1Price"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(Decimal::ZERO),
-            last: item["lastPrice"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(Decimal::ZERO),
-            volume_24h: item["volume24h"]
-                .as_str()
-                .unwrap_or("0")
-                .parse()
-                .unwrap_or(Decimal::ZERO),
-            timestamp: Utc::now(),
-        })
+    async fn get_funding_rate(&self, pair: &TradingPair) -> Result<FundingRate, ExchangeError> {
+        self.retry
+            .run("Bybit get_funding_rate", || self.fetch_funding_rate(pair))
+            .await
     }
 
     async fn place_order(
@@ -278,36 +969,146 @@ impl ExchangeConnector for BybitConnector {
         order_type: OrderType,
         quantity: Decimal,
         price: Option<Decimal>,
-    ) -> Result<String, ExchangeError> {
-        let symbol = pair.symbol_for(Exchange::Bybit);
-        let timestamp = Utc::now().timestamp_millis();
+    ) -> Result<Order, ExchangeError> {
+        // One id across all retry attempts, so a retried request after a
+        // timeout is deduplicated by the venue instead of double-filling
+        let client_order_id = super::new_client_order_id();
+        self.retry
+            .run("Bybit place_order", || {
+                self.submit_order(pair, side, order_type, quantity, price, false, &client_order_id)
+            })
+            .await
+    }
 
-        let mut body = serde_json::json!({
-            "category": "spot",
-            "symbol": symbol,
-            "side": match side { OrderSide::Buy => "Buy", OrderSide::Sell => "Sell" },
-            "orderType": match order_type { OrderType::Market => "Market", OrderType::Limit => "Limit" },
-            "qty": quantity.to_string(),
-        });
+    async fn place_margin_order(
+        &self,
+        pair: &TradingPair,
+        side: OrderSide,
+        order_type: OrderType,
+        quantity: Decimal,
+        price: Option<Decimal>,
+    ) -> Result<Order, ExchangeError> {
+        let client_order_id = super::new_client_order_id();
+        self.retry
+            .run("Bybit place_margin_order", || {
+                self.submit_order(pair, side, order_type, quantity, price, true, &client_order_id)
+            })
+            .await
+    }
 
-        if let Some(p) = price {
-            body["price"] = serde_json::Value::String(p.to_string());
-            body["timeInForce"] = serde_json::Value::String("GTC".to_string());
+    async fn get_open_orders(&self, pair: &TradingPair) -> Result<Vec<Order>, ExchangeError> {
+        self.retry
+            .run("Bybit get_open_orders", || self.fetch_open_orders(pair))
+            .await
+    }
+
+    async fn get_order_history(
+        &self,
+        pair: &TradingPair,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<Order>, ExchangeError> {
+        self.retry
+            .run("Bybit get_order_history", || {
+                self.fetch_order_history(pair, since)
+            })
+            .await
+    }
+
+    async fn cancel_order(
+        &self,
+        pair: &TradingPair,
+        order_id: &str,
+    ) -> Result<(), ExchangeError> {
+        self.retry
+            .run("Bybit cancel_order", || self.do_cancel_order(pair, order_id))
+            .await
+    }
+
+    async fn get_order_fill(
+        &self,
+        pair: &TradingPair,
+        order_id: &str,
+    ) -> Result<OrderFill, ExchangeError> {
+        self.retry
+            .run("Bybit get_order_fill", || self.fetch_order_fill(pair, order_id))
+            .await
+    }
+
+    async fn withdraw(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        address: &str,
+        network: &str,
+    ) -> Result<String, ExchangeError> {
+        if !self.config.allow_withdrawals {
+            return Err(ExchangeError::Api(
+                "Withdrawals disabled for Bybit (set allow_withdrawals)".to_string(),
+            ));
         }
+        self.retry
+            .run("Bybit withdraw", || {
+                self.do_withdraw(asset, amount, address, network)
+            })
+            .await
+    }
+
+    async fn get_deposit_address(
+        &self,
+        asset: &str,
+        network: &str,
+    ) -> Result<DepositAddress, ExchangeError> {
+        self.retry
+            .run("Bybit get_deposit_address", || {
+                self.fetch_deposit_address(asset, network)
+            })
+            .await
+    }
 
-        let body_str = serde_json::to_string(&body).unwrap();
-        let signature = self.sign_request(timestamp, &body_str);
+    async fn get_balances(&self) -> Result<Vec<ExchangeBalance>, ExchangeError> {
+        self.retry
+            .run("Bybit get_balances", || self.fetch_balances())
+            .await
+    }
 
-        let url = format!("{}/v5/order/create", BYBIT_REST_URL);
+    async fn get_account_events(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<AccountEvent>, ExchangeError> {
+        self.retry
+            .run("Bybit get_account_events", || self.fetch_account_events(since))
+            .await
+    }
+
+    fn fee_pct(&self) -> Decimal {
+        self.config.fee_pct
+    }
+
+    fn parse_error_count(&self) -> u64 {
+        self.parse_errors.count()
+    }
+
+    fn last_ws_message_ms(&self) -> i64 {
+        self.last_ws_message.load(Ordering::Relaxed)
+    }
+
+    fn ws_reconnect_count(&self) -> u64 {
+        self.ws_reconnects.load(Ordering::Relaxed)
+    }
+
+    fn api_version(&self) -> &'static str {
+        BYBIT_API_VERSION
+    }
+
+    async fn probe_compatibility(&self) -> Result<(), ExchangeError> {
+        let url = format!(
+            "{}/v5/market/tickers?category=spot&symbol=BTCUSDT",
+            BYBIT_REST_URL
+        );
 
         let resp = self
             .client
-            .post(&url)
-            .header("X-BAPI-API-KEY", &self.config.api_key)
-            .header("X-BAPI-SIGN", &signature)
-            .header("X-BAPI-TIMESTAMP", timestamp.to_string())
-            .header("Content-Type", "application/json")
-            .body(body_str)
+            .get(&url)
             .send()
             .await
             .map_err(|e| ExchangeError::Connection(e.to_string()))?;
@@ -317,34 +1118,34 @@ impl ExchangeConnector for BybitConnector {
             .await
             .map_err(|e| ExchangeError::Parse(e.to_string()))?;
 
-        if data["retCode"].as_i64() == Some(0) {
-            Ok(data["result"]["orderId"]
-                .as_str()
-                .unwrap_or("unknown")
-                .to_string())
-        } else {
-            Err(ExchangeError::OrderFailed(
-                data["retMsg"]
-                    .as_str()
-                    .unwrap_or("Unknown error")
-                    .to_string(),
-            ))
+        if data["retCode"].as_i64() != Some(0) {
+            return Err(ExchangeError::Api(format!(
+                "Bybit {} probe failed: retCode={}",
+                BYBIT_API_VERSION, data["retCode"]
+            )));
         }
-    }
 
-    async fn get_balances(&self) -> Result<Vec<ExchangeBalance>, ExchangeError> {
-        let timestamp = Utc::now().timestamp_millis();
-        let query = "accountType=UNIFIED";
-        let signature = self.sign_request(timestamp, query);
+        // Check the fields the ticker path depends on are still present
+        let item = &data["result"]["list"][0];
+        for field in ["symbol", "bid1Price", "ask1Price", "lastPrice", "volume24h"] {
+            if item[field].as_str().is_none() {
+                return Err(ExchangeError::Api(format!(
+                    "Bybit {} probe failed: missing field '{}'",
+                    BYBIT_API_VERSION, field
+                )));
+            }
+        }
+
+        Ok(())
+    }
 
-        let url = format!("{}/v5/account/wallet-balance?{}", BYBIT_REST_URL, query);
+    async fn sync_server_time(&self) -> Result<(), ExchangeError> {
+        let url = format!("{}/v5/market/time", BYBIT_REST_URL);
 
+        let local_before = Utc::now().timestamp_millis();
         let resp = self
             .client
             .get(&url)
-            .header("X-BAPI-API-KEY", &self.config.api_key)
-            .header("X-BAPI-SIGN", &signature)
-            .header("X-BAPI-TIMESTAMP", timestamp.to_string())
             .send()
             .await
             .map_err(|e| ExchangeError::Connection(e.to_string()))?;
@@ -353,32 +1154,22 @@ impl ExchangeConnector for BybitConnector {
             .json()
             .await
             .map_err(|e| ExchangeError::Parse(e.to_string()))?;
+        let local_after = Utc::now().timestamp_millis();
 
-        let coins = data["result"]["list"][0]["coin"]
-            .as_array()
-            .unwrap_or(&vec![])
-            .iter()
-            .filter_map(|c| {
-                let free: Decimal = c["availableToWithdraw"].as_str()?.parse().ok()?;
-                let locked: Decimal = c["locked"].as_str()?.parse().ok()?;
-                if free + locked > Decimal::ZERO {
-                    Some(ExchangeBalance {
-                        exchange: Exchange::Bybit,
-                        asset: c["coin"].as_str()?.to_string(),
-                        free,
-                        locked,
-                        total: free + locked,
-                    })
-                } else {
-                    None
-                }
+        let server_ms = data["result"]["timeNano"]
+            .as_str()
+            .and_then(|s| s.parse::<i64>().ok())
+            .map(|n| n / 1_000_000)
+            .or_else(|| {
+                data["result"]["timeSecond"]
+                    .as_str()
+                    .and_then(|s| s.parse::<i64>().ok())
+                    .map(|s| s * 1000)
             })
-            .collect();
-
-        Ok(coins)
-    }
+            .ok_or_else(|| ExchangeError::Parse("missing server time in response".to_string()))?;
 
-    fn fee_pct(&self) -> Decimal {
-        self.config.fee_pct
+        self.time_sync.update(server_ms, local_before, local_after);
+        tracing::debug!("Bybit clock offset: {}ms", self.time_sync.offset_ms());
+        Ok(())
     }
 }
@@ -1,7 +1,13 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::Arc;
 
-use crate::types::{Exchange, ExchangeBalance, OrderSide, OrderType, Ticker, TradingPair};
+use crate::types::{
+    AccountEvent, DepositAddress, Exchange, ExchangeBalance, FundingRate, Order, OrderBook,
+    OrderBookLevel, OrderFill, OrderSide, OrderType, Ticker, TradingPair,
+};
 
 pub mod bybit;
 pub mod bitget;
@@ -21,6 +27,27 @@ pub trait ExchangeConnector: Send + Sync {
     /// Get the current best bid/ask for a pair (REST fallback)
     async fn get_ticker(&self, pair: &TradingPair) -> Result<Ticker, ExchangeError>;
 
+    /// Get an L2 order book snapshot for a pair, up to `depth` levels a side
+    async fn get_order_book(
+        &self,
+        pair: &TradingPair,
+        depth: u32,
+    ) -> Result<OrderBook, ExchangeError>;
+
+    /// Current funding rate on the pair's perpetual market
+    async fn get_funding_rate(&self, pair: &TradingPair) -> Result<FundingRate, ExchangeError>;
+
+    /// Place a margin order (borrowing the asset automatically when
+    /// selling without inventory)
+    async fn place_margin_order(
+        &self,
+        pair: &TradingPair,
+        side: OrderSide,
+        order_type: OrderType,
+        quantity: Decimal,
+        price: Option<Decimal>,
+    ) -> Result<Order, ExchangeError>;
+
     /// Place an order on this exchange
     async fn place_order(
         &self,
@@ -29,13 +56,310 @@ pub trait ExchangeConnector: Send + Sync {
         order_type: OrderType,
         quantity: Decimal,
         price: Option<Decimal>,
-    ) -> Result<String, ExchangeError>; // Returns order ID
+    ) -> Result<Order, ExchangeError>;
+
+    /// Orders still working on the exchange for a pair
+    async fn get_open_orders(&self, pair: &TradingPair) -> Result<Vec<Order>, ExchangeError>;
+
+    /// Completed and cancelled orders for a pair since the given time —
+    /// the exchange's own record, used by reconciliation
+    async fn get_order_history(
+        &self,
+        pair: &TradingPair,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<Order>, ExchangeError>;
+
+    /// Cancel an open order by its exchange-assigned id
+    async fn cancel_order(&self, pair: &TradingPair, order_id: &str)
+        -> Result<(), ExchangeError>;
+
+    /// Realized execution details for an order: average fill price, filled
+    /// quantity and the fee actually charged
+    async fn get_order_fill(
+        &self,
+        pair: &TradingPair,
+        order_id: &str,
+    ) -> Result<OrderFill, ExchangeError>;
+
+    /// Withdraw an asset to an external address, returning the venue's
+    /// withdrawal id. Refused unless the venue's `allow_withdrawals`
+    /// config flag is set — a prerequisite for automated rebalancing.
+    async fn withdraw(
+        &self,
+        asset: &str,
+        amount: Decimal,
+        address: &str,
+        network: &str,
+    ) -> Result<String, ExchangeError>;
+
+    /// Deposit address for an asset on the given network
+    async fn get_deposit_address(
+        &self,
+        asset: &str,
+        network: &str,
+    ) -> Result<DepositAddress, ExchangeError>;
 
     /// Get balances for all assets on this exchange
     async fn get_balances(&self) -> Result<Vec<ExchangeBalance>, ExchangeError>;
 
+    /// Fetch account-level events (deposits, withdrawals, fills) since the
+    /// given time, so activity outside the bot is visible to inventory
+    /// tracking and reconciliation
+    async fn get_account_events(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<AccountEvent>, ExchangeError>;
+
     /// Get the trading fee for a pair on this exchange (as a percentage)
     fn fee_pct(&self) -> Decimal;
+
+    /// Number of payload parse failures recorded since startup (strict mode)
+    fn parse_error_count(&self) -> u64;
+
+    /// Milliseconds since the Unix epoch of the last WebSocket message
+    /// received on any subscription, or 0 if none has arrived yet
+    fn last_ws_message_ms(&self) -> i64;
+
+    /// WS reconnect attempts since startup — a rough connection-stability
+    /// metric surfaced in the exchange health registry
+    fn ws_reconnect_count(&self) -> u64 {
+        0
+    }
+
+    /// Order-book checksum mismatches observed since startup — a
+    /// data-integrity metric. Venues whose depth feeds carry no checksum
+    /// report 0.
+    fn checksum_failure_count(&self) -> u64 {
+        0
+    }
+
+    /// Exchange API version this connector is written against (e.g. "v5")
+    fn api_version(&self) -> &'static str;
+
+    /// Verify the exchange's public API still matches the schema this
+    /// connector assumes (a smoke test against a live public endpoint).
+    /// Run at startup so breaking API changes fail health checks early
+    /// instead of surfacing as degraded data or P&L.
+    async fn probe_compatibility(&self) -> Result<(), ExchangeError>;
+
+    /// Query the exchange's server time and update the local clock-skew
+    /// estimate used when building signed timestamps. Hosts with drifting
+    /// clocks otherwise hit intermittent "timestamp expired" auth errors.
+    async fn sync_server_time(&self) -> Result<(), ExchangeError>;
+}
+
+/// Estimated offset between the local clock and an exchange's server clock.
+///
+/// Signed request timestamps are built from `now_ms()` so auth keeps working
+/// on hosts whose clocks drift from the exchange's.
+#[derive(Debug, Default)]
+pub struct TimeSync {
+    /// server_time - local_time, in milliseconds
+    offset_ms: AtomicI64,
+}
+
+impl TimeSync {
+    /// Local time adjusted onto the exchange's clock, in milliseconds
+    pub fn now_ms(&self) -> i64 {
+        Utc::now().timestamp_millis() + self.offset_ms.load(Ordering::Relaxed)
+    }
+
+    pub fn offset_ms(&self) -> i64 {
+        self.offset_ms.load(Ordering::Relaxed)
+    }
+
+    /// Update the skew estimate from a server-time sample, compensating for
+    /// half the round trip
+    pub fn update(&self, server_ms: i64, local_before_ms: i64, local_after_ms: i64) {
+        let midpoint = local_before_ms + (local_after_ms - local_before_ms) / 2;
+        self.offset_ms.store(server_ms - midpoint, Ordering::Relaxed);
+    }
+}
+
+/// Shared retry policy for exchange REST calls.
+///
+/// Wraps an operation in exponential backoff with jitter so transient
+/// connection errors and rate limits don't immediately fail a trade leg.
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_backoff_ms: u64,
+    max_backoff_ms: u64,
+    retry_api_errors: bool,
+    /// Per-endpoint round-trip recorder, when latency tracking is attached
+    sla: Option<(Exchange, Arc<crate::sla::VenueSla>)>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::from_config(&crate::config::RetryConfig::default())
+    }
+}
+
+impl RetryPolicy {
+    pub fn from_config(cfg: &crate::config::RetryConfig) -> Self {
+        Self {
+            max_attempts: cfg.max_attempts.max(1),
+            base_backoff_ms: cfg.base_backoff_ms,
+            max_backoff_ms: cfg.max_backoff_ms,
+            retry_api_errors: cfg.retry_api_errors,
+            sla: None,
+        }
+    }
+
+    /// Attach a latency tracker: each successful attempt's round trip is
+    /// recorded against the endpoint name (the word after the venue in
+    /// `op_name`, e.g. "Bybit get_ticker" → "get_ticker")
+    pub fn with_latency(mut self, exchange: Exchange, sla: Arc<crate::sla::VenueSla>) -> Self {
+        self.sla = Some((exchange, sla));
+        self
+    }
+
+    /// Whether an error class is worth retrying under this policy
+    fn is_retryable(&self, err: &ExchangeError) -> bool {
+        match err {
+            ExchangeError::Connection(_) | ExchangeError::RateLimit => true,
+            ExchangeError::Api(_) => self.retry_api_errors,
+            _ => false,
+        }
+    }
+
+    /// Run `op`, retrying retryable failures with exponential backoff + jitter
+    pub async fn run<T, F, Fut>(&self, op_name: &str, mut op: F) -> Result<T, ExchangeError>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = Result<T, ExchangeError>>,
+    {
+        let mut attempt: u32 = 0;
+        loop {
+            attempt += 1;
+            let started = std::time::Instant::now();
+            match op().await {
+                Ok(v) => {
+                    if let Some((exchange, sla)) = &self.sla {
+                        let endpoint = op_name.split_whitespace().last().unwrap_or(op_name);
+                        sla.record_rest_rtt(
+                            *exchange,
+                            endpoint,
+                            started.elapsed().as_secs_f64() * 1_000.0,
+                        );
+                    }
+                    return Ok(v);
+                }
+                Err(e) if attempt < self.max_attempts && self.is_retryable(&e) => {
+                    let backoff = self
+                        .base_backoff_ms
+                        .saturating_mul(1u64 << (attempt - 1).min(16))
+                        .min(self.max_backoff_ms);
+                    // Up to 50% jitter so concurrent retries don't align
+                    let jitter = rand::random::<u64>() % (backoff / 2 + 1);
+                    tracing::warn!(
+                        "{} failed (attempt {}/{}): {} — retrying in {}ms",
+                        op_name,
+                        attempt,
+                        self.max_attempts,
+                        e,
+                        backoff + jitter
+                    );
+                    tokio::time::sleep(std::time::Duration::from_millis(backoff + jitter)).await;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Generate a client order id to attach to every submitted order, so a
+/// request retried after a network timeout is rejected by the venue as a
+/// duplicate instead of double-filling, and so local trades can be joined
+/// to exchange fills unambiguously. Kept to 36 characters for Bybit's
+/// `orderLinkId` limit.
+pub(crate) fn new_client_order_id() -> String {
+    format!("arb-{}", uuid::Uuid::new_v4().simple())
+}
+
+/// Parse `[["price","qty"], …]` order book levels as both exchanges return them
+pub(crate) fn parse_levels(v: &serde_json::Value) -> Vec<OrderBookLevel> {
+    v.as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|lvl| {
+                    let price: Decimal = lvl[0].as_str()?.parse().ok()?;
+                    let qty: Decimal = lvl[1].as_str()?.parse().ok()?;
+                    Some(OrderBookLevel { price, qty })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Convert an exchange millisecond timestamp (string or integer) to UTC
+pub(crate) fn ms_to_utc(v: &serde_json::Value) -> DateTime<Utc> {
+    let ms = v
+        .as_str()
+        .and_then(|s| s.parse::<i64>().ok())
+        .or_else(|| v.as_i64())
+        .unwrap_or(0);
+    DateTime::from_timestamp_millis(ms).unwrap_or_else(Utc::now)
+}
+
+/// Counts payload parse failures for a connector.
+///
+/// With `strict_parse` enabled in the exchange config, unexpected payload
+/// shapes are counted and logged as alert-worthy errors instead of fields
+/// silently defaulting to zero — so an exchange API format change shows up
+/// in the logs within minutes instead of via degraded P&L.
+#[derive(Debug, Default)]
+pub struct ParseErrorCounter {
+    count: AtomicU64,
+}
+
+impl ParseErrorCounter {
+    /// Record a parse failure. Logs the first few occurrences in full, then
+    /// every 100th to avoid flooding the logs on a sustained format change.
+    pub fn record(&self, exchange: Exchange, detail: &str) {
+        let n = self.count.fetch_add(1, Ordering::Relaxed) + 1;
+        if n <= 10 || n.is_multiple_of(100) {
+            tracing::error!("[{}] Strict parse error #{}: {}", exchange, n, detail);
+        }
+    }
+
+    /// Total failures recorded since startup
+    pub fn count(&self) -> u64 {
+        self.count.load(Ordering::Relaxed)
+    }
+
+    /// Parse an optional string field into a Decimal.
+    ///
+    /// Missing or unparseable values fall back to zero as before; in strict
+    /// mode an unparseable value is always recorded, and a missing value is
+    /// recorded when the field is `required` for this exchange's payloads.
+    pub fn parse_field(
+        &self,
+        strict: bool,
+        exchange: Exchange,
+        name: &str,
+        raw: Option<&str>,
+        required: bool,
+    ) -> Decimal {
+        match raw {
+            Some(s) => match s.parse() {
+                Ok(v) => v,
+                Err(_) => {
+                    if strict {
+                        self.record(exchange, &format!("unparseable {} value '{}'", name, s));
+                    }
+                    Decimal::ZERO
+                }
+            },
+            None => {
+                if strict && required {
+                    self.record(exchange, &format!("missing {} field", name));
+                }
+                Decimal::ZERO
+            }
+        }
+    }
 }
 
 /// Exchange-related errors
@@ -1,77 +1,327 @@
 use chrono::Utc;
 use rust_decimal::Decimal;
 use rust_decimal_macros::dec;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::{mpsc, Mutex};
+use tokio::sync::{mpsc, watch, Mutex, Semaphore};
 use tracing::{error, info, warn};
 use uuid::Uuid;
 
 use crate::config::Config;
 use crate::exchange::ExchangeConnector;
+use crate::fx::FxRateCache;
+use crate::orders::OrderTracker;
+use crate::positions::PositionTracker;
+use crate::prices::PriceCache;
+use crate::sla::VenueSla;
 use crate::types::*;
 
+/// Opportunities waiting for a trade slot; beyond this the worst are shed
+const OPPORTUNITY_QUEUE_CAP: usize = 16;
+
+/// Queued opportunities older than this are stale and dropped unexecuted
+const OPPORTUNITY_MAX_AGE_MS: i64 = 2_000;
+
+/// A queued opportunity, ordered so the best net spread runs first and
+/// ties go to the fresher detection
+struct PendingOpportunity(ArbitrageOpportunity);
+
+impl PartialEq for PendingOpportunity {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.net_spread_pct == other.0.net_spread_pct
+            && self.0.detected_at == other.0.detected_at
+    }
+}
+
+impl Eq for PendingOpportunity {}
+
+impl PartialOrd for PendingOpportunity {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PendingOpportunity {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0
+            .net_spread_pct
+            .cmp(&other.0.net_spread_pct)
+            .then(self.0.detected_at.cmp(&other.0.detected_at))
+    }
+}
+
+/// Canary-period tracking for one pair
+#[derive(Debug, Default)]
+struct CanaryState {
+    trades: u32,
+    filled: u32,
+    slippage_bps_sum: Decimal,
+    promoted: bool,
+}
+
 /// Executes arbitrage trades based on detected opportunities
 pub struct OrderExecutor {
     connectors: Vec<Arc<dyn ExchangeConnector>>,
-    config: Config,
+    /// Live configuration; updated through POST /api/config at runtime
+    config_rx: watch::Receiver<Config>,
+    /// Latest prices, for re-pricing simulated fills after artificial latency
+    prices: Arc<PriceCache>,
+    /// FX rates for normalizing profits into the reporting currency
+    fx: Arc<FxRateCache>,
+    /// Rolling venue latency tracking (order-ack times recorded here)
+    sla: Arc<VenueSla>,
+    /// Central registry of orders this bot has placed
+    orders: Arc<OrderTracker>,
+    /// Per-asset, per-exchange inventory, updated as trades execute
+    positions: Arc<PositionTracker>,
     /// Trade history
     trades: Arc<Mutex<Vec<TradeResult>>>,
     /// Channel to broadcast executed trades
     trade_tx: mpsc::UnboundedSender<TradeResult>,
+    /// Epoch ms the executor loop last proved alive, for GET /readyz
+    last_alive: AtomicI64,
     /// Counters
     total_trades: Arc<AtomicU64>,
     total_profit: Arc<Mutex<Decimal>>,
     daily_loss: Arc<Mutex<Decimal>>,
+    /// Start of the accounting day `daily_loss` covers; the window rolls
+    /// at `risk.daily_reset_hour_utc`
+    loss_window_start: Arc<Mutex<chrono::DateTime<Utc>>>,
     /// Last trade timestamp for cooldown
     last_trade_at: Arc<Mutex<Option<chrono::DateTime<Utc>>>>,
+    /// Cumulative losses per strategy, consumed against
+    /// `risk.strategy_budgets`
+    strategy_losses: Arc<Mutex<HashMap<String, Decimal>>>,
+    /// Cumulative traded notional per venue, checked against
+    /// `risk.max_exposure_per_exchange`
+    exposure: Arc<Mutex<HashMap<Exchange, Decimal>>>,
+    /// Canary tracking per pair (fractional sizing until promoted)
+    canary: Arc<Mutex<HashMap<String, CanaryState>>>,
+    /// Bounds in-flight trades at `risk.max_concurrent_trades`
+    trade_slots: Arc<Semaphore>,
+    /// Consecutive Failed/PartialFill results, feeding the circuit breaker
+    consecutive_failures: Arc<AtomicU64>,
+    /// When the circuit breaker tripped, with the reason; None = closed
+    breaker_tripped: Arc<Mutex<Option<BreakerTrip>>>,
+    /// False on a standby instance until failover promotes it to leader
+    execution_enabled: Arc<AtomicBool>,
+    /// Operator kill switch: once set, nothing trades until an explicit
+    /// re-arm, regardless of failover state
+    kill_switch: Arc<AtomicBool>,
+    /// Operator pause (shared with the API layer and detector)
+    paused: Arc<AtomicBool>,
+    /// Runtime simulation flag, seeded from config and kept in sync with
+    /// API config updates (the `config` field itself is a startup copy)
+    simulation_mode: Arc<AtomicBool>,
+    /// Virtual per-venue balances for simulation, seeded from each
+    /// exchange's `sim_balances` — an empty map keeps the old
+    /// unlimited-funds behavior
+    virtual_balances: Arc<Mutex<HashMap<(Exchange, String), Decimal>>>,
+    /// Webhook notifications for breaker trips and risk-limit hits
+    notifier: Arc<crate::notify::Notifier>,
+}
+
+/// An open circuit breaker: when it tripped and why
+struct BreakerTrip {
+    at: chrono::DateTime<Utc>,
+    reason: String,
 }
 
 impl OrderExecutor {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         connectors: Vec<Arc<dyn ExchangeConnector>>,
-        config: Config,
+        config_rx: watch::Receiver<Config>,
         trade_tx: mpsc::UnboundedSender<TradeResult>,
+        prices: Arc<PriceCache>,
+        fx: Arc<FxRateCache>,
+        sla: Arc<VenueSla>,
+        orders: Arc<OrderTracker>,
+        positions: Arc<PositionTracker>,
+        execution_enabled: Arc<AtomicBool>,
+        paused: Arc<AtomicBool>,
+        notifier: Arc<crate::notify::Notifier>,
     ) -> Self {
+        // Startup snapshot for values that can't change after construction
+        let config = config_rx.borrow().clone();
+        let trade_slots = Arc::new(Semaphore::new(
+            config.risk.max_concurrent_trades.max(1) as usize,
+        ));
+        let risk_reset_hour = config.risk.daily_reset_hour_utc;
+        let simulation_mode = Arc::new(AtomicBool::new(config.engine.simulation_mode));
+
+        // Seed virtual balances so paper trading hits the same
+        // insufficient-balance conditions live mode would
+        let mut virtual_balances = HashMap::new();
+        for exchange in [Exchange::Bybit, Exchange::Bitget] {
+            if let Some(cfg) = config.get_exchange(&exchange) {
+                for (asset, qty) in &cfg.sim_balances {
+                    virtual_balances.insert((exchange, asset.to_uppercase()), *qty);
+                }
+            }
+        }
+
         Self {
             connectors,
-            config,
+            config_rx,
+            prices,
+            fx,
+            sla,
+            orders,
+            positions,
             trades: Arc::new(Mutex::new(Vec::new())),
             trade_tx,
+            last_alive: AtomicI64::new(0),
             total_trades: Arc::new(AtomicU64::new(0)),
             total_profit: Arc::new(Mutex::new(Decimal::ZERO)),
             daily_loss: Arc::new(Mutex::new(Decimal::ZERO)),
+            loss_window_start: Arc::new(Mutex::new(Self::window_start(
+                Utc::now(),
+                risk_reset_hour,
+            ))),
             last_trade_at: Arc::new(Mutex::new(None)),
+            strategy_losses: Arc::new(Mutex::new(HashMap::new())),
+            exposure: Arc::new(Mutex::new(HashMap::new())),
+            canary: Arc::new(Mutex::new(HashMap::new())),
+            trade_slots,
+            consecutive_failures: Arc::new(AtomicU64::new(0)),
+            breaker_tripped: Arc::new(Mutex::new(None)),
+            execution_enabled,
+            kill_switch: Arc::new(AtomicBool::new(false)),
+            paused,
+            simulation_mode,
+            virtual_balances: Arc::new(Mutex::new(virtual_balances)),
+            notifier,
         }
     }
 
-    /// Start listening for opportunities and execute trades
-    pub async fn start(&self, mut opportunity_rx: mpsc::UnboundedReceiver<ArbitrageOpportunity>) {
-        info!("Order executor started (simulation={})", self.config.engine.simulation_mode);
+    /// Start listening for opportunities and execute trades. Trades run
+    /// concurrently up to `risk.max_concurrent_trades`.
+    /// Epoch ms the executor loop last proved alive (0 = not started)
+    pub fn last_alive_ms(&self) -> i64 {
+        self.last_alive.load(Ordering::Relaxed)
+    }
+
+    pub async fn start(
+        self: Arc<Self>,
+        mut opportunity_rx: mpsc::UnboundedReceiver<ArbitrageOpportunity>,
+    ) {
+        info!(
+            "Order executor started (simulation={})",
+            self.is_simulation()
+        );
+
+        // Opportunities can arrive faster than trade slots free up; a small
+        // priority queue runs the best net spread first and sheds the rest
+        let mut queue: std::collections::BinaryHeap<PendingOpportunity> =
+            std::collections::BinaryHeap::new();
+
+        loop {
+            self.last_alive
+                .store(Utc::now().timestamp_millis(), Ordering::Relaxed);
+            // Block for the next opportunity only when idle, then drain
+            // whatever else has already arrived; the periodic wake keeps
+            // the liveness stamp fresh while the queue sits empty
+            if queue.is_empty() {
+                tokio::select! {
+                    received = opportunity_rx.recv() => match received {
+                        Some(opp) => queue.push(PendingOpportunity(opp)),
+                        None => break,
+                    },
+                    _ = tokio::time::sleep(std::time::Duration::from_secs(1)) => continue,
+                }
+            }
+            while let Ok(opp) = opportunity_rx.try_recv() {
+                queue.push(PendingOpportunity(opp));
+            }
+            if queue.len() > OPPORTUNITY_QUEUE_CAP {
+                // Keep only the best entries (sorted vec is ascending)
+                let mut sorted = queue.into_sorted_vec();
+                sorted.drain(..sorted.len() - OPPORTUNITY_QUEUE_CAP);
+                queue = sorted.into();
+            }
+
+            let Some(PendingOpportunity(opp)) = queue.pop() else {
+                continue;
+            };
+
+            // Stale by the time a slot opened up — the prices it was
+            // detected at are long gone
+            let age_ms = (Utc::now() - opp.detected_at).num_milliseconds();
+            if age_ms > OPPORTUNITY_MAX_AGE_MS {
+                continue;
+            }
 
-        while let Some(opp) = opportunity_rx.recv().await {
             if !opp.is_actionable {
                 continue;
             }
 
+            // Standby instances watch the same market data but never trade
+            // until failover promotes them
+            if !self.execution_enabled.load(Ordering::Relaxed) {
+                continue;
+            }
+
+            // Killed by the operator; stays down until re-armed
+            if self.kill_switch.load(Ordering::Relaxed) {
+                continue;
+            }
+
+            // Paused by the operator
+            if self.paused.load(Ordering::Relaxed) {
+                continue;
+            }
+
             // Check risk limits
             if let Err(reason) = self.check_risk_limits(&opp).await {
                 warn!("Skipping opportunity {}: {}", opp.id, reason);
+                self.notifier.notify(
+                    crate::notify::NotificationKind::RiskLimit,
+                    "Risk limit hit",
+                    reason,
+                );
                 continue;
             }
 
             // Check cooldown
             if let Some(last) = *self.last_trade_at.lock().await {
                 let elapsed = (Utc::now() - last).num_milliseconds() as u64;
-                if elapsed < self.config.risk.trade_cooldown_ms {
+                if elapsed < self.cfg().trade_cooldown_ms_for(&opp.pair) {
                     continue;
                 }
             }
 
-            // Execute the trade
+            // Execute in a worker, bounded by the concurrency limit
+            let permit = match self.trade_slots.clone().acquire_owned().await {
+                Ok(permit) => permit,
+                Err(_) => break,
+            };
+            let executor = self.clone();
+            tokio::spawn(async move {
+                executor.execute_and_record(opp).await;
+                drop(permit);
+            });
+        }
+    }
+
+    /// Execute one opportunity and fold the outcome into the counters,
+    /// history and broadcast channel
+    #[tracing::instrument(name = "execute_opportunity", skip_all, fields(opportunity_id = %opp.id, pair = %opp.pair))]
+    async fn execute_and_record(&self, opp: ArbitrageOpportunity) {
+        {
             let result = self.execute_trade(&opp).await;
             match &result {
                 Ok(trade) => {
+                    self.positions.apply_trade(trade);
+                    {
+                        let mut exposure = self.exposure.lock().await;
+                        *exposure.entry(trade.buy_exchange).or_default() +=
+                            trade.quantity * trade.buy_price;
+                        *exposure.entry(trade.sell_exchange).or_default() +=
+                            trade.quantity * trade.sell_price;
+                    }
+                    self.record_canary_result(&opp, Some(trade)).await;
                     info!(
                         "Trade executed: {} | Buy {} @ {} on {} | Sell @ {} on {} | Profit: {}",
                         trade.id,
@@ -83,39 +333,302 @@ impl OrderExecutor {
                         trade.net_profit,
                     );
 
-                    // Update counters
+                    // Update counters — profit and the daily-loss limit
+                    // are tracked in the reporting currency so pairs with
+                    // different quotes aggregate sensibly
+                    let reported = trade.net_profit_reporting.unwrap_or(trade.net_profit);
                     self.total_trades.fetch_add(1, Ordering::Relaxed);
-                    *self.total_profit.lock().await += trade.net_profit;
-                    if trade.net_profit < Decimal::ZERO {
-                        *self.daily_loss.lock().await += trade.net_profit.abs();
+                    *self.total_profit.lock().await += reported;
+                    if reported < Decimal::ZERO {
+                        *self.daily_loss.lock().await += reported.abs();
+                        if !trade.strategy.is_empty() {
+                            *self
+                                .strategy_losses
+                                .lock()
+                                .await
+                                .entry(trade.strategy.clone())
+                                .or_default() += reported.abs();
+                        }
                     }
                     *self.last_trade_at.lock().await = Some(Utc::now());
 
                     // Store and broadcast
                     self.trades.lock().await.push(trade.clone());
                     let _ = self.trade_tx.send(trade.clone());
+
+                    self.record_breaker_outcome(&opp, Some(trade)).await;
                 }
                 Err(e) => {
+                    self.record_canary_result(&opp, None).await;
                     error!("Trade execution failed for opportunity {}: {}", opp.id, e);
+                    self.record_breaker_outcome(&opp, None).await;
                 }
             }
         }
     }
 
+    /// Fold one trade outcome into the circuit breaker: consecutive bad
+    /// results or a single badly slipped fill pause trading
+    async fn record_breaker_outcome(&self, opp: &ArbitrageOpportunity, trade: Option<&TradeResult>) {
+        if !self.cfg().circuit_breaker.enabled {
+            return;
+        }
+
+        let bad = match trade {
+            None => true,
+            Some(trade) => matches!(trade.status, TradeStatus::Failed | TradeStatus::PartialFill),
+        };
+
+        if let Some(trade) = trade {
+            // Realized slippage vs. the prices the opportunity was priced on
+            let mut slippage_pct = Decimal::ZERO;
+            if opp.buy_price > Decimal::ZERO {
+                slippage_pct += (trade.buy_price - opp.buy_price) / opp.buy_price * dec!(100);
+            }
+            if opp.sell_price > Decimal::ZERO {
+                slippage_pct += (opp.sell_price - trade.sell_price) / opp.sell_price * dec!(100);
+            }
+            if slippage_pct > self.cfg().circuit_breaker.max_slippage_pct {
+                self.trip_breaker(format!(
+                    "realized slippage {:.4}% > max {}%",
+                    slippage_pct, self.cfg().circuit_breaker.max_slippage_pct
+                ))
+                .await;
+                return;
+            }
+        }
+
+        if bad {
+            let failures = self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+            if failures >= self.cfg().circuit_breaker.max_consecutive_failures as u64 {
+                self.trip_breaker(format!("{} consecutive failed trades", failures))
+                    .await;
+            }
+        } else {
+            self.consecutive_failures.store(0, Ordering::Relaxed);
+        }
+    }
+
+    async fn trip_breaker(&self, reason: String) {
+        let mut tripped = self.breaker_tripped.lock().await;
+        if tripped.is_none() {
+            error!(
+                "Circuit breaker tripped: {} — pausing trading for {}s",
+                reason, self.cfg().circuit_breaker.cooloff_secs
+            );
+            self.notifier.notify(
+                crate::notify::NotificationKind::CircuitBreaker,
+                "Circuit breaker tripped",
+                reason.clone(),
+            );
+            *tripped = Some(BreakerTrip {
+                at: Utc::now(),
+                reason,
+            });
+        }
+    }
+
+    /// The open breaker's reason, or None when trading may proceed. An
+    /// expired cool-off closes the breaker on the way through.
+    pub async fn breaker_state(&self) -> Option<String> {
+        if !self.cfg().circuit_breaker.enabled {
+            return None;
+        }
+        let mut tripped = self.breaker_tripped.lock().await;
+        let trip = tripped.as_ref()?;
+        let elapsed = (Utc::now() - trip.at).num_seconds();
+        if elapsed >= self.cfg().circuit_breaker.cooloff_secs as i64 {
+            info!("Circuit breaker cool-off elapsed, resuming trading");
+            *tripped = None;
+            self.consecutive_failures.store(0, Ordering::Relaxed);
+            return None;
+        }
+        Some(trip.reason.clone())
+    }
+
+    /// Whether trades are currently simulated rather than sent to venues
+    pub fn is_simulation(&self) -> bool {
+        self.simulation_mode.load(Ordering::Relaxed)
+    }
+
+    /// Switch between simulation and live execution at runtime (driven by
+    /// API config updates)
+    pub fn set_simulation_mode(&self, simulation: bool) {
+        let was = self.simulation_mode.swap(simulation, Ordering::Relaxed);
+        if was && !simulation {
+            error!("LIVE TRADING ENABLED — orders will be sent to exchanges");
+        } else if !was && simulation {
+            info!("Simulation mode enabled — orders are no longer sent to exchanges");
+        }
+    }
+
+    /// Engage the kill switch: no opportunity executes until `rearm`
+    pub fn kill(&self) {
+        if !self.kill_switch.swap(true, Ordering::Relaxed) {
+            error!("Kill switch engaged: trading halted until re-armed");
+        }
+    }
+
+    /// Disengage the kill switch and resume normal gating
+    pub fn rearm(&self) {
+        if self.kill_switch.swap(false, Ordering::Relaxed) {
+            info!("Kill switch re-armed: trading may resume");
+        }
+    }
+
+    /// True while the kill switch is engaged
+    pub fn is_killed(&self) -> bool {
+        self.kill_switch.load(Ordering::Relaxed)
+    }
+
+    /// Why order placement is currently blocked, if it is — so subsystems
+    /// that place orders outside the main execution loop (flatten,
+    /// funding) honor the same operator controls
+    pub fn trading_halt_reason(&self) -> Option<&'static str> {
+        if !self.execution_enabled.load(Ordering::Relaxed) {
+            Some("execution disabled (standby)")
+        } else if self.kill_switch.load(Ordering::Relaxed) {
+            Some("kill switch engaged")
+        } else if self.paused.load(Ordering::Relaxed) {
+            Some("engine paused")
+        } else {
+            None
+        }
+    }
+
+    /// Close the breaker immediately (operator reset)
+    pub async fn reset_breaker(&self) {
+        let mut tripped = self.breaker_tripped.lock().await;
+        if tripped.take().is_some() {
+            info!("Circuit breaker manually reset");
+        }
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+    }
+
+    /// Snapshot of the current (live-updatable) configuration
+    fn cfg(&self) -> Config {
+        self.config_rx.borrow().clone()
+    }
+
+    /// Start of the accounting day containing `now`
+    fn window_start(now: chrono::DateTime<Utc>, reset_hour: u32) -> chrono::DateTime<Utc> {
+        let boundary = now
+            .date_naive()
+            .and_hms_opt(reset_hour.min(23), 0, 0)
+            .unwrap_or_default()
+            .and_utc();
+        if now < boundary {
+            boundary - chrono::Duration::days(1)
+        } else {
+            boundary
+        }
+    }
+
+    /// Zero `daily_loss` once the accounting day has rolled over
+    async fn roll_loss_window(&self) {
+        let window = Self::window_start(Utc::now(), self.cfg().risk.daily_reset_hour_utc);
+        let mut start = self.loss_window_start.lock().await;
+        if window > *start {
+            let mut daily_loss = self.daily_loss.lock().await;
+            if *daily_loss > Decimal::ZERO {
+                info!(
+                    "Daily loss window reset: clearing {} (window start {})",
+                    daily_loss, window
+                );
+            }
+            *daily_loss = Decimal::ZERO;
+            *start = window;
+        }
+    }
+
+    /// Seconds until the daily-loss window next resets
+    pub fn seconds_until_loss_reset(&self) -> i64 {
+        let next = Self::window_start(Utc::now(), self.cfg().risk.daily_reset_hour_utc)
+            + chrono::Duration::days(1);
+        (next - Utc::now()).num_seconds().max(0)
+    }
+
+    /// Current accumulated daily loss (reporting currency)
+    pub async fn get_daily_loss(&self) -> Decimal {
+        self.roll_loss_window().await;
+        *self.daily_loss.lock().await
+    }
+
     /// Validate risk limits before executing
     async fn check_risk_limits(&self, opp: &ArbitrageOpportunity) -> Result<(), String> {
+        if let Some(reason) = self.breaker_state().await {
+            return Err(format!("Circuit breaker open: {}", reason));
+        }
+        self.roll_loss_window().await;
         let daily_loss = *self.daily_loss.lock().await;
-        if daily_loss >= self.config.risk.max_daily_loss {
+        if daily_loss >= self.cfg().risk.max_daily_loss {
             return Err(format!(
                 "Daily loss limit reached: {} >= {}",
-                daily_loss, self.config.risk.max_daily_loss
+                daily_loss, self.cfg().risk.max_daily_loss
             ));
         }
 
-        if opp.quantity > self.config.risk.max_position {
+        // An experimental strategy stops trading once it has burned
+        // through its allocated bankroll
+        if let Some(budget) = self.cfg().risk.strategy_budgets.get(&opp.strategy) {
+            let consumed = self
+                .strategy_losses
+                .lock()
+                .await
+                .get(&opp.strategy)
+                .copied()
+                .unwrap_or(Decimal::ZERO);
+            if consumed >= *budget {
+                return Err(format!(
+                    "Strategy '{}' budget exhausted: {} >= {}",
+                    opp.strategy, consumed, budget
+                ));
+            }
+        }
+
+        // Don't keep draining one venue: refuse the sell leg once tracked
+        // base inventory there can't cover it (margin venues can borrow)
+        let sell_on_margin = self
+            .cfg()
+            .get_exchange(&opp.sell_exchange)
+            .map(|cfg| cfg.margin_enabled)
+            .unwrap_or(false);
+        if !sell_on_margin {
+            if let Some(base_inventory) = self
+                .positions
+                .inventory(opp.sell_exchange, &opp.pair.base)
+            {
+                if base_inventory < opp.quantity {
+                    return Err(format!(
+                        "Insufficient {} inventory on {}: {} < {}",
+                        opp.pair.base, opp.sell_exchange, base_inventory, opp.quantity
+                    ));
+                }
+            }
+        }
+
+        // Per-venue notional cap: both legs count against their venue
+        if let Some(max_exposure) = self.cfg().risk.max_exposure_per_exchange {
+            let exposure = self.exposure.lock().await;
+            for (exchange, notional) in [
+                (opp.buy_exchange, opp.quantity * opp.buy_price),
+                (opp.sell_exchange, opp.quantity * opp.sell_price),
+            ] {
+                let current = exposure.get(&exchange).copied().unwrap_or(Decimal::ZERO);
+                if current + notional > max_exposure {
+                    return Err(format!(
+                        "Exposure limit on {}: {} + {} > max {}",
+                        exchange, current, notional, max_exposure
+                    ));
+                }
+            }
+        }
+
+        let max_position = self.cfg().max_position_for(&opp.pair);
+        if opp.quantity > max_position {
             return Err(format!(
                 "Position too large: {} > max {}",
-                opp.quantity, self.config.risk.max_position
+                opp.quantity, max_position
             ));
         }
 
@@ -123,40 +636,89 @@ impl OrderExecutor {
     }
 
     /// Execute a buy on one exchange and a sell on another
+    #[tracing::instrument(name = "execute_trade", skip_all, fields(opportunity_id = %opp.id, buy = %opp.buy_exchange, sell = %opp.sell_exchange))]
     async fn execute_trade(
         &self,
         opp: &ArbitrageOpportunity,
     ) -> Result<TradeResult, String> {
         let trade_id = Uuid::new_v4().to_string();
 
-        if self.config.engine.simulation_mode {
-            // Simulation mode — don't place real orders
+        // Canary period: newly traded pairs run at a fraction of size
+        let scaled;
+        let opp = if let Some(fraction) = self.canary_fraction(&opp.pair).await {
+            let mut reduced = opp.clone();
+            reduced.quantity =
+                (reduced.quantity * fraction).max(self.cfg().min_trade_qty_for(&opp.pair));
+            info!(
+                "Canary sizing {}: {} -> {} ({}x)",
+                reduced.pair, opp.quantity, reduced.quantity, fraction
+            );
+            scaled = reduced;
+            &scaled
+        } else {
+            opp
+        };
+
+        if self.is_simulation() {
+            // Simulation mode — don't place real orders. Each leg waits out
+            // its exchange's artificial latency and re-prices against the
+            // latest ticker, then fill prices move against us by the
+            // configured slippage model instead of filling at exactly the
+            // quoted ask/bid.
             let buy_fee = self.get_fee(opp.buy_exchange);
             let sell_fee = self.get_fee(opp.sell_exchange);
 
-            let gross_profit = opp.quantity * (opp.sell_price - opp.buy_price);
-            let fees = opp.quantity * opp.buy_price * (buy_fee / dec!(100))
-                + opp.quantity * opp.sell_price * (sell_fee / dec!(100));
+            let submitted_at = Utc::now();
+            let (buy_fill, sell_fill) = tokio::join!(
+                self.simulated_leg_price(opp.buy_exchange, &opp.pair, OrderSide::Buy, opp.buy_price),
+                self.simulated_leg_price(opp.sell_exchange, &opp.pair, OrderSide::Sell, opp.sell_price),
+            );
+
+            let buy_slip = self.simulated_slippage_bps(opp.quantity);
+            let sell_slip = self.simulated_slippage_bps(opp.quantity);
+            let buy_price = buy_fill * (dec!(1) + buy_slip / dec!(10000));
+            let sell_price = sell_fill * (dec!(1) - sell_slip / dec!(10000));
+
+            let gross_profit = opp.quantity * (sell_price - buy_price);
+            let fees = opp.quantity * buy_price * (buy_fee / dec!(100))
+                + opp.quantity * sell_price * (sell_fee / dec!(100))
+                + self.borrow_cost(opp.sell_exchange, opp.quantity * sell_price);
             let net_profit = gross_profit - fees;
 
+            // Debit/credit the virtual balances; fails like a real
+            // InsufficientBalance would
+            self.settle_virtual_trade(opp, buy_price, sell_price, buy_fee, sell_fee)
+                .await?;
+
             return Ok(TradeResult {
                 id: trade_id,
                 opportunity_id: opp.id.clone(),
+                strategy: opp.strategy.clone(),
                 pair: opp.pair.clone(),
                 buy_exchange: opp.buy_exchange,
                 sell_exchange: opp.sell_exchange,
-                buy_price: opp.buy_price,
-                sell_price: opp.sell_price,
+                buy_price,
+                sell_price,
                 quantity: opp.quantity,
                 gross_profit,
                 fees,
                 net_profit,
+                net_profit_reporting: self.to_reporting(net_profit, &opp.pair.quote),
+                size_constraints: opp.size_constraints.clone(),
                 status: TradeStatus::Filled,
+                detect_to_submit_ms: (submitted_at - opp.detected_at).num_milliseconds(),
+                submit_to_fill_ms: (Utc::now() - submitted_at).num_milliseconds(),
                 executed_at: Utc::now(),
             });
         }
 
         // Live mode — execute simultaneously on both exchanges
+        if !self.cfg().pair_enabled_on(&opp.buy_exchange, &opp.pair) {
+            return Err("Pair disabled on buy exchange".to_string());
+        }
+        if !self.cfg().pair_enabled_on(&opp.sell_exchange, &opp.pair) {
+            return Err("Pair disabled on sell exchange".to_string());
+        }
         let buy_connector = self
             .connectors
             .iter()
@@ -169,32 +731,127 @@ impl OrderExecutor {
             .find(|c| c.exchange() == opp.sell_exchange)
             .ok_or("Sell exchange connector not found")?;
 
-        let order_type = if self.config.trading.order_type == "limit" {
+        let order_type = if self.cfg().order_type_for(&opp.pair) == "limit" {
             OrderType::Limit
         } else {
             OrderType::Market
         };
 
-        // Execute both orders concurrently
-        let buy_future = buy_connector.place_order(
-            &opp.pair,
-            OrderSide::Buy,
-            order_type,
-            opp.quantity,
-            Some(opp.buy_price),
-        );
+        // Execute both orders concurrently. When margin is enabled on the
+        // sell venue the sell leg borrows the base asset automatically, so
+        // it can execute without inventory there.
+        let sell_on_margin = self
+            .cfg()
+            .get_exchange(&opp.sell_exchange)
+            .map(|cfg| cfg.margin_enabled)
+            .unwrap_or(false);
 
-        let sell_future = sell_connector.place_order(
-            &opp.pair,
-            OrderSide::Sell,
-            order_type,
-            opp.quantity,
-            Some(opp.sell_price),
-        );
+        let submitted_at = Utc::now();
+        let buy_future = async {
+            let started = std::time::Instant::now();
+            let result = buy_connector
+                .place_order(
+                    &opp.pair,
+                    OrderSide::Buy,
+                    order_type,
+                    opp.quantity,
+                    Some(opp.buy_price),
+                )
+                .await;
+            (result, started.elapsed())
+        };
+
+        let sell_future = async {
+            let started = std::time::Instant::now();
+            let result = if sell_on_margin {
+                sell_connector
+                    .place_margin_order(
+                        &opp.pair,
+                        OrderSide::Sell,
+                        order_type,
+                        opp.quantity,
+                        Some(opp.sell_price),
+                    )
+                    .await
+            } else {
+                sell_connector
+                    .place_order(
+                        &opp.pair,
+                        OrderSide::Sell,
+                        order_type,
+                        opp.quantity,
+                        Some(opp.sell_price),
+                    )
+                    .await
+            };
+            (result, started.elapsed())
+        };
 
-        let (buy_result, sell_result) = tokio::join!(buy_future, sell_future);
+        let ((buy_result, buy_ack), (sell_result, sell_ack)) =
+            tokio::join!(buy_future, sell_future);
+        self.sla
+            .record_rest_ack(opp.buy_exchange, buy_ack.as_millis() as f64);
+        self.sla
+            .record_rest_ack(opp.sell_exchange, sell_ack.as_millis() as f64);
 
-        let status = match (&buy_result, &sell_result) {
+        // Track placed legs centrally so they show up in GET /api/orders
+        if let Ok(ref order) = buy_result {
+            self.orders.record(order.clone());
+        }
+        if let Ok(ref order) = sell_result {
+            self.orders.record(order.clone());
+        }
+
+        // Limit legs are GTC — follow each one up past the fill timeout,
+        // cancelling and re-placing per `trading.limit_timeout_action`
+        let mut buy_price = opp.buy_price;
+        let mut sell_price = opp.sell_price;
+        if matches!(order_type, OrderType::Limit) {
+            let (buy_follow, sell_follow) = tokio::join!(
+                async {
+                    match &buy_result {
+                        Ok(order) => {
+                            self.supervise_limit_leg(
+                                buy_connector,
+                                &opp.pair,
+                                &order.id,
+                                OrderSide::Buy,
+                                opp.quantity,
+                                opp.buy_price,
+                                false,
+                            )
+                            .await
+                        }
+                        Err(_) => None,
+                    }
+                },
+                async {
+                    match &sell_result {
+                        Ok(order) => {
+                            self.supervise_limit_leg(
+                                sell_connector,
+                                &opp.pair,
+                                &order.id,
+                                OrderSide::Sell,
+                                opp.quantity,
+                                opp.sell_price,
+                                sell_on_margin,
+                            )
+                            .await
+                        }
+                        Err(_) => None,
+                    }
+                },
+            );
+            if let Some(price) = buy_follow {
+                buy_price = price;
+            }
+            if let Some(price) = sell_follow {
+                sell_price = price;
+            }
+        }
+
+        let mut status = match (&buy_result, &sell_result) {
             (Ok(_), Ok(_)) => TradeStatus::Filled,
             (Ok(_), Err(_)) | (Err(_), Ok(_)) => TradeStatus::PartialFill,
             (Err(_), Err(_)) => TradeStatus::Failed,
@@ -204,29 +861,617 @@ impl OrderExecutor {
             return Err(format!("Both orders failed: buy={}, sell={}", e1, e2));
         }
 
+        // One leg filled, the other failed — first try to complete the
+        // failed leg at slightly worse prices, then reverse the filled leg
+        // at market instead of carrying naked directional exposure, booking
+        // the realized round-trip loss as this trade's P&L
+        let mut unwind = None;
+        if matches!(status, TradeStatus::PartialFill) {
+            let recovered = match (&buy_result, &sell_result) {
+                (Ok(_), Err(_)) => self
+                    .retry_failed_leg(
+                        sell_connector,
+                        &opp.pair,
+                        OrderSide::Sell,
+                        opp.quantity,
+                        opp.sell_price,
+                        sell_on_margin,
+                    )
+                    .await
+                    .map(|price| sell_price = price),
+                (Err(_), Ok(_)) => self
+                    .retry_failed_leg(
+                        buy_connector,
+                        &opp.pair,
+                        OrderSide::Buy,
+                        opp.quantity,
+                        opp.buy_price,
+                        false,
+                    )
+                    .await
+                    .map(|price| buy_price = price),
+                _ => None,
+            };
+            if recovered.is_some() {
+                status = TradeStatus::Filled;
+            }
+        }
+        if matches!(status, TradeStatus::PartialFill) && self.cfg().risk.unwind_partial_fills {
+            unwind = match (&buy_result, &sell_result) {
+                (Ok(_), Err(e)) => {
+                    error!(
+                        "UNWIND: sell leg on {} failed ({}) — reversing filled buy of {} {} on {}",
+                        opp.sell_exchange, e, opp.quantity, opp.pair, opp.buy_exchange
+                    );
+                    self.unwind_leg(
+                        buy_connector,
+                        &opp.pair,
+                        OrderSide::Buy,
+                        opp.quantity,
+                        opp.buy_price,
+                        false,
+                    )
+                    .await
+                }
+                (Err(e), Ok(_)) => {
+                    error!(
+                        "UNWIND: buy leg on {} failed ({}) — reversing filled sell of {} {} on {}",
+                        opp.buy_exchange, e, opp.quantity, opp.pair, opp.sell_exchange
+                    );
+                    self.unwind_leg(
+                        sell_connector,
+                        &opp.pair,
+                        OrderSide::Sell,
+                        opp.quantity,
+                        opp.sell_price,
+                        sell_on_margin,
+                    )
+                    .await
+                }
+                _ => None,
+            };
+        }
+
+        // Pull realized fill details so P&L is booked from the actual
+        // average prices, filled quantity and fees, not the detected quotes
+        let mut fill_qty = opp.quantity;
+        let mut reported_fees = None;
+        if unwind.is_none() {
+            let (buy_fill, sell_fill) = tokio::join!(
+                async {
+                    match &buy_result {
+                        Ok(order) => self.leg_fill(buy_connector, &opp.pair, &order.id).await,
+                        Err(_) => None,
+                    }
+                },
+                async {
+                    match &sell_result {
+                        Ok(order) => self.leg_fill(sell_connector, &opp.pair, &order.id).await,
+                        Err(_) => None,
+                    }
+                },
+            );
+            if let Some(fill) = &buy_fill {
+                buy_price = fill.avg_price;
+            }
+            if let Some(fill) = &sell_fill {
+                sell_price = fill.avg_price;
+            }
+            if let (Some(bought), Some(sold)) = (&buy_fill, &sell_fill) {
+                fill_qty = bought.filled_qty.min(sold.filled_qty);
+                reported_fees = Some(bought.fee + sold.fee);
+            }
+        }
+
         let buy_fee = buy_connector.fee_pct();
         let sell_fee = sell_connector.fee_pct();
-        let gross_profit = opp.quantity * (opp.sell_price - opp.buy_price);
-        let fees = opp.quantity * opp.buy_price * (buy_fee / dec!(100))
-            + opp.quantity * opp.sell_price * (sell_fee / dec!(100));
+        let (gross_profit, fees) = if let Some((price_loss, unwind_fees)) = unwind {
+            // Round trip on one venue: no arbitrage P&L, just the unwind cost
+            (-price_loss, unwind_fees)
+        } else {
+            let gross = fill_qty * (sell_price - buy_price);
+            // Fall back to the configured fee schedule when a venue didn't
+            // report what it charged
+            let fees = reported_fees.unwrap_or_else(|| {
+                fill_qty * buy_price * (buy_fee / dec!(100))
+                    + fill_qty * sell_price * (sell_fee / dec!(100))
+            }) + self.borrow_cost(opp.sell_exchange, fill_qty * sell_price);
+            (gross, fees)
+        };
 
         Ok(TradeResult {
             id: trade_id,
             opportunity_id: opp.id.clone(),
+            strategy: opp.strategy.clone(),
             pair: opp.pair.clone(),
             buy_exchange: opp.buy_exchange,
             sell_exchange: opp.sell_exchange,
-            buy_price: opp.buy_price,
-            sell_price: opp.sell_price,
-            quantity: opp.quantity,
+            buy_price,
+            sell_price,
+            quantity: fill_qty,
             gross_profit,
             fees,
             net_profit: gross_profit - fees,
+            net_profit_reporting: self.to_reporting(gross_profit - fees, &opp.pair.quote),
+            size_constraints: opp.size_constraints.clone(),
             status,
+            detect_to_submit_ms: (submitted_at - opp.detected_at).num_milliseconds(),
+            submit_to_fill_ms: (Utc::now() - submitted_at).num_milliseconds(),
             executed_at: Utc::now(),
         })
     }
 
+    /// Poll a leg's realized fill details, giving the venue a moment to
+    /// settle its execution reports. None when the venue can't report them,
+    /// in which case P&L falls back to the detected prices.
+    #[tracing::instrument(name = "leg_fill", skip_all, fields(exchange = %connector.exchange(), order_id = %order_id))]
+    async fn leg_fill(
+        &self,
+        connector: &Arc<dyn ExchangeConnector>,
+        pair: &TradingPair,
+        order_id: &str,
+    ) -> Option<OrderFill> {
+        let exchange = connector.exchange();
+        for attempt in 0..3 {
+            if attempt > 0 {
+                tokio::time::sleep(std::time::Duration::from_millis(250)).await;
+            }
+            match connector.get_order_fill(pair, order_id).await {
+                Ok(fill) if fill.avg_price > Decimal::ZERO && fill.filled_qty > Decimal::ZERO => {
+                    return Some(fill);
+                }
+                Ok(_) => {} // no executions reported yet — poll again
+                Err(e) => {
+                    warn!(
+                        "Could not fetch fill for order {} on {}: {}",
+                        order_id, exchange, e
+                    );
+                    return None;
+                }
+            }
+        }
+        warn!(
+            "No fill reported for order {} on {} after polling — booking detected prices",
+            order_id, exchange
+        );
+        None
+    }
+
+    /// Follow up a GTC limit leg: wait out `trading.limit_timeout_ms` and,
+    /// if the order is still working, cancel it and either convert it to a
+    /// market order or re-place the limit at the current top of book, per
+    /// `trading.limit_timeout_action`. Returns the replacement price when
+    /// the leg was re-placed, so P&L is booked against it.
+    #[allow(clippy::too_many_arguments)]
+    async fn supervise_limit_leg(
+        &self,
+        connector: &Arc<dyn ExchangeConnector>,
+        pair: &TradingPair,
+        order_id: &str,
+        side: OrderSide,
+        quantity: Decimal,
+        leg_price: Decimal,
+        on_margin: bool,
+    ) -> Option<Decimal> {
+        let cfg = self.cfg();
+        if cfg.trading.limit_timeout_ms == 0 {
+            return None;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(cfg.trading.limit_timeout_ms)).await;
+
+        let exchange = connector.exchange();
+        match connector.get_open_orders(pair).await {
+            Ok(open) if open.iter().any(|o| o.id == order_id) => {}
+            Ok(_) => return None, // filled within the timeout
+            Err(e) => {
+                warn!(
+                    "Could not check open orders for {} on {}: {} — leaving limit leg as-is",
+                    pair, exchange, e
+                );
+                return None;
+            }
+        }
+
+        if let Err(e) = connector.cancel_order(pair, order_id).await {
+            // Most likely filled between the check and the cancel
+            warn!(
+                "Cancel of stale limit leg {} on {} failed: {}",
+                order_id, exchange, e
+            );
+            return None;
+        }
+        self.orders.mark_cancelled(exchange, order_id);
+
+        // Aggressive replacement price: cross the current top of book
+        let top = self
+            .prices
+            .get(exchange, &pair.to_string())
+            .map(|t| match side {
+                OrderSide::Buy => t.ask,
+                OrderSide::Sell => t.bid,
+            })
+            .filter(|p| *p > Decimal::ZERO);
+        let reprice = cfg.trading.limit_timeout_action == "reprice" && top.is_some();
+        let est_price = top.unwrap_or(leg_price);
+        let (new_type, limit_price) = if reprice {
+            (OrderType::Limit, Some(est_price))
+        } else {
+            (OrderType::Market, None)
+        };
+
+        warn!(
+            "Limit leg {} on {} unfilled after {}ms — cancelled, re-placing as {:?} at ~{}",
+            order_id,
+            exchange,
+            cfg.trading.limit_timeout_ms,
+            new_type,
+            est_price.round_dp(6)
+        );
+        let result = if on_margin {
+            connector
+                .place_margin_order(pair, side, new_type, quantity, limit_price)
+                .await
+        } else {
+            connector
+                .place_order(pair, side, new_type, quantity, limit_price)
+                .await
+        };
+        match result {
+            Ok(order) => {
+                self.orders.record(order.clone());
+                Some(est_price)
+            }
+            Err(e) => {
+                error!(
+                    "Failed to re-place cancelled limit leg {:?} {} {} on {}: {} — leg is unfilled",
+                    side, quantity, pair, exchange, e
+                );
+                None
+            }
+        }
+    }
+
+    /// Re-attempt a failed leg at progressively worse limit prices, up to
+    /// the configured slippage budget. Returns the achieved price on
+    /// success so P&L is booked against the degraded fill.
+    async fn retry_failed_leg(
+        &self,
+        connector: &Arc<dyn ExchangeConnector>,
+        pair: &TradingPair,
+        side: OrderSide,
+        quantity: Decimal,
+        base_price: Decimal,
+        on_margin: bool,
+    ) -> Option<Decimal> {
+        let cfg = self.cfg();
+        let attempts = cfg.risk.leg_retry_attempts;
+        let budget = cfg.risk.leg_retry_max_slippage_bps;
+        if attempts == 0 || budget <= Decimal::ZERO {
+            return None;
+        }
+
+        // Spread the slippage budget evenly across attempts
+        let step = budget / Decimal::from(attempts);
+        let exchange = connector.exchange();
+        for attempt in 1..=attempts {
+            let slip = step * Decimal::from(attempt);
+            let price = match side {
+                OrderSide::Buy => base_price * (dec!(1) + slip / dec!(10000)),
+                OrderSide::Sell => base_price * (dec!(1) - slip / dec!(10000)),
+            };
+            warn!(
+                "Retrying failed {:?} leg of {} on {} at {} ({} bps worse, attempt {}/{})",
+                side,
+                pair,
+                exchange,
+                price.round_dp(6),
+                slip.round_dp(2),
+                attempt,
+                attempts
+            );
+            let result = if on_margin {
+                connector
+                    .place_margin_order(pair, side, OrderType::Limit, quantity, Some(price))
+                    .await
+            } else {
+                connector
+                    .place_order(pair, side, OrderType::Limit, quantity, Some(price))
+                    .await
+            };
+            match result {
+                Ok(order) => {
+                    self.orders.record(order.clone());
+                    info!(
+                        "Leg retry filled {:?} {} {} on {} at {} (order {})",
+                        side,
+                        quantity,
+                        pair,
+                        exchange,
+                        price.round_dp(6),
+                        order.id
+                    );
+                    return Some(price);
+                }
+                Err(e) => {
+                    warn!(
+                        "Leg retry {}/{} failed for {} on {}: {}",
+                        attempt, attempts, pair, exchange, e
+                    );
+                }
+            }
+        }
+        None
+    }
+
+    /// Reverse a lone filled leg with a market order. Returns the
+    /// estimated realized (price loss, fees) of the round trip in quote
+    /// currency, or None when the reverse order itself fails — in which
+    /// case the exposure is still open and needs manual attention.
+    async fn unwind_leg(
+        &self,
+        connector: &Arc<dyn ExchangeConnector>,
+        pair: &TradingPair,
+        filled_side: OrderSide,
+        quantity: Decimal,
+        fill_price: Decimal,
+        on_margin: bool,
+    ) -> Option<(Decimal, Decimal)> {
+        let exchange = connector.exchange();
+        let reverse_side = match filled_side {
+            OrderSide::Buy => OrderSide::Sell,
+            OrderSide::Sell => OrderSide::Buy,
+        };
+
+        // Estimate the exit price from the latest ticker; fall back to the
+        // original fill so a missing quote still yields a fee-only estimate
+        let exit_price = self
+            .prices
+            .get(exchange, &pair.to_string())
+            .map(|t| match reverse_side {
+                OrderSide::Buy => t.ask,
+                OrderSide::Sell => t.bid,
+            })
+            .filter(|p| *p > Decimal::ZERO)
+            .unwrap_or(fill_price);
+
+        let result = if on_margin {
+            connector
+                .place_margin_order(pair, reverse_side, OrderType::Market, quantity, None)
+                .await
+        } else {
+            connector
+                .place_order(pair, reverse_side, OrderType::Market, quantity, None)
+                .await
+        };
+
+        match result {
+            Ok(order) => {
+                self.orders.record(order.clone());
+                let price_loss = match filled_side {
+                    OrderSide::Buy => quantity * (fill_price - exit_price),
+                    OrderSide::Sell => quantity * (exit_price - fill_price),
+                };
+                let fees = (quantity * fill_price + quantity * exit_price)
+                    * (connector.fee_pct() / dec!(100));
+                error!(
+                    "UNWIND: reversed {:?} {} {} on {} (order {}) — realized loss ~{} + {} fees",
+                    filled_side,
+                    quantity,
+                    pair,
+                    exchange,
+                    order.id,
+                    price_loss.round_dp(4),
+                    fees.round_dp(4)
+                );
+                Some((price_loss, fees))
+            }
+            Err(e) => {
+                error!(
+                    "UNWIND FAILED: could not reverse {:?} {} {} on {}: {} — naked exposure remains",
+                    filled_side, quantity, pair, exchange, e
+                );
+                None
+            }
+        }
+    }
+
+    /// Apply one simulated trade to the virtual balances, refusing it when
+    /// a venue couldn't have funded its leg. A no-op when no venue has
+    /// `sim_balances` configured (the old unlimited-funds behavior).
+    async fn settle_virtual_trade(
+        &self,
+        opp: &ArbitrageOpportunity,
+        buy_price: Decimal,
+        sell_price: Decimal,
+        buy_fee: Decimal,
+        sell_fee: Decimal,
+    ) -> Result<(), String> {
+        let mut balances = self.virtual_balances.lock().await;
+        if balances.is_empty() {
+            return Ok(());
+        }
+
+        let base = opp.pair.base.to_uppercase();
+        let quote = opp.pair.quote.to_uppercase();
+        let cost = opp.quantity * buy_price * (dec!(1) + buy_fee / dec!(100));
+        let proceeds = opp.quantity * sell_price * (dec!(1) - sell_fee / dec!(100));
+
+        let quote_held = balances
+            .get(&(opp.buy_exchange, quote.clone()))
+            .copied()
+            .unwrap_or(Decimal::ZERO);
+        if quote_held < cost {
+            return Err(format!(
+                "Insufficient virtual balance on {}: need {} {}, have {}",
+                opp.buy_exchange, cost.round_dp(4), quote, quote_held.round_dp(4)
+            ));
+        }
+
+        // Margin venues borrow the base asset, exactly as the live sell
+        // leg would
+        let sell_on_margin = self
+            .cfg()
+            .get_exchange(&opp.sell_exchange)
+            .map(|cfg| cfg.margin_enabled)
+            .unwrap_or(false);
+        let base_held = balances
+            .get(&(opp.sell_exchange, base.clone()))
+            .copied()
+            .unwrap_or(Decimal::ZERO);
+        if !sell_on_margin && base_held < opp.quantity {
+            return Err(format!(
+                "Insufficient virtual balance on {}: need {} {}, have {}",
+                opp.sell_exchange, opp.quantity, base, base_held.round_dp(8)
+            ));
+        }
+
+        *balances.entry((opp.buy_exchange, quote.clone())).or_default() -= cost;
+        *balances.entry((opp.buy_exchange, base.clone())).or_default() += opp.quantity;
+        *balances.entry((opp.sell_exchange, base)).or_default() -= opp.quantity;
+        *balances.entry((opp.sell_exchange, quote)).or_default() += proceeds;
+        Ok(())
+    }
+
+    /// Current virtual balances, sorted for display
+    pub async fn virtual_balances(&self) -> Vec<(Exchange, String, Decimal)> {
+        let balances = self.virtual_balances.lock().await;
+        let mut rows: Vec<_> = balances
+            .iter()
+            .map(|((exchange, asset), qty)| (*exchange, asset.clone(), *qty))
+            .collect();
+        rows.sort_by_key(|(exchange, asset, _)| (asset.clone(), exchange.to_string()));
+        rows
+    }
+
+    /// The canary size fraction for this pair, or None once promoted (or
+    /// when canary mode is disabled)
+    async fn canary_fraction(&self, pair: &TradingPair) -> Option<Decimal> {
+        if !self.cfg().canary.enabled {
+            return None;
+        }
+        let canary = self.canary.lock().await;
+        match canary.get(&pair.to_string()) {
+            Some(state) if state.promoted => None,
+            _ => Some(self.cfg().canary.size_fraction),
+        }
+    }
+
+    /// Record realized slippage and fill quality for a canary-period pair,
+    /// promoting to full size once the window passes its thresholds
+    async fn record_canary_result(&self, opp: &ArbitrageOpportunity, trade: Option<&TradeResult>) {
+        if !self.cfg().canary.enabled {
+            return;
+        }
+        let mut canary = self.canary.lock().await;
+        let state = canary.entry(opp.pair.to_string()).or_default();
+        if state.promoted {
+            return;
+        }
+
+        state.trades += 1;
+        if let Some(trade) = trade {
+            // Adverse move of both fills vs the detected prices, in bps
+            let buy_slip = (trade.buy_price - opp.buy_price) / opp.buy_price;
+            let sell_slip = (opp.sell_price - trade.sell_price) / opp.sell_price;
+            state.slippage_bps_sum += (buy_slip + sell_slip) / dec!(2) * dec!(10000);
+            if matches!(trade.status, TradeStatus::Filled) {
+                state.filled += 1;
+            }
+        }
+
+        if state.trades >= self.cfg().canary.trades {
+            let avg_slippage = state.slippage_bps_sum / Decimal::from(state.trades);
+            let fill_rate = Decimal::from(state.filled) / Decimal::from(state.trades) * dec!(100);
+
+            if avg_slippage <= self.cfg().canary.max_avg_slippage_bps
+                && fill_rate >= self.cfg().canary.min_fill_rate_pct
+            {
+                state.promoted = true;
+                info!(
+                    "Canary passed for {}: avg slippage {} bps, fill rate {}% — promoting to full size",
+                    opp.pair, avg_slippage.round_dp(2), fill_rate.round_dp(1)
+                );
+            } else {
+                warn!(
+                    "Canary failed for {}: avg slippage {} bps, fill rate {}% — staying at {}x for another window",
+                    opp.pair, avg_slippage.round_dp(2), fill_rate.round_dp(1),
+                    self.cfg().canary.size_fraction
+                );
+                *state = CanaryState::default();
+            }
+        }
+    }
+
+    /// Wait out the exchange's simulated latency, then return the price
+    /// this leg would actually fill at — the latest quote at fill time, not
+    /// the one the opportunity was detected against. This exposes how many
+    /// "opportunities" would have evaporated before orders landed.
+    async fn simulated_leg_price(
+        &self,
+        exchange: Exchange,
+        pair: &TradingPair,
+        side: OrderSide,
+        detected_price: Decimal,
+    ) -> Decimal {
+        if let Some(cfg) = self.cfg().get_exchange(&exchange) {
+            let mut delay_ms = cfg.sim_latency_ms;
+            if cfg.sim_latency_jitter_ms > 0 {
+                delay_ms += rand::random::<u64>() % (cfg.sim_latency_jitter_ms + 1);
+            }
+            if delay_ms > 0 {
+                tokio::time::sleep(std::time::Duration::from_millis(delay_ms)).await;
+            }
+        }
+
+        self.prices
+            .get(exchange, &pair.to_string())
+            .map(|t| match side {
+                OrderSide::Buy => t.ask,
+                OrderSide::Sell => t.bid,
+            })
+            .unwrap_or(detected_price)
+    }
+
+    /// Adverse slippage (basis points) for one simulated leg, according to
+    /// the configured model
+    fn simulated_slippage_bps(&self, quantity: Decimal) -> Decimal {
+        let slip = &self.cfg().slippage;
+        match slip.model.as_str() {
+            "fixed" => slip.fixed_bps,
+            "random" => {
+                let span = (slip.max_bps - slip.min_bps).max(Decimal::ZERO);
+                let fraction =
+                    Decimal::from_f64_retain(rand::random::<f64>()).unwrap_or(Decimal::ZERO);
+                slip.min_bps + span * fraction
+            }
+            "depth" => slip.bps_per_qty * quantity,
+            _ => Decimal::ZERO,
+        }
+    }
+
+    /// An amount in a pair's quote currency expressed in the reporting
+    /// currency, or None when no conversion path is available
+    fn to_reporting(&self, amount: Decimal, quote: &str) -> Option<Decimal> {
+        self.fx.quote_to_reporting(
+            &self.prices,
+            amount,
+            quote,
+            &self.cfg().trading.reporting_currency,
+        )
+    }
+
+    /// Borrow interest charged against a margin sell of `notional` on this
+    /// exchange — one day of interest, a conservative bound since the
+    /// borrow is repaid as soon as the position settles. Zero when margin
+    /// is disabled for the venue.
+    fn borrow_cost(&self, exchange: Exchange, notional: Decimal) -> Decimal {
+        self.cfg()
+            .get_exchange(&exchange)
+            .filter(|cfg| cfg.margin_enabled)
+            .map(|cfg| notional * cfg.borrow_rate_daily_pct / dec!(100))
+            .unwrap_or(Decimal::ZERO)
+    }
+
     fn get_fee(&self, exchange: Exchange) -> Decimal {
         self.connectors
             .iter()
@@ -0,0 +1,165 @@
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::Serialize;
+
+use crate::types::{Exchange, TradeResult};
+
+/// Rolling window used for fee-tier qualification on both venues
+const VOLUME_WINDOW_DAYS: i64 = 30;
+
+/// One rung of a venue's published fee-tier schedule
+#[derive(Debug, Clone, Serialize)]
+pub struct FeeTier {
+    pub name: &'static str,
+    /// 30-day traded volume (USD) required to qualify
+    pub min_volume_usd: Decimal,
+    pub maker_fee_pct: Decimal,
+    pub taker_fee_pct: Decimal,
+}
+
+/// Published spot fee-tier schedule for an exchange, lowest tier first
+pub fn fee_schedule(exchange: Exchange) -> Vec<FeeTier> {
+    match exchange {
+        Exchange::Bybit => vec![
+            FeeTier {
+                name: "VIP 0",
+                min_volume_usd: dec!(0),
+                maker_fee_pct: dec!(0.1),
+                taker_fee_pct: dec!(0.1),
+            },
+            FeeTier {
+                name: "VIP 1",
+                min_volume_usd: dec!(1_000_000),
+                maker_fee_pct: dec!(0.04),
+                taker_fee_pct: dec!(0.06),
+            },
+            FeeTier {
+                name: "VIP 2",
+                min_volume_usd: dec!(5_000_000),
+                maker_fee_pct: dec!(0.025),
+                taker_fee_pct: dec!(0.05),
+            },
+            FeeTier {
+                name: "VIP 3",
+                min_volume_usd: dec!(10_000_000),
+                maker_fee_pct: dec!(0.02),
+                taker_fee_pct: dec!(0.045),
+            },
+        ],
+        Exchange::Bitget => vec![
+            FeeTier {
+                name: "VIP 0",
+                min_volume_usd: dec!(0),
+                maker_fee_pct: dec!(0.1),
+                taker_fee_pct: dec!(0.1),
+            },
+            FeeTier {
+                name: "VIP 1",
+                min_volume_usd: dec!(1_000_000),
+                maker_fee_pct: dec!(0.08),
+                taker_fee_pct: dec!(0.08),
+            },
+            FeeTier {
+                name: "VIP 2",
+                min_volume_usd: dec!(5_000_000),
+                maker_fee_pct: dec!(0.06),
+                taker_fee_pct: dec!(0.07),
+            },
+            FeeTier {
+                name: "VIP 3",
+                min_volume_usd: dec!(20_000_000),
+                maker_fee_pct: dec!(0.04),
+                taker_fee_pct: dec!(0.06),
+            },
+        ],
+    }
+}
+
+/// Current fee-tier standing for one exchange, exposed via
+/// GET /api/fees/tiers
+#[derive(Debug, Clone, Serialize)]
+pub struct FeeTierStatus {
+    pub exchange: Exchange,
+    /// Traded volume (quote/USD) over the trailing 30 days
+    pub volume_30d_usd: Decimal,
+    pub current_tier: FeeTier,
+    /// The next rung up, if any
+    pub next_tier: Option<FeeTier>,
+    /// Projected date the next tier is reached at the current run rate
+    pub projected_tier_change: Option<DateTime<Utc>>,
+}
+
+/// Map the trade ledger onto each venue's fee-tier schedule.
+///
+/// Volume counts both legs: the buy notional on the buy exchange and the
+/// sell notional on the sell exchange. The projection extrapolates the
+/// run rate observed inside the window; it is None once the top tier is
+/// reached or while there is no volume to extrapolate from.
+pub fn project_fee_tiers(trades: &[TradeResult], now: DateTime<Utc>) -> Vec<FeeTierStatus> {
+    let window_start = now - Duration::days(VOLUME_WINDOW_DAYS);
+
+    [Exchange::Bybit, Exchange::Bitget]
+        .iter()
+        .map(|&exchange| {
+            let mut volume = Decimal::ZERO;
+            let mut earliest: Option<DateTime<Utc>> = None;
+
+            for trade in trades {
+                if trade.executed_at < window_start {
+                    continue;
+                }
+                let mut notional = Decimal::ZERO;
+                if trade.buy_exchange == exchange {
+                    notional += trade.quantity * trade.buy_price;
+                }
+                if trade.sell_exchange == exchange {
+                    notional += trade.quantity * trade.sell_price;
+                }
+                if notional > Decimal::ZERO {
+                    volume += notional;
+                    earliest = Some(match earliest {
+                        Some(e) if e < trade.executed_at => e,
+                        _ => trade.executed_at,
+                    });
+                }
+            }
+
+            let schedule = fee_schedule(exchange);
+            let current_tier = schedule
+                .iter()
+                .rev()
+                .find(|t| volume >= t.min_volume_usd)
+                .cloned()
+                .unwrap_or_else(|| schedule[0].clone());
+            let next_tier = schedule
+                .iter()
+                .find(|t| t.min_volume_usd > volume)
+                .cloned();
+
+            // Run rate over the observed part of the window, at least one day
+            // so a single fresh trade doesn't project absurd growth
+            let projected_tier_change = next_tier.as_ref().and_then(|next| {
+                let observed_ms = earliest
+                    .map(|e| (now - e).num_milliseconds())
+                    .unwrap_or(0)
+                    .max(86_400_000);
+                let daily_rate = volume / Decimal::from(observed_ms) * dec!(86400000);
+                if daily_rate <= Decimal::ZERO {
+                    return None;
+                }
+                let days_needed = (next.min_volume_usd - volume) / daily_rate;
+                let secs: i64 = (days_needed * dec!(86400)).try_into().ok()?;
+                Some(now + Duration::seconds(secs))
+            });
+
+            FeeTierStatus {
+                exchange,
+                volume_30d_usd: volume,
+                current_tier,
+                next_tier,
+                projected_tier_change,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,363 @@
+use chrono::Utc;
+use dashmap::DashMap;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tracing::warn;
+
+use crate::config::Config;
+use crate::prices::PriceCache;
+use crate::reference::ReferencePriceCache;
+use crate::types::ArbitrageOpportunity;
+
+/// Quote currencies close enough to USD to compare against a USD reference
+const USD_QUOTES: &[&str] = &["USD", "USDT", "USDC"];
+
+/// One stage of the opportunity filter pipeline. Filters are registered by
+/// name via `filters.chain` in config, so stages can be enabled, disabled
+/// and reordered without touching the detector.
+pub trait OpportunityFilter: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// Whether the opportunity should continue down the pipeline
+    fn passes(&self, opp: &ArbitrageOpportunity, prices: &PriceCache) -> bool;
+}
+
+/// Pass/reject totals for one pipeline stage, for the API
+#[derive(Debug, Clone, Serialize)]
+pub struct FilterStats {
+    pub name: &'static str,
+    pub passed: u64,
+    pub rejected: u64,
+}
+
+#[derive(Default)]
+struct FilterCounters {
+    passed: AtomicU64,
+    rejected: AtomicU64,
+}
+
+/// The ordered filter pipeline every finalized opportunity runs through
+/// before it is forwarded, with per-stage pass/reject counts so the usual
+/// rejection reason can be monitored
+pub struct FilterChain {
+    filters: Vec<Arc<dyn OpportunityFilter>>,
+    counters: Vec<FilterCounters>,
+}
+
+impl FilterChain {
+    /// Build the filters named in `filters.chain`, in order, warning on
+    /// unknown names. `spread_history` is the detector's rolling per-pair
+    /// spread distribution, feeding the adaptive threshold mode;
+    /// `reference` is the external index feed for the sanity check.
+    pub fn from_config(
+        config: &Config,
+        config_rx: tokio::sync::watch::Receiver<Config>,
+        spread_history: Arc<DashMap<String, VecDeque<f64>>>,
+        reference: Arc<ReferencePriceCache>,
+    ) -> Self {
+        let mut filters: Vec<Arc<dyn OpportunityFilter>> = Vec::new();
+        for name in &config.filters.chain {
+            match name.as_str() {
+                "staleness" => filters.push(Arc::new(StalenessFilter {
+                    max_age_ms: config.filters.max_ticker_age_ms,
+                })),
+                "reference_price" => filters.push(Arc::new(ReferencePriceFilter {
+                    max_deviation_pct: config.reference.max_deviation_pct,
+                    reference: reference.clone(),
+                })),
+                "volatility" => filters.push(Arc::new(VolatilityFilter {
+                    max_volatility_pct: config.filters.max_volatility_pct,
+                })),
+                "confidence" => filters.push(Arc::new(ConfidenceFilter {
+                    min_confidence: config.filters.min_confidence,
+                })),
+                "blacklist" => filters.push(Arc::new(BlacklistFilter {
+                    pairs: config.filters.blacklist.clone(),
+                })),
+                "min_spread" => filters.push(Arc::new(MinSpreadFilter {
+                    config_rx: config_rx.clone(),
+                    spread_history: spread_history.clone(),
+                })),
+                "persistence" => filters.push(Arc::new(PersistenceFilter {
+                    window_ms: config.filters.persistence_ms,
+                    seen: DashMap::new(),
+                })),
+                "min_profit" => filters.push(Arc::new(MinProfitFilter {
+                    min_profit: config.filters.min_profit,
+                })),
+                "dedupe" => filters.push(Arc::new(DedupeFilter {
+                    interval_ms: config.filters.dedupe_interval_ms,
+                    spread_delta_pct: config.filters.dedupe_spread_delta_pct,
+                    last_emitted: DashMap::new(),
+                })),
+                other => warn!("Unknown filter '{}' in filters.chain — skipping", other),
+            }
+        }
+        let counters = filters.iter().map(|_| FilterCounters::default()).collect();
+        Self { filters, counters }
+    }
+
+    /// Run the pipeline in order. Returns the name of the first filter
+    /// that rejected, or None when every stage passed.
+    pub fn check(&self, opp: &ArbitrageOpportunity, prices: &PriceCache) -> Option<&'static str> {
+        for (filter, counters) in self.filters.iter().zip(&self.counters) {
+            if filter.passes(opp, prices) {
+                counters.passed.fetch_add(1, Ordering::Relaxed);
+            } else {
+                counters.rejected.fetch_add(1, Ordering::Relaxed);
+                return Some(filter.name());
+            }
+        }
+        None
+    }
+
+    /// Per-stage pass/reject totals, in pipeline order
+    pub fn stats(&self) -> Vec<FilterStats> {
+        self.filters
+            .iter()
+            .zip(&self.counters)
+            .map(|(filter, counters)| FilterStats {
+                name: filter.name(),
+                passed: counters.passed.load(Ordering::Relaxed),
+                rejected: counters.rejected.load(Ordering::Relaxed),
+            })
+            .collect()
+    }
+}
+
+/// Rejects opportunities priced off tickers older than `max_ticker_age_ms`
+/// on either leg
+struct StalenessFilter {
+    max_age_ms: i64,
+}
+
+impl OpportunityFilter for StalenessFilter {
+    fn name(&self) -> &'static str {
+        "staleness"
+    }
+
+    fn passes(&self, opp: &ArbitrageOpportunity, prices: &PriceCache) -> bool {
+        let now = Utc::now();
+        let pair_str = opp.pair.to_string();
+        [opp.buy_exchange, opp.sell_exchange].iter().all(|exchange| {
+            prices
+                .get(*exchange, &pair_str)
+                .map(|t| (now - t.timestamp).num_milliseconds() <= self.max_age_ms)
+                .unwrap_or(false)
+        })
+    }
+}
+
+/// Rejects opportunities whose leg prices deviate too far from an
+/// external reference feed — an absurd "spread" from a mis-parsed
+/// exchange field passes every internal consistency check, so only an
+/// outside index catches it. Passes when the reference feed is disabled,
+/// stale, doesn't cover the asset, or the pair isn't USD-quoted.
+struct ReferencePriceFilter {
+    max_deviation_pct: Decimal,
+    reference: Arc<ReferencePriceCache>,
+}
+
+impl OpportunityFilter for ReferencePriceFilter {
+    fn name(&self) -> &'static str {
+        "reference_price"
+    }
+
+    fn passes(&self, opp: &ArbitrageOpportunity, _prices: &PriceCache) -> bool {
+        if !USD_QUOTES.contains(&opp.pair.quote.as_str()) {
+            return true;
+        }
+        [opp.buy_price, opp.sell_price].iter().all(|price| {
+            self.reference
+                .deviation_pct(&opp.pair.base, *price)
+                .map(|deviation| deviation <= self.max_deviation_pct)
+                .unwrap_or(true)
+        })
+    }
+}
+
+/// Rejects when either leg's recent realized volatility (from the
+/// opportunity context) exceeds the cap — wide spreads during violent
+/// moves are usually gone before orders land
+struct VolatilityFilter {
+    max_volatility_pct: f64,
+}
+
+impl OpportunityFilter for VolatilityFilter {
+    fn name(&self) -> &'static str {
+        "volatility"
+    }
+
+    fn passes(&self, opp: &ArbitrageOpportunity, _prices: &PriceCache) -> bool {
+        ["buy_volatility_pct", "sell_volatility_pct"].iter().all(|key| {
+            opp.context
+                .get(*key)
+                .and_then(|v| v.as_f64())
+                .map(|vol| vol <= self.max_volatility_pct)
+                .unwrap_or(true)
+        })
+    }
+}
+
+/// Rejects opportunities below the confidence floor (e.g. those priced
+/// off degraded REST polling)
+struct ConfidenceFilter {
+    min_confidence: Decimal,
+}
+
+impl OpportunityFilter for ConfidenceFilter {
+    fn name(&self) -> &'static str {
+        "confidence"
+    }
+
+    fn passes(&self, opp: &ArbitrageOpportunity, _prices: &PriceCache) -> bool {
+        opp.confidence >= self.min_confidence
+    }
+}
+
+/// Rejects pairs listed in `filters.blacklist`
+struct BlacklistFilter {
+    pairs: Vec<String>,
+}
+
+impl OpportunityFilter for BlacklistFilter {
+    fn name(&self) -> &'static str {
+        "blacklist"
+    }
+
+    fn passes(&self, opp: &ArbitrageOpportunity, _prices: &PriceCache) -> bool {
+        let pair = opp.pair.to_string();
+        !self.pairs.iter().any(|p| p.eq_ignore_ascii_case(&pair))
+    }
+}
+
+/// The minimum spread threshold, formerly hardcoded in the strategies'
+/// spread evaluation. Static per-pair thresholds by default; with
+/// `adaptive_threshold.enabled` the pair's gross spread must instead
+/// exceed mean + k·stddev of its rolling distribution.
+struct MinSpreadFilter {
+    /// Live configuration, so threshold changes apply without restart
+    config_rx: tokio::sync::watch::Receiver<Config>,
+    /// Rolling gross-spread samples per pair, fed by the detector
+    spread_history: Arc<DashMap<String, VecDeque<f64>>>,
+}
+
+impl OpportunityFilter for MinSpreadFilter {
+    fn name(&self) -> &'static str {
+        "min_spread"
+    }
+
+    fn passes(&self, opp: &ArbitrageOpportunity, _prices: &PriceCache) -> bool {
+        let config = self.config_rx.borrow();
+        let adaptive = &config.adaptive_threshold;
+        if adaptive.enabled {
+            let pair = opp.pair.to_string();
+            if let Some(history) = self.spread_history.get(&pair) {
+                if history.len() >= adaptive.min_samples.max(2) {
+                    let n = history.len() as f64;
+                    let mean = history.iter().sum::<f64>() / n;
+                    let var =
+                        history.iter().map(|s| (s - mean) * (s - mean)).sum::<f64>() / n;
+                    let k = adaptive.pair_k.get(&pair).copied().unwrap_or(adaptive.k);
+                    let threshold = mean + k * var.sqrt();
+                    if let Some(spread) = opp.spread_pct.to_f64() {
+                        return spread > threshold;
+                    }
+                }
+            }
+        }
+        opp.net_spread_pct > config.min_spread_pct_for(&opp.pair)
+    }
+}
+
+/// Rejects a (pair, direction) until its spread has persisted for
+/// `persistence_ms` — one-tick blips are usually stale quotes, not real
+/// opportunities. Disabled when the window is zero.
+struct PersistenceFilter {
+    window_ms: i64,
+    /// (first seen, last seen) ms timestamps per (pair, direction)
+    seen: DashMap<String, (i64, i64)>,
+}
+
+impl OpportunityFilter for PersistenceFilter {
+    fn name(&self) -> &'static str {
+        "persistence"
+    }
+
+    fn passes(&self, opp: &ArbitrageOpportunity, _prices: &PriceCache) -> bool {
+        if self.window_ms <= 0 {
+            return true;
+        }
+        let now = Utc::now().timestamp_millis();
+        let key = format!("{}|{}->{}", opp.pair, opp.buy_exchange, opp.sell_exchange);
+        let mut entry = self.seen.entry(key).or_insert((now, now));
+        let (first_seen, last_seen) = *entry;
+
+        // A gap longer than the window means the spread closed and
+        // reopened — start the clock again
+        if now - last_seen > self.window_ms {
+            *entry = (now, now);
+            return false;
+        }
+        entry.1 = now;
+        now - first_seen >= self.window_ms
+    }
+}
+
+/// Rejects opportunities whose estimated profit (in the reporting
+/// currency when available) is below the floor
+struct MinProfitFilter {
+    min_profit: Decimal,
+}
+
+impl OpportunityFilter for MinProfitFilter {
+    fn name(&self) -> &'static str {
+        "min_profit"
+    }
+
+    fn passes(&self, opp: &ArbitrageOpportunity, _prices: &PriceCache) -> bool {
+        opp.potential_profit_reporting.unwrap_or(opp.potential_profit) >= self.min_profit
+    }
+}
+
+/// Suppresses repeats of a (pair, direction) that the detector would
+/// otherwise re-emit on every tick of a persisting spread, flooding the
+/// channel, the UI and the executor. A repeat goes through only when the
+/// spread has moved materially or the debounce interval has elapsed.
+struct DedupeFilter {
+    interval_ms: i64,
+    spread_delta_pct: Decimal,
+    /// (last emit ms, net spread at last emit) per (pair, direction)
+    last_emitted: DashMap<String, (i64, Decimal)>,
+}
+
+impl OpportunityFilter for DedupeFilter {
+    fn name(&self) -> &'static str {
+        "dedupe"
+    }
+
+    fn passes(&self, opp: &ArbitrageOpportunity, _prices: &PriceCache) -> bool {
+        let now = Utc::now().timestamp_millis();
+        let key = format!("{}|{}->{}", opp.pair, opp.buy_exchange, opp.sell_exchange);
+        let mut entry = match self.last_emitted.entry(key) {
+            dashmap::mapref::entry::Entry::Vacant(vacant) => {
+                vacant.insert((now, opp.net_spread_pct));
+                return true;
+            }
+            dashmap::mapref::entry::Entry::Occupied(occupied) => occupied.into_ref(),
+        };
+
+        let (last_ms, last_spread) = *entry;
+        if (opp.net_spread_pct - last_spread).abs() >= self.spread_delta_pct
+            || now - last_ms >= self.interval_ms
+        {
+            *entry = (now, opp.net_spread_pct);
+            return true;
+        }
+        false
+    }
+}
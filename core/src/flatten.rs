@@ -0,0 +1,205 @@
+use chrono::{Timelike, Utc};
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+use crate::config::Config;
+use crate::exchange::ExchangeConnector;
+use crate::executor::OrderExecutor;
+use crate::prices::PriceCache;
+use crate::types::{OrderSide, OrderType, TradingPair};
+
+/// Residual inventory below this notional (quote currency) is treated as
+/// dust and left alone
+const DUST_NOTIONAL_USD: Decimal = dec!(1);
+
+/// Enforces the end-of-day flat-position policy: once per day at the
+/// configured UTC time, unwinds any residual base-asset inventory back to
+/// target allocations with market orders, subject to a max-cost cap.
+pub struct FlatPositionEnforcer {
+    connectors: Vec<Arc<dyn ExchangeConnector>>,
+    prices: Arc<PriceCache>,
+    config: Config,
+    /// Shares the executor's trading gates (kill switch, pause, standby)
+    /// and live simulation-mode flag
+    executor: Arc<OrderExecutor>,
+}
+
+impl FlatPositionEnforcer {
+    pub fn new(
+        connectors: Vec<Arc<dyn ExchangeConnector>>,
+        prices: Arc<PriceCache>,
+        config: Config,
+        executor: Arc<OrderExecutor>,
+    ) -> Self {
+        Self {
+            connectors,
+            prices,
+            config,
+            executor,
+        }
+    }
+
+    /// Run the daily schedule. Checks the clock every 30 seconds and
+    /// flattens at most once per UTC day.
+    pub async fn start(&self) {
+        if !self.config.flatten.enabled {
+            return;
+        }
+
+        let (hour, minute) = match parse_time_utc(&self.config.flatten.time_utc) {
+            Some(hm) => hm,
+            None => {
+                error!(
+                    "Invalid flatten.time_utc '{}', expected HH:MM — flat-position policy disabled",
+                    self.config.flatten.time_utc
+                );
+                return;
+            }
+        };
+
+        info!(
+            "Flat-position policy armed: flattening daily at {:02}:{:02} UTC (max cost ${})",
+            hour, minute, self.config.flatten.max_cost_usd
+        );
+
+        let mut last_run_date = None;
+        loop {
+            let now = Utc::now();
+            let due = now.hour() == hour && now.minute() >= minute;
+            if due && last_run_date != Some(now.date_naive()) {
+                last_run_date = Some(now.date_naive());
+                self.flatten_all().await;
+            }
+            tokio::time::sleep(Duration::from_secs(30)).await;
+        }
+    }
+
+    /// Unwind residual inventory on every exchange back to targets
+    async fn flatten_all(&self) {
+        // Honor the same operator controls the executor does — a kill
+        // switch must stop every order path, not just arbitrage
+        if let Some(reason) = self.executor.trading_halt_reason() {
+            warn!("End-of-day flatten skipped: {}", reason);
+            return;
+        }
+        let simulation = self.executor.is_simulation();
+        info!("End-of-day flatten starting");
+        let mut total_cost = Decimal::ZERO;
+
+        for connector in &self.connectors {
+            let exchange = connector.exchange();
+            let balances = match connector.get_balances().await {
+                Ok(b) => b,
+                Err(e) => {
+                    error!("Flatten: failed to fetch {} balances: {}", exchange, e);
+                    continue;
+                }
+            };
+
+            for balance in balances {
+                let Some(pair) = self.pair_for_base(&balance.asset) else {
+                    continue; // quote currencies and assets we don't trade
+                };
+
+                let target = self
+                    .config
+                    .flatten
+                    .targets
+                    .get(&balance.asset)
+                    .copied()
+                    .unwrap_or(Decimal::ZERO);
+                let residual = balance.total - target;
+
+                let Some(ticker) = self.prices.get(exchange, &pair.to_string()) else {
+                    warn!(
+                        "Flatten: no price for {} on {}, skipping {} residual",
+                        pair, exchange, residual
+                    );
+                    continue;
+                };
+
+                let notional = residual.abs() * ticker.mid_price();
+                if notional < DUST_NOTIONAL_USD {
+                    continue;
+                }
+
+                // Estimated unwind cost: taker fee plus half the spread
+                let fee = connector.fee_pct();
+                let cost = notional * (fee / dec!(100)) + residual.abs() * ticker.spread() / dec!(2);
+                if total_cost + cost > self.config.flatten.max_cost_usd {
+                    warn!(
+                        "Flatten: unwinding {} {} on {} would cost ~${} (total ${}), over the ${} cap — leaving position open",
+                        residual, balance.asset, exchange,
+                        cost.round_dp(2), (total_cost + cost).round_dp(2),
+                        self.config.flatten.max_cost_usd
+                    );
+                    continue;
+                }
+
+                let side = if residual > Decimal::ZERO {
+                    OrderSide::Sell
+                } else {
+                    OrderSide::Buy
+                };
+
+                // Paper deployments report what would have been unwound
+                // without touching real venue balances
+                if simulation {
+                    total_cost += cost;
+                    info!(
+                        "Flatten (simulation): would {:?} {} {} on {} — est. cost ${}",
+                        side, residual.abs(), balance.asset, exchange,
+                        cost.round_dp(2)
+                    );
+                    continue;
+                }
+
+                match connector
+                    .place_order(&pair, side, OrderType::Market, residual.abs(), None)
+                    .await
+                {
+                    Ok(order) => {
+                        total_cost += cost;
+                        info!(
+                            "Flatten: {:?} {} {} on {} (order {}) — est. cost ${}",
+                            side, residual.abs(), balance.asset, exchange,
+                            order.id, cost.round_dp(2)
+                        );
+                    }
+                    Err(e) => {
+                        error!(
+                            "Flatten: failed to {:?} {} {} on {}: {}",
+                            side, residual.abs(), balance.asset, exchange, e
+                        );
+                    }
+                }
+            }
+        }
+
+        info!(
+            "End-of-day flatten complete — estimated cost ${}",
+            total_cost.round_dp(2)
+        );
+    }
+
+    /// Find the configured spot pair whose base matches this asset
+    fn pair_for_base(&self, asset: &str) -> Option<TradingPair> {
+        self.config.trading.pairs.iter().find_map(|p| {
+            TradingPair::parse(p).filter(|pair| {
+                pair.market == crate::types::MarketType::Spot
+                    && pair.base.eq_ignore_ascii_case(asset)
+            })
+        })
+    }
+}
+
+/// Parse "HH:MM" into (hour, minute)
+fn parse_time_utc(s: &str) -> Option<(u32, u32)> {
+    let (h, m) = s.split_once(':')?;
+    let hour: u32 = h.parse().ok()?;
+    let minute: u32 = m.parse().ok()?;
+    (hour < 24 && minute < 60).then_some((hour, minute))
+}
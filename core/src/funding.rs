@@ -0,0 +1,253 @@
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, error, info};
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::exchange::ExchangeConnector;
+use crate::executor::OrderExecutor;
+use crate::prices::PriceCache;
+use crate::types::{OrderSide, OrderType, TradingPair};
+
+/// A spot-buy / perp-short funding opportunity on one venue
+#[derive(Debug, Clone, Serialize)]
+pub struct FundingOpportunity {
+    pub id: String,
+    pub exchange: crate::types::Exchange,
+    pub pair: TradingPair,
+    pub spot_price: Decimal,
+    pub perp_price: Decimal,
+    /// Funding rate per interval, percent (shorts receive when positive)
+    pub funding_rate_pct: Decimal,
+    pub interval_hours: u32,
+    /// Funding yield annualized, percent
+    pub annualized_yield_pct: Decimal,
+    /// Taker fees to open both legs, percent of notional
+    pub entry_fees_pct: Decimal,
+    pub quantity: Decimal,
+    pub detected_at: DateTime<Utc>,
+    pub is_actionable: bool,
+}
+
+/// Polls perpetual funding rates and surfaces spot-buy / perp-short
+/// opportunities where the funding yield exceeds fees. Each venue is
+/// evaluated on its own — both legs open on the same exchange, so there is
+/// no cross-venue settlement risk.
+pub struct FundingArbMonitor {
+    connectors: Vec<Arc<dyn ExchangeConnector>>,
+    prices: Arc<PriceCache>,
+    config: Config,
+    /// Shares the executor's trading gates (kill switch, pause, standby)
+    /// and live simulation-mode flag
+    executor: Arc<OrderExecutor>,
+    /// Latest evaluation per (exchange, pair), for the API
+    latest: DashMap<String, FundingOpportunity>,
+}
+
+impl FundingArbMonitor {
+    pub fn new(
+        connectors: Vec<Arc<dyn ExchangeConnector>>,
+        prices: Arc<PriceCache>,
+        config: Config,
+        executor: Arc<OrderExecutor>,
+    ) -> Self {
+        Self {
+            connectors,
+            prices,
+            config,
+            executor,
+            latest: DashMap::new(),
+        }
+    }
+
+    /// Poll loop (no-op unless `funding.enabled`)
+    pub async fn start(&self) {
+        if !self.config.funding.enabled {
+            return;
+        }
+        let poll_secs = self.config.funding.poll_secs.max(10);
+        info!(
+            "Funding-rate monitor started (poll every {}s, min annualized yield {}%)",
+            poll_secs, self.config.funding.min_annualized_yield_pct
+        );
+
+        let pairs: Vec<TradingPair> = self
+            .config
+            .trading
+            .pairs
+            .iter()
+            .filter_map(|p| TradingPair::parse(p))
+            .filter(|p| p.market == crate::types::MarketType::Spot)
+            .collect();
+
+        loop {
+            for pair in &pairs {
+                for connector in &self.connectors {
+                    self.evaluate(connector, pair).await;
+                }
+            }
+            tokio::time::sleep(Duration::from_secs(poll_secs)).await;
+        }
+    }
+
+    async fn evaluate(&self, connector: &Arc<dyn ExchangeConnector>, spot_pair: &TradingPair) {
+        let exchange = connector.exchange();
+        let perp_pair = TradingPair::perp(&spot_pair.base, &spot_pair.quote);
+
+        let funding = match connector.get_funding_rate(&perp_pair).await {
+            Ok(f) => f,
+            Err(e) => {
+                debug!("No funding rate for {} on {}: {}", perp_pair, exchange, e);
+                return;
+            }
+        };
+
+        let Some(spot) = self.prices.get(exchange, &spot_pair.to_string()) else {
+            return;
+        };
+        let perp = match connector.get_ticker(&perp_pair).await {
+            Ok(t) => t,
+            Err(e) => {
+                debug!("No perp ticker for {} on {}: {}", perp_pair, exchange, e);
+                return;
+            }
+        };
+
+        let funding_rate_pct = funding.rate * dec!(100);
+        let intervals_per_year = Decimal::from(24 * 365 / funding.interval_hours.max(1));
+        let annualized_yield_pct = funding_rate_pct * intervals_per_year;
+        // Spot taker fee to buy plus perp taker fee to short
+        let entry_fees_pct = connector.fee_pct() * dec!(2);
+
+        // Shorts receive funding only when the rate is positive, and the
+        // position must out-earn its entry cost within a day to be worth
+        // the balance-sheet usage
+        let daily_yield_pct = funding_rate_pct * Decimal::from(24 / funding.interval_hours.max(1));
+        let is_actionable = funding.rate > Decimal::ZERO
+            && annualized_yield_pct >= self.config.funding.min_annualized_yield_pct
+            && daily_yield_pct > entry_fees_pct;
+
+        let opportunity = FundingOpportunity {
+            id: Uuid::new_v4().to_string(),
+            exchange,
+            pair: spot_pair.clone(),
+            spot_price: spot.ask,
+            perp_price: perp.bid,
+            funding_rate_pct,
+            interval_hours: funding.interval_hours,
+            annualized_yield_pct,
+            entry_fees_pct,
+            quantity: self.config.trading.max_trade_qty,
+            detected_at: Utc::now(),
+            is_actionable,
+        };
+
+        if is_actionable {
+            info!(
+                "Funding opportunity on {}: {} at {}%/interval ({}% annualized)",
+                exchange,
+                spot_pair,
+                funding_rate_pct.round_dp(4),
+                annualized_yield_pct.round_dp(2)
+            );
+            if self.config.funding.auto_execute && !self.executor.is_simulation() {
+                // Same operator controls as the executor — kill, pause and
+                // standby stop funding-arb order flow too
+                if let Some(reason) = self.executor.trading_halt_reason() {
+                    debug!("Funding auto-execute blocked: {}", reason);
+                } else {
+                    self.open_position(connector, &opportunity, &perp_pair).await;
+                }
+            }
+        }
+
+        self.latest
+            .insert(format!("{}:{}", exchange, spot_pair), opportunity);
+    }
+
+    /// Open both legs: buy spot, short the perp for the same quantity
+    async fn open_position(
+        &self,
+        connector: &Arc<dyn ExchangeConnector>,
+        opp: &FundingOpportunity,
+        perp_pair: &TradingPair,
+    ) {
+        let spot_pair = &opp.pair;
+        let qty = opp.quantity;
+
+        let (spot_leg, perp_leg) = tokio::join!(
+            connector.place_order(spot_pair, OrderSide::Buy, OrderType::Market, qty, None),
+            connector.place_order(perp_pair, OrderSide::Sell, OrderType::Market, qty, None),
+        );
+
+        match (spot_leg, perp_leg) {
+            (Ok(spot_order), Ok(perp_order)) => info!(
+                "Funding position opened on {}: spot {} / perp {}",
+                opp.exchange, spot_order.id, perp_order.id
+            ),
+            // One leg filled and the other did not: a naked position, not
+            // a hedge — reverse the filled leg at market immediately
+            (Ok(spot_order), Err(e)) => {
+                error!(
+                    "Funding position on {}: perp leg failed ({}) — unwinding spot buy {}",
+                    opp.exchange, e, spot_order.id
+                );
+                self.unwind_leg(connector, spot_pair, OrderSide::Sell, qty)
+                    .await;
+            }
+            (Err(e), Ok(perp_order)) => {
+                error!(
+                    "Funding position on {}: spot leg failed ({}) — unwinding perp short {}",
+                    opp.exchange, e, perp_order.id
+                );
+                self.unwind_leg(connector, perp_pair, OrderSide::Buy, qty)
+                    .await;
+            }
+            (Err(spot_err), Err(perp_err)) => error!(
+                "Funding position on {} failed on both legs: spot={} perp={}",
+                opp.exchange, spot_err, perp_err
+            ),
+        }
+    }
+
+    /// Reverse a filled leg at market after the other leg failed
+    async fn unwind_leg(
+        &self,
+        connector: &Arc<dyn ExchangeConnector>,
+        pair: &TradingPair,
+        side: OrderSide,
+        qty: Decimal,
+    ) {
+        match connector
+            .place_order(pair, side, OrderType::Market, qty, None)
+            .await
+        {
+            Ok(order) => info!(
+                "Funding unwind: {:?} {} {} on {} (order {})",
+                side,
+                qty,
+                pair,
+                connector.exchange(),
+                order.id
+            ),
+            Err(e) => error!(
+                "Funding unwind FAILED on {}: {:?} {} {}: {} — naked position needs manual intervention",
+                connector.exchange(),
+                side,
+                qty,
+                pair,
+                e
+            ),
+        }
+    }
+
+    /// Latest evaluation per (exchange, pair), for GET /api/funding
+    pub fn snapshot(&self) -> Vec<FundingOpportunity> {
+        self.latest.iter().map(|e| e.value().clone()).collect()
+    }
+}
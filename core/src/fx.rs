@@ -0,0 +1,304 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::config::FxConfig;
+use crate::prices::PriceCache;
+use crate::types::Exchange;
+
+/// Currencies that normalize to USD 1:1 without a provider lookup
+const USD_EQUIVALENTS: &[&str] = &["USD", "USDT", "USDC"];
+
+/// Source of fiat FX rates for quote normalization. Implementations return
+/// USD per one unit of each currency.
+#[async_trait]
+pub trait FxRateProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+    async fn fetch_rates(&self) -> Result<HashMap<String, Decimal>, String>;
+}
+
+/// Fixed rates straight from config — no network, never refreshes
+pub struct StaticFxProvider {
+    rates: HashMap<String, Decimal>,
+}
+
+#[async_trait]
+impl FxRateProvider for StaticFxProvider {
+    fn name(&self) -> &'static str {
+        "static"
+    }
+
+    async fn fetch_rates(&self) -> Result<HashMap<String, Decimal>, String> {
+        Ok(self.rates.clone())
+    }
+}
+
+/// ECB daily reference rates (EUR base, converted to USD terms)
+pub struct EcbProvider {
+    client: reqwest::Client,
+}
+
+#[async_trait]
+impl FxRateProvider for EcbProvider {
+    fn name(&self) -> &'static str {
+        "ecb"
+    }
+
+    async fn fetch_rates(&self) -> Result<HashMap<String, Decimal>, String> {
+        let xml = self
+            .client
+            .get("https://www.ecb.europa.eu/stats/eurofxref/eurofxref-daily.xml")
+            .send()
+            .await
+            .map_err(|e| e.to_string())?
+            .text()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        // The feed is one <Cube currency='X' rate='Y'/> element per line —
+        // lightweight attribute scanning beats pulling in an XML crate
+        let mut per_eur: HashMap<String, Decimal> = HashMap::new();
+        for line in xml.lines() {
+            let Some(currency) = attr_value(line, "currency") else {
+                continue;
+            };
+            let Some(rate) = attr_value(line, "rate").and_then(|r| r.parse::<Decimal>().ok())
+            else {
+                continue;
+            };
+            if rate > Decimal::ZERO {
+                per_eur.insert(currency.to_string(), rate);
+            }
+        }
+
+        // ECB quotes units-per-EUR; re-base everything to USD terms
+        let usd_per_eur = *per_eur
+            .get("USD")
+            .ok_or("ECB feed missing USD reference rate")?;
+        let mut rates: HashMap<String, Decimal> = per_eur
+            .iter()
+            .map(|(ccy, per_eur_rate)| (ccy.clone(), usd_per_eur / per_eur_rate))
+            .collect();
+        rates.insert("EUR".to_string(), usd_per_eur);
+        Ok(rates)
+    }
+}
+
+/// openexchangerates.org latest rates (USD base, requires an app id)
+pub struct OpenExchangeRatesProvider {
+    client: reqwest::Client,
+    app_id: String,
+}
+
+#[async_trait]
+impl FxRateProvider for OpenExchangeRatesProvider {
+    fn name(&self) -> &'static str {
+        "openexchangerates"
+    }
+
+    async fn fetch_rates(&self) -> Result<HashMap<String, Decimal>, String> {
+        let url = format!(
+            "https://openexchangerates.org/api/latest.json?app_id={}",
+            self.app_id
+        );
+        let data: serde_json::Value = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?
+            .json()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut rates = HashMap::new();
+        if let Some(obj) = data["rates"].as_object() {
+            for (ccy, rate) in obj {
+                // Feed is units-per-USD; invert to USD-per-unit
+                if let Some(per_usd) = rate
+                    .as_f64()
+                    .and_then(Decimal::from_f64_retain)
+                    .filter(|r| *r > Decimal::ZERO)
+                {
+                    rates.insert(ccy.clone(), Decimal::ONE / per_usd);
+                }
+            }
+        }
+        if rates.is_empty() {
+            return Err(format!("openexchangerates returned no rates: {}", data));
+        }
+        Ok(rates)
+    }
+}
+
+/// One cached rate as exposed via GET /api/fx/rates
+#[derive(Debug, Clone, Serialize)]
+pub struct FxRateInfo {
+    pub currency: String,
+    /// USD per one unit of the currency
+    pub usd_rate: Decimal,
+    pub as_of: Option<DateTime<Utc>>,
+    pub stale: bool,
+}
+
+/// Staleness-checked FX rate cache used for non-USD quote normalization.
+///
+/// Rates older than `max_age_secs` are refused rather than silently served,
+/// so fiat cross-venue comparisons never run on a dead feed.
+pub struct FxRateCache {
+    provider: Arc<dyn FxRateProvider>,
+    rates: DashMap<String, Decimal>,
+    /// Millisecond timestamp of the last successful refresh (0 = never)
+    last_refresh_ms: AtomicI64,
+    refresh_secs: u64,
+    max_age_secs: u64,
+}
+
+impl FxRateCache {
+    /// Build the provider named in config ("static", "ecb",
+    /// "openexchangerates") and wrap it in a cache
+    pub fn from_config(config: &FxConfig) -> Self {
+        let provider: Arc<dyn FxRateProvider> = match config.provider.as_str() {
+            "ecb" => Arc::new(EcbProvider {
+                client: reqwest::Client::new(),
+            }),
+            "openexchangerates" => Arc::new(OpenExchangeRatesProvider {
+                client: reqwest::Client::new(),
+                app_id: config.app_id.clone(),
+            }),
+            _ => Arc::new(StaticFxProvider {
+                rates: config.rates.clone(),
+            }),
+        };
+
+        Self {
+            provider,
+            rates: DashMap::new(),
+            last_refresh_ms: AtomicI64::new(0),
+            refresh_secs: config.refresh_secs.max(60),
+            max_age_secs: config.max_age_secs,
+        }
+    }
+
+    /// Refresh loop — fetches immediately, then every `refresh_secs`
+    pub async fn start(&self) {
+        info!(
+            "FX rate cache started (provider={}, refresh every {}s)",
+            self.provider.name(),
+            self.refresh_secs
+        );
+        loop {
+            match self.provider.fetch_rates().await {
+                Ok(fresh) => {
+                    for (ccy, rate) in fresh {
+                        self.rates.insert(ccy.to_uppercase(), rate);
+                    }
+                    self.last_refresh_ms
+                        .store(Utc::now().timestamp_millis(), Ordering::Relaxed);
+                }
+                Err(e) => warn!("FX rate refresh failed ({}): {}", self.provider.name(), e),
+            }
+            tokio::time::sleep(Duration::from_secs(self.refresh_secs)).await;
+        }
+    }
+
+    fn is_stale(&self) -> bool {
+        let last = self.last_refresh_ms.load(Ordering::Relaxed);
+        last == 0 || Utc::now().timestamp_millis() - last > (self.max_age_secs * 1000) as i64
+    }
+
+    /// USD per one unit of `currency`, or None if unknown or stale
+    pub fn usd_rate(&self, currency: &str) -> Option<Decimal> {
+        let currency = currency.to_uppercase();
+        if USD_EQUIVALENTS.contains(&currency.as_str()) {
+            return Some(Decimal::ONE);
+        }
+        if self.is_stale() {
+            warn!(
+                "FX rates stale (last refresh over {}s ago), refusing {} conversion",
+                self.max_age_secs, currency
+            );
+            return None;
+        }
+        self.rates.get(&currency).map(|r| *r)
+    }
+
+    /// Convert an amount in `currency` to USD, or None if unknown or stale
+    pub fn to_usd(&self, amount: Decimal, currency: &str) -> Option<Decimal> {
+        self.usd_rate(currency).map(|rate| amount * rate)
+    }
+
+    /// Convert an amount between two currencies via their USD rates, or
+    /// None if either leg is unknown or stale
+    pub fn convert(&self, amount: Decimal, from: &str, to: &str) -> Option<Decimal> {
+        let to_rate = self.usd_rate(to).filter(|r| *r > Decimal::ZERO)?;
+        Some(amount * self.usd_rate(from)? / to_rate)
+    }
+
+    /// Convert an amount denominated in a pair's quote currency into the
+    /// reporting currency, preferring a live crypto cross (the
+    /// QUOTE/REPORTING ticker, or its inverse, on either venue) and falling
+    /// back to fiat FX rates. None when no conversion path is available.
+    pub fn quote_to_reporting(
+        &self,
+        prices: &PriceCache,
+        amount: Decimal,
+        quote: &str,
+        reporting: &str,
+    ) -> Option<Decimal> {
+        let quote = quote.to_uppercase();
+        let reporting = reporting.to_uppercase();
+        if quote == reporting {
+            return Some(amount);
+        }
+
+        for exchange in [Exchange::Bybit, Exchange::Bitget] {
+            if let Some(cross) = prices.get(exchange, &format!("{}/{}", quote, reporting)) {
+                return Some(amount * cross.mid_price());
+            }
+            if let Some(inverse) = prices.get(exchange, &format!("{}/{}", reporting, quote)) {
+                let mid = inverse.mid_price();
+                if mid > Decimal::ZERO {
+                    return Some(amount / mid);
+                }
+            }
+        }
+
+        self.convert(amount, &quote, &reporting)
+    }
+
+    /// Snapshot of all cached rates with staleness, for the API
+    pub fn snapshot(&self) -> Vec<FxRateInfo> {
+        let last = self.last_refresh_ms.load(Ordering::Relaxed);
+        let as_of = (last > 0).then(|| DateTime::from_timestamp_millis(last).unwrap_or_default());
+        let stale = self.is_stale();
+        self.rates
+            .iter()
+            .map(|entry| FxRateInfo {
+                currency: entry.key().clone(),
+                usd_rate: *entry.value(),
+                as_of,
+                stale,
+            })
+            .collect()
+    }
+}
+
+/// Extract `name='value'` or `name="value"` from a line of markup
+fn attr_value<'a>(line: &'a str, name: &str) -> Option<&'a str> {
+    let start = line.find(&format!("{}=", name))? + name.len() + 1;
+    let quote = line.as_bytes().get(start)?;
+    if *quote != b'\'' && *quote != b'"' {
+        return None;
+    }
+    let rest = &line[start + 1..];
+    let end = rest.find(*quote as char)?;
+    Some(&rest[..end])
+}
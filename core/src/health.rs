@@ -0,0 +1,86 @@
+use std::sync::Arc;
+use tokio::sync::watch;
+
+use crate::config::Config;
+use crate::exchange::ExchangeConnector;
+use crate::prices::PriceCache;
+use crate::types::{ConnectionState, ExchangeHealth};
+
+/// A feed silent for this many staleness windows is reported as down
+/// rather than reconnecting
+const DOWN_AFTER_WINDOWS: i64 = 3;
+
+/// Per-exchange connectivity registry, served via GET /api/exchanges and
+/// pushed to dashboards as `WsMessage::ExchangeHealth`.
+///
+/// State is derived from what the connectors already track: a venue is
+/// connected while its WS feed is within the configured staleness window,
+/// reconnecting once it goes silent (the connectors retry forever), and
+/// down when it has never connected or has been silent for several
+/// windows.
+pub struct ExchangeHealthRegistry {
+    connectors: Vec<Arc<dyn ExchangeConnector>>,
+    config_rx: watch::Receiver<Config>,
+    prices: Arc<PriceCache>,
+}
+
+impl ExchangeHealthRegistry {
+    pub fn new(
+        connectors: Vec<Arc<dyn ExchangeConnector>>,
+        config_rx: watch::Receiver<Config>,
+        prices: Arc<PriceCache>,
+    ) -> Self {
+        Self {
+            connectors,
+            config_rx,
+            prices,
+        }
+    }
+
+    /// Current connectivity snapshot across all connectors
+    pub fn snapshot(&self) -> Vec<ExchangeHealth> {
+        let config = self.config_rx.borrow().clone();
+        let now_ms = chrono::Utc::now().timestamp_millis();
+
+        self.connectors
+            .iter()
+            .map(|connector| {
+                let exchange = connector.exchange();
+                let stale_ms = config
+                    .get_exchange(&exchange)
+                    .map(|cfg| cfg.ws_stale_secs.max(5))
+                    .unwrap_or(30) as i64
+                    * 1_000;
+
+                let last_ws_ms = connector.last_ws_message_ms();
+                let state = if last_ws_ms == 0 {
+                    ConnectionState::Down
+                } else {
+                    let silent_ms = now_ms - last_ws_ms;
+                    if silent_ms <= stale_ms {
+                        ConnectionState::Connected
+                    } else if silent_ms <= stale_ms * DOWN_AFTER_WINDOWS {
+                        ConnectionState::Reconnecting
+                    } else {
+                        ConnectionState::Down
+                    }
+                };
+
+                let last_ticker = self
+                    .prices
+                    .all()
+                    .into_iter()
+                    .filter(|t| t.exchange == exchange)
+                    .map(|t| t.timestamp)
+                    .max();
+
+                ExchangeHealth {
+                    exchange,
+                    state,
+                    last_ticker,
+                    reconnects: connector.ws_reconnect_count(),
+                }
+            })
+            .collect()
+    }
+}
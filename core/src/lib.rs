@@ -1,10 +1,45 @@
+pub mod account;
 pub mod arbitrage;
+pub mod audit;
+pub mod backtest;
+pub mod candles;
 pub mod config;
+pub mod costmodel;
+pub mod download;
+pub mod eventlog;
+pub mod events;
 pub mod exchange;
+pub mod fees;
+pub mod filter;
+pub mod flatten;
+pub mod funding;
+pub mod fx;
+pub mod health;
+pub mod mirror;
+pub mod notify;
+pub mod optimize;
+pub mod orders;
+pub mod pnl;
+pub mod portfolio;
+pub mod positions;
+pub mod prices;
+pub mod rebalance;
+pub mod reconcile;
+pub mod recorder;
+pub mod replay;
+pub mod reference;
+pub mod sla;
+pub mod slack;
+pub mod spreads;
+pub mod store;
+pub mod strategy;
+pub mod telegram;
 pub mod executor;
 pub mod types;
 
+pub use account::AccountEventMonitor;
 pub use arbitrage::ArbitrageDetector;
 pub use config::Config;
 pub use executor::OrderExecutor;
+pub use prices::PriceCache;
 pub use types::*;
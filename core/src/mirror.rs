@@ -0,0 +1,131 @@
+use redis::AsyncCommands;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::config::MirrorConfig;
+use crate::prices::PriceCache;
+use crate::types::{ArbitrageOpportunity, Ticker, TradeResult};
+
+/// Recent opportunities kept in the Redis list
+const OPPORTUNITY_LIST_CAP: isize = 1_000;
+/// Recent trades kept in the Redis list
+const TRADE_LIST_CAP: isize = 10_000;
+
+/// Mirrors the live state a dashboard needs — latest prices, recent
+/// opportunities and the trade feed — into Redis, so separate read-only
+/// processes (or multiple API replicas) can serve it without living in
+/// the trading engine's process.
+///
+/// Layout under the configured prefix: `<prefix>:prices` is a hash of
+/// `exchange:pair` → ticker JSON; `<prefix>:opportunities` and
+/// `<prefix>:trades` are capped lists, newest first; each also publishes
+/// on a channel of the same name for push consumers. Writes are
+/// fire-and-forget — Redis being down must never stall the engine.
+pub struct RedisMirror {
+    config: MirrorConfig,
+    manager: Mutex<Option<redis::aio::ConnectionManager>>,
+}
+
+impl RedisMirror {
+    pub fn from_config(config: &MirrorConfig) -> Self {
+        Self {
+            config: config.clone(),
+            manager: Mutex::new(None),
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Connect and start mirroring the ticker stream; no-op unless enabled.
+    /// The connection manager reconnects by itself after outages.
+    pub async fn start(&self, prices: Arc<PriceCache>) {
+        if !self.config.enabled {
+            return;
+        }
+        let manager = match redis::Client::open(self.config.url.as_str()) {
+            Ok(client) => match redis::aio::ConnectionManager::new(client).await {
+                Ok(manager) => manager,
+                Err(e) => {
+                    warn!("Redis mirror disabled: could not connect to {}: {}", self.config.url, e);
+                    return;
+                }
+            },
+            Err(e) => {
+                warn!("Redis mirror disabled: bad URL {}: {}", self.config.url, e);
+                return;
+            }
+        };
+        *self.manager.lock().await = Some(manager);
+        info!(
+            "Redis mirror started ({}, prefix {})",
+            self.config.url, self.config.key_prefix
+        );
+
+        let mut updates = prices.subscribe();
+        use tokio::sync::broadcast::error::RecvError;
+        loop {
+            match updates.recv().await {
+                Ok(ticker) => self.record_ticker(&ticker).await,
+                Err(RecvError::Lagged(_)) => continue,
+                Err(RecvError::Closed) => break,
+            }
+        }
+    }
+
+    pub async fn record_ticker(&self, ticker: &Ticker) {
+        let Some(mut conn) = self.connection().await else {
+            return;
+        };
+        let Ok(json) = serde_json::to_string(ticker) else {
+            return;
+        };
+        let key = format!("{}:prices", self.config.key_prefix);
+        let field = format!("{}:{}", ticker.exchange, ticker.pair);
+        let result: redis::RedisResult<()> = async {
+            conn.hset::<_, _, _, ()>(&key, &field, &json).await?;
+            conn.publish::<_, _, ()>(&key, &json).await
+        }
+        .await;
+        if let Err(e) = result {
+            warn!("Redis mirror write failed: {}", e);
+        }
+    }
+
+    pub async fn record_opportunity(&self, opp: &ArbitrageOpportunity) {
+        let Ok(json) = serde_json::to_string(opp) else {
+            return;
+        };
+        self.push_list("opportunities", &json, OPPORTUNITY_LIST_CAP)
+            .await;
+    }
+
+    pub async fn record_trade(&self, trade: &TradeResult) {
+        let Ok(json) = serde_json::to_string(trade) else {
+            return;
+        };
+        self.push_list("trades", &json, TRADE_LIST_CAP).await;
+    }
+
+    async fn push_list(&self, name: &str, json: &str, cap: isize) {
+        let Some(mut conn) = self.connection().await else {
+            return;
+        };
+        let key = format!("{}:{}", self.config.key_prefix, name);
+        let result: redis::RedisResult<()> = async {
+            conn.lpush::<_, _, ()>(&key, json).await?;
+            conn.ltrim::<_, ()>(&key, 0, cap - 1).await?;
+            conn.publish::<_, _, ()>(&key, json).await
+        }
+        .await;
+        if let Err(e) = result {
+            warn!("Redis mirror write failed: {}", e);
+        }
+    }
+
+    async fn connection(&self) -> Option<redis::aio::ConnectionManager> {
+        self.manager.lock().await.clone()
+    }
+}
@@ -0,0 +1,339 @@
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+use tracing::{info, warn};
+
+use crate::config::{Config, NotifyConfig};
+use crate::prices::PriceCache;
+use crate::slack::SlackNotifier;
+use crate::telegram::TelegramBot;
+use crate::types::Exchange;
+
+/// Repeats of a deduplicated notification kind are suppressed this long
+const DEDUPE_SECS: i64 = 60;
+
+/// How often the feed watch checks each venue's ticker freshness
+const FEED_CHECK_SECS: u64 = 10;
+
+/// What a notification is about; kinds that can fire in bursts (risk
+/// rejections, feed flapping) are deduplicated per title
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    TradeExecuted,
+    /// An opportunity whose estimated profit cleared the configured bar
+    Opportunity,
+    CircuitBreaker,
+    RiskLimit,
+    ConnectorDisconnect,
+    ConnectorReconnect,
+    /// An operator changed engine parameters at runtime
+    ConfigChange,
+}
+
+/// How urgent a notification is, for sink-side filtering
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Severity::Info => "info",
+            Severity::Warning => "warning",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+impl NotificationKind {
+    /// snake_case name, matching the serialized form (template keys)
+    pub fn name(&self) -> &'static str {
+        match self {
+            NotificationKind::TradeExecuted => "trade_executed",
+            NotificationKind::Opportunity => "opportunity",
+            NotificationKind::CircuitBreaker => "circuit_breaker",
+            NotificationKind::RiskLimit => "risk_limit",
+            NotificationKind::ConnectorDisconnect => "connector_disconnect",
+            NotificationKind::ConnectorReconnect => "connector_reconnect",
+            NotificationKind::ConfigChange => "config_change",
+        }
+    }
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            NotificationKind::TradeExecuted
+            | NotificationKind::Opportunity
+            | NotificationKind::ConnectorReconnect
+            | NotificationKind::ConfigChange => Severity::Info,
+            NotificationKind::RiskLimit | NotificationKind::ConnectorDisconnect => {
+                Severity::Warning
+            }
+            NotificationKind::CircuitBreaker => Severity::Critical,
+        }
+    }
+
+    fn dedupes(&self) -> bool {
+        matches!(
+            self,
+            NotificationKind::Opportunity
+                | NotificationKind::RiskLimit
+                | NotificationKind::ConnectorDisconnect
+                | NotificationKind::ConnectorReconnect
+        )
+    }
+}
+
+/// One outbound notification, POSTed as the JSON body
+#[derive(Debug, Clone, Serialize)]
+pub struct Notification {
+    pub kind: NotificationKind,
+    pub title: String,
+    pub detail: String,
+    pub at: DateTime<Utc>,
+}
+
+/// Notification subsystem with a webhook sink: POSTs JSON for executed
+/// trades, tripped circuit breakers, connector feed outages and risk-limit
+/// hits to each configured URL, with retries and optional HMAC-SHA256
+/// signing (hex digest of the body in `X-Arbiter-Signature`).
+///
+/// Delivery runs on its own task fed by a channel, so a slow receiver
+/// never stalls the trading path.
+pub struct Notifier {
+    config: Config,
+    telegram: Arc<TelegramBot>,
+    slack: Arc<SlackNotifier>,
+    tx: mpsc::UnboundedSender<Notification>,
+    rx: Mutex<Option<mpsc::UnboundedReceiver<Notification>>>,
+    /// kind+title → last sent, ms — burst suppression for noisy kinds
+    last_sent: DashMap<String, i64>,
+    /// Every notification also lands on the system event timeline
+    log: Arc<crate::eventlog::SystemEventLog>,
+}
+
+impl Notifier {
+    pub fn from_config(config: &Config) -> Self {
+        let (tx, rx) = mpsc::unbounded_channel();
+        Self {
+            config: config.clone(),
+            telegram: Arc::new(TelegramBot::from_config(&config.telegram)),
+            slack: Arc::new(SlackNotifier::from_config(&config.slack)),
+            tx,
+            rx: Mutex::new(Some(rx)),
+            last_sent: DashMap::new(),
+            log: Arc::new(crate::eventlog::SystemEventLog::new()),
+        }
+    }
+
+    /// The system event timeline fed by this notifier
+    pub fn event_log(&self) -> Arc<crate::eventlog::SystemEventLog> {
+        self.log.clone()
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.webhooks_enabled() || self.telegram.enabled() || self.slack.enabled()
+    }
+
+    fn webhooks_enabled(&self) -> bool {
+        self.config.notify.enabled && !self.config.notify.urls.is_empty()
+    }
+
+    /// Queue a notification for delivery; burst-prone kinds repeat at most
+    /// once a minute per title
+    pub fn notify(&self, kind: NotificationKind, title: &str, detail: String) {
+        if kind.dedupes() {
+            let key = format!("{:?}-{}", kind, title);
+            let now = Utc::now().timestamp_millis();
+            if let Some(last) = self.last_sent.get(&key) {
+                if now - *last < DEDUPE_SECS * 1_000 {
+                    return;
+                }
+            }
+            self.last_sent.insert(key, now);
+        }
+        // The timeline records everything, whether or not a sink is up
+        self.log.record(kind, title, &detail);
+        if !self.enabled() {
+            return;
+        }
+        let _ = self.tx.send(Notification {
+            kind,
+            title: title.to_string(),
+            detail,
+            at: Utc::now(),
+        });
+    }
+
+    /// Start the delivery task, the Telegram command poll and the
+    /// connector feed watch; no-op unless a sink is enabled
+    pub async fn start(
+        self: Arc<Self>,
+        prices: Arc<PriceCache>,
+        paused: Arc<std::sync::atomic::AtomicBool>,
+    ) {
+        let Some(rx) = self.rx.lock().await.take() else {
+            return;
+        };
+        // The feed watch below runs even with no sink configured — its
+        // events still land on the system event timeline
+        if self.enabled() {
+            info!(
+                "Notifier started ({} webhook URLs{}{})",
+                self.config.notify.urls.len(),
+                if self.telegram.enabled() {
+                    ", Telegram"
+                } else {
+                    ""
+                },
+                if self.slack.enabled() { ", Slack" } else { "" }
+            );
+        }
+
+        let config = if self.webhooks_enabled() {
+            Some(self.config.notify.clone())
+        } else {
+            None
+        };
+        let telegram = self.telegram.clone();
+        let slack = self.slack.clone();
+        tokio::spawn(async move {
+            deliver(config, telegram, slack, rx).await;
+        });
+
+        let telegram_for_commands = self.telegram.clone();
+        let prices_for_commands = prices.clone();
+        tokio::spawn(async move {
+            telegram_for_commands
+                .start_commands(paused, prices_for_commands)
+                .await;
+        });
+
+        self.feed_watch(prices).await;
+    }
+
+    /// Watch each venue's ticker freshness and notify when a feed goes
+    /// silent past its configured staleness window (and when it recovers) —
+    /// the observable symptom of a connector disconnect
+    async fn feed_watch(&self, prices: Arc<PriceCache>) {
+        let mut fresh: std::collections::HashMap<Exchange, bool> = std::collections::HashMap::new();
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(FEED_CHECK_SECS)).await;
+            for exchange in [Exchange::Bybit, Exchange::Bitget] {
+                let Some(cfg) = self.config_for(&exchange) else {
+                    continue;
+                };
+                let latest = prices
+                    .all()
+                    .into_iter()
+                    .filter(|t| t.exchange == exchange)
+                    .map(|t| t.timestamp)
+                    .max();
+                // Nothing received yet: not connected rather than dropped
+                let Some(latest) = latest else {
+                    continue;
+                };
+                let age_secs = (Utc::now() - latest).num_seconds();
+                let is_fresh = age_secs <= cfg as i64;
+                let was_fresh = fresh.insert(exchange, is_fresh).unwrap_or(true);
+                if was_fresh && !is_fresh {
+                    self.notify(
+                        NotificationKind::ConnectorDisconnect,
+                        &format!("{} feed silent", exchange),
+                        format!("No ticker from {} for {}s", exchange, age_secs),
+                    );
+                } else if !was_fresh && is_fresh {
+                    self.notify(
+                        NotificationKind::ConnectorReconnect,
+                        &format!("{} feed recovered", exchange),
+                        format!("{} is streaming tickers again", exchange),
+                    );
+                }
+            }
+        }
+    }
+
+    fn config_for(&self, exchange: &Exchange) -> Option<u64> {
+        self.config
+            .get_exchange(exchange)
+            .filter(|cfg| cfg.enabled)
+            .map(|cfg| cfg.ws_stale_secs)
+    }
+}
+
+/// Deliver queued notifications to every webhook URL (retrying each with
+/// backoff) and to Telegram
+async fn deliver(
+    config: Option<NotifyConfig>,
+    telegram: Arc<TelegramBot>,
+    slack: Arc<SlackNotifier>,
+    mut rx: mpsc::UnboundedReceiver<Notification>,
+) {
+    let client = reqwest::Client::new();
+    while let Some(notification) = rx.recv().await {
+        telegram
+            .send(&format!("{}\n{}", notification.title, notification.detail))
+            .await;
+        slack.send(&notification).await;
+        let Some(config) = &config else {
+            continue;
+        };
+        let Ok(body) = serde_json::to_string(&notification) else {
+            continue;
+        };
+        let signature = sign(&config.secret, &body);
+        for url in &config.urls {
+            let mut delivered = false;
+            for attempt in 0..config.retry_attempts.max(1) {
+                if attempt > 0 {
+                    tokio::time::sleep(std::time::Duration::from_secs(1 << attempt)).await;
+                }
+                let mut request = client
+                    .post(url)
+                    .header("Content-Type", "application/json")
+                    .body(body.clone());
+                if let Some(signature) = &signature {
+                    request = request.header("X-Arbiter-Signature", signature);
+                }
+                match request.send().await {
+                    Ok(response) if response.status().is_success() => {
+                        delivered = true;
+                        break;
+                    }
+                    Ok(response) => {
+                        warn!("Webhook {} returned {}", url, response.status());
+                    }
+                    Err(e) => {
+                        warn!("Webhook {} failed: {}", url, e);
+                    }
+                }
+            }
+            if !delivered {
+                warn!(
+                    "Dropping {:?} notification for {} after {} attempts",
+                    notification.kind,
+                    url,
+                    config.retry_attempts.max(1)
+                );
+            }
+        }
+    }
+}
+
+/// Hex HMAC-SHA256 of the body; None when no secret is configured
+fn sign(secret: &str, body: &str) -> Option<String> {
+    if secret.is_empty() {
+        return None;
+    }
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).ok()?;
+    mac.update(body.as_bytes());
+    Some(hex::encode(mac.finalize().into_bytes()))
+}
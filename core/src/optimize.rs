@@ -0,0 +1,201 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+use tracing::info;
+
+use crate::backtest::{BacktestReport, Backtester};
+use crate::config::Config;
+use crate::types::Ticker;
+
+/// Candidate values per swept dimension. Build one by hand, or with
+/// `around` for a grid bracketing the currently configured values.
+#[derive(Debug, Clone)]
+pub struct SweepSpec {
+    pub min_spread_pct: Vec<Decimal>,
+    pub max_trade_qty: Vec<Decimal>,
+    pub trade_cooldown_ms: Vec<u64>,
+    /// Fixed-model slippage assumption applied per leg
+    pub slippage_bps: Vec<Decimal>,
+}
+
+impl SweepSpec {
+    /// A small grid bracketing the loaded config: 0.5×–2× around the
+    /// configured spread threshold and size, half/normal/double cooldown,
+    /// and a spread of slippage assumptions
+    pub fn around(config: &Config) -> Self {
+        let bracket = |value: Decimal| {
+            let mut values = vec![
+                value / Decimal::from(2),
+                value,
+                value * Decimal::new(15, 1),
+                value * Decimal::from(2),
+            ];
+            values.retain(|v| *v > Decimal::ZERO);
+            values.dedup();
+            values
+        };
+        let cooldown = config.risk.trade_cooldown_ms;
+        let mut cooldowns = vec![cooldown / 2, cooldown, cooldown * 2];
+        cooldowns.dedup();
+        Self {
+            min_spread_pct: bracket(config.engine.min_spread_pct),
+            max_trade_qty: bracket(config.trading.max_trade_qty),
+            trade_cooldown_ms: cooldowns,
+            slippage_bps: vec![
+                Decimal::ZERO,
+                Decimal::new(2, 0),
+                Decimal::new(5, 0),
+                Decimal::new(10, 0),
+            ],
+        }
+    }
+
+    /// Every grid combination, in sweep order
+    fn combos(&self) -> Vec<(Decimal, Decimal, u64, Decimal)> {
+        let mut combos = Vec::new();
+        for &spread in &self.min_spread_pct {
+            for &qty in &self.max_trade_qty {
+                for &cooldown in &self.trade_cooldown_ms {
+                    for &slip in &self.slippage_bps {
+                        combos.push((spread, qty, cooldown, slip));
+                    }
+                }
+            }
+        }
+        combos
+    }
+
+    /// `samples` random combinations drawn from the dimension candidates
+    fn sample(&self, samples: usize) -> Vec<(Decimal, Decimal, u64, Decimal)> {
+        let pick = |values: &[Decimal]| values[rand::random::<usize>() % values.len().max(1)];
+        (0..samples)
+            .map(|_| {
+                (
+                    pick(&self.min_spread_pct),
+                    pick(&self.max_trade_qty),
+                    self.trade_cooldown_ms[rand::random::<usize>() % self.trade_cooldown_ms.len().max(1)],
+                    pick(&self.slippage_bps),
+                )
+            })
+            .collect()
+    }
+}
+
+/// One evaluated parameter set for one pair
+#[derive(Debug, Clone, Serialize)]
+pub struct SweepResult {
+    pub pair: String,
+    pub min_spread_pct: Decimal,
+    pub max_trade_qty: Decimal,
+    pub trade_cooldown_ms: u64,
+    pub slippage_bps: Decimal,
+    pub report: BacktestReport,
+}
+
+/// Parameter sweep harness on top of the backtester: re-runs the same
+/// recorded data per configured pair under every candidate parameter set
+/// (grid, or random samples from the grid) and reports results best-first
+/// per pair by net profit.
+pub struct Optimizer {
+    base: Config,
+    spec: SweepSpec,
+}
+
+impl Optimizer {
+    pub fn new(base: Config, spec: SweepSpec) -> Self {
+        Self { base, spec }
+    }
+
+    /// Exhaustive grid search over every combination
+    pub async fn grid(&self, tickers: &[Ticker]) -> Vec<SweepResult> {
+        self.sweep(tickers, self.spec.combos()).await
+    }
+
+    /// Random search: `samples` combinations drawn from the grid
+    pub async fn random(&self, tickers: &[Ticker], samples: usize) -> Vec<SweepResult> {
+        self.sweep(tickers, self.spec.sample(samples)).await
+    }
+
+    async fn sweep(
+        &self,
+        tickers: &[Ticker],
+        combos: Vec<(Decimal, Decimal, u64, Decimal)>,
+    ) -> Vec<SweepResult> {
+        let mut results = Vec::new();
+        for pair in &self.base.trading.pairs {
+            // Restrict the replay to this pair's tickers so each report is
+            // attributable to one market
+            let pair_tickers: Vec<Ticker> = tickers
+                .iter()
+                .filter(|t| t.pair.to_string() == *pair)
+                .cloned()
+                .collect();
+            if pair_tickers.is_empty() {
+                continue;
+            }
+            info!(
+                "Sweeping {} parameter sets over {} ({} tickers)",
+                combos.len(),
+                pair,
+                pair_tickers.len()
+            );
+            for &(spread, qty, cooldown, slip) in &combos {
+                let report = self
+                    .evaluate(pair, &pair_tickers, spread, qty, cooldown, slip)
+                    .await;
+                results.push(SweepResult {
+                    pair: pair.clone(),
+                    min_spread_pct: spread,
+                    max_trade_qty: qty,
+                    trade_cooldown_ms: cooldown,
+                    slippage_bps: slip,
+                    report,
+                });
+            }
+        }
+
+        // Best first within each pair
+        results.sort_by(|a, b| {
+            a.pair
+                .cmp(&b.pair)
+                .then(b.report.net_profit.cmp(&a.report.net_profit))
+        });
+        let mut last_pair = String::new();
+        for result in &results {
+            if result.pair != last_pair {
+                last_pair = result.pair.clone();
+                info!(
+                    "Best for {}: spread {}%, qty {}, cooldown {}ms, slip {}bps → net {} over {} trades",
+                    result.pair,
+                    result.min_spread_pct,
+                    result.max_trade_qty,
+                    result.trade_cooldown_ms,
+                    result.slippage_bps,
+                    result.report.net_profit,
+                    result.report.trades
+                );
+            }
+        }
+        results
+    }
+
+    async fn evaluate(
+        &self,
+        pair: &str,
+        tickers: &[Ticker],
+        spread: Decimal,
+        qty: Decimal,
+        cooldown: u64,
+        slip: Decimal,
+    ) -> BacktestReport {
+        let mut config = self.base.clone();
+        config.trading.pairs = vec![pair.to_string()];
+        // Per-pair overrides would mask the swept globals
+        config.trading.pair_overrides.clear();
+        config.engine.min_spread_pct = spread;
+        config.trading.max_trade_qty = qty;
+        config.risk.trade_cooldown_ms = cooldown;
+        config.slippage.model = "fixed".to_string();
+        config.slippage.fixed_bps = slip;
+        Backtester::new(config).run(tickers).await
+    }
+}
@@ -0,0 +1,50 @@
+use dashmap::DashMap;
+
+use crate::types::{Exchange, Order, OrderStatus};
+
+/// Central registry of orders the bot has placed, keyed by
+/// (exchange, order id). The executor records every placed leg here; the
+/// API reads it for GET /api/orders and updates it on manual cancels.
+#[derive(Default)]
+pub struct OrderTracker {
+    orders: DashMap<(Exchange, String), Order>,
+}
+
+impl OrderTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a newly placed (or refreshed) order
+    pub fn record(&self, order: Order) {
+        self.orders
+            .insert((order.exchange, order.id.clone()), order);
+    }
+
+    /// Mark a tracked order cancelled (after the exchange confirmed it)
+    pub fn mark_cancelled(&self, exchange: Exchange, order_id: &str) {
+        if let Some(mut entry) = self.orders.get_mut(&(exchange, order_id.to_string())) {
+            entry.status = OrderStatus::Cancelled;
+            entry.updated_at = chrono::Utc::now();
+        }
+    }
+
+    /// Orders still working on an exchange, newest first
+    pub fn open(&self) -> Vec<Order> {
+        let mut open: Vec<Order> = self
+            .orders
+            .iter()
+            .filter(|e| e.value().is_open())
+            .map(|e| e.value().clone())
+            .collect();
+        open.sort_by_key(|o| std::cmp::Reverse(o.created_at));
+        open
+    }
+
+    /// Every tracked order, newest first
+    pub fn all(&self) -> Vec<Order> {
+        let mut all: Vec<Order> = self.orders.iter().map(|e| e.value().clone()).collect();
+        all.sort_by_key(|o| std::cmp::Reverse(o.created_at));
+        all
+    }
+}
@@ -0,0 +1,134 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::fx::FxRateCache;
+use crate::positions::PositionTracker;
+use crate::prices::PriceCache;
+use crate::types::TradeResult;
+
+/// Realized PnL booked on one calendar day (UTC)
+#[derive(Debug, Clone, Serialize)]
+pub struct DailyPnl {
+    /// YYYY-MM-DD
+    pub date: String,
+    pub trades: usize,
+    /// Net profit booked that day, in the reporting currency where a
+    /// conversion path existed
+    pub realized: Decimal,
+    pub fees: Decimal,
+    /// Fees attributed per venue (half of each trade's fees to each leg)
+    pub fees_by_exchange: BTreeMap<String, Decimal>,
+}
+
+/// One open inventory entry marked to market
+#[derive(Debug, Clone, Serialize)]
+pub struct UnrealizedEntry {
+    pub exchange: crate::types::Exchange,
+    pub asset: String,
+    pub qty: Decimal,
+    /// Current value in the reporting currency (None = no conversion path)
+    pub value: Option<Decimal>,
+}
+
+/// Full PnL report, as served via GET /api/pnl
+#[derive(Debug, Clone, Serialize)]
+pub struct PnlReport {
+    pub reporting_currency: String,
+    pub days: Vec<DailyPnl>,
+    pub realized_total: Decimal,
+    pub fees_total: Decimal,
+    /// Tracked inventory deltas marked at current prices — the PnL still
+    /// sitting in open positions rather than booked trades
+    pub unrealized: Vec<UnrealizedEntry>,
+    pub unrealized_total: Decimal,
+}
+
+/// PnL accounting: books realized profit per trade into per-day buckets
+/// and marks the position tracker's open inventory to market via current
+/// tickers for the unrealized side.
+pub struct PnlCalculator {
+    prices: Arc<PriceCache>,
+    fx: Arc<FxRateCache>,
+    positions: Arc<PositionTracker>,
+}
+
+impl PnlCalculator {
+    pub fn new(
+        prices: Arc<PriceCache>,
+        fx: Arc<FxRateCache>,
+        positions: Arc<PositionTracker>,
+    ) -> Self {
+        Self {
+            prices,
+            fx,
+            positions,
+        }
+    }
+
+    /// Build the report over the given trades (already windowed by the
+    /// caller), in the configured reporting currency
+    pub fn report(&self, trades: &[TradeResult], reporting: &str) -> PnlReport {
+        let mut days: BTreeMap<String, DailyPnl> = BTreeMap::new();
+        for trade in trades {
+            let date = trade.executed_at.format("%Y-%m-%d").to_string();
+            let day = days.entry(date.clone()).or_insert_with(|| DailyPnl {
+                date,
+                trades: 0,
+                realized: Decimal::ZERO,
+                fees: Decimal::ZERO,
+                fees_by_exchange: BTreeMap::new(),
+            });
+            day.trades += 1;
+            day.realized += trade.net_profit_reporting.unwrap_or(trade.net_profit);
+
+            // Fees are booked per trade in quote units; convert and split
+            // evenly across the two legs' venues
+            let fees = self
+                .fx
+                .quote_to_reporting(&self.prices, trade.fees, &trade.pair.quote, reporting)
+                .unwrap_or(trade.fees);
+            day.fees += fees;
+            let half = fees / Decimal::TWO;
+            *day.fees_by_exchange
+                .entry(trade.buy_exchange.to_string())
+                .or_default() += half;
+            *day.fees_by_exchange
+                .entry(trade.sell_exchange.to_string())
+                .or_default() += fees - half;
+        }
+
+        let mut unrealized = Vec::new();
+        let mut unrealized_total = Decimal::ZERO;
+        for position in self.positions.snapshot() {
+            if position.qty == Decimal::ZERO {
+                continue;
+            }
+            let value = self.fx.quote_to_reporting(
+                &self.prices,
+                position.qty,
+                &position.asset,
+                reporting,
+            );
+            if let Some(value) = value {
+                unrealized_total += value;
+            }
+            unrealized.push(UnrealizedEntry {
+                exchange: position.exchange,
+                asset: position.asset,
+                qty: position.qty,
+                value,
+            });
+        }
+
+        PnlReport {
+            reporting_currency: reporting.to_string(),
+            realized_total: days.values().map(|d| d.realized).sum(),
+            fees_total: days.values().map(|d| d.fees).sum(),
+            days: days.into_values().collect(),
+            unrealized,
+            unrealized_total,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::exchange::ExchangeConnector;
+use crate::types::{Exchange, ExchangeBalance};
+
+/// A balance fetch failure on one venue; the other venues' balances are
+/// still served
+#[derive(Debug, Clone, Serialize)]
+pub struct PortfolioError {
+    pub exchange: Exchange,
+    pub error: String,
+}
+
+/// Balances across all venues as of one fetch, plus per-exchange errors
+#[derive(Debug, Clone, Serialize)]
+pub struct PortfolioSnapshot {
+    pub balances: Vec<ExchangeBalance>,
+    pub errors: Vec<PortfolioError>,
+    pub as_of: DateTime<Utc>,
+    /// True when served from the short-lived cache rather than fetched
+    pub cached: bool,
+}
+
+/// Short-lived cache in front of the venues' balance endpoints, so the
+/// dashboard polling /api/portfolio doesn't hammer signed REST calls
+pub struct PortfolioCache {
+    ttl_ms: i64,
+    inner: Mutex<Option<PortfolioSnapshot>>,
+}
+
+impl PortfolioCache {
+    pub fn new(ttl_ms: i64) -> Self {
+        Self {
+            ttl_ms: ttl_ms.max(0),
+            inner: Mutex::new(None),
+        }
+    }
+
+    /// Current balances across `connectors`, served from cache while the
+    /// last fetch is younger than the TTL
+    pub async fn snapshot(
+        &self,
+        connectors: &[Arc<dyn ExchangeConnector>],
+    ) -> PortfolioSnapshot {
+        let mut cached = self.inner.lock().await;
+        if let Some(snapshot) = cached.as_ref() {
+            let age_ms = (Utc::now() - snapshot.as_of).num_milliseconds();
+            if age_ms < self.ttl_ms {
+                let mut snapshot = snapshot.clone();
+                snapshot.cached = true;
+                return snapshot;
+            }
+        }
+
+        let mut balances = Vec::new();
+        let mut errors = Vec::new();
+        for connector in connectors {
+            match connector.get_balances().await {
+                Ok(mut venue_balances) => balances.append(&mut venue_balances),
+                Err(e) => errors.push(PortfolioError {
+                    exchange: connector.exchange(),
+                    error: e.to_string(),
+                }),
+            }
+        }
+        let snapshot = PortfolioSnapshot {
+            balances,
+            errors,
+            as_of: Utc::now(),
+            cached: false,
+        };
+        *cached = Some(snapshot.clone());
+        snapshot
+    }
+}
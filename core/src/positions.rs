@@ -0,0 +1,119 @@
+use dashmap::DashMap;
+use rust_decimal::Decimal;
+use serde::Serialize;
+
+use crate::types::{Exchange, ExchangeBalance, TradeResult, TradeStatus};
+
+/// One tracked inventory entry, as exposed via GET /api/positions
+#[derive(Debug, Clone, Serialize)]
+pub struct PositionInfo {
+    pub exchange: Exchange,
+    pub asset: String,
+    pub qty: Decimal,
+}
+
+/// Per-asset inventory skew across venues: arbitrage legs drain the base
+/// asset on the sell venue and pile it up on the buy venue
+#[derive(Debug, Clone, Serialize)]
+pub struct SkewInfo {
+    pub asset: String,
+    pub min_qty: Decimal,
+    pub max_qty: Decimal,
+    /// max_qty - min_qty; large values mean a rebalance is overdue
+    pub skew: Decimal,
+}
+
+/// Tracks per-asset, per-exchange inventory, updated from executed trades
+/// and re-anchored from balance snapshots, so execution can see when one
+/// venue is about to run dry
+#[derive(Default)]
+pub struct PositionTracker {
+    inventory: DashMap<(Exchange, String), Decimal>,
+}
+
+impl PositionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fold one executed trade into the tracked inventory: the buy venue
+    /// gains base and spends quote, the sell venue does the reverse
+    pub fn apply_trade(&self, trade: &TradeResult) {
+        if matches!(trade.status, TradeStatus::Failed) {
+            return;
+        }
+        let base = trade.pair.base.clone();
+        let quote = trade.pair.quote.clone();
+        *self
+            .inventory
+            .entry((trade.buy_exchange, base.clone()))
+            .or_default() += trade.quantity;
+        *self
+            .inventory
+            .entry((trade.buy_exchange, quote.clone()))
+            .or_default() -= trade.quantity * trade.buy_price;
+        *self.inventory.entry((trade.sell_exchange, base)).or_default() -= trade.quantity;
+        *self
+            .inventory
+            .entry((trade.sell_exchange, quote))
+            .or_default() += trade.quantity * trade.sell_price;
+    }
+
+    /// Re-anchor tracked inventory from a venue balance snapshot; exact
+    /// balances beat the running trade deltas whenever they're available
+    pub fn sync_balances(&self, balances: &[ExchangeBalance]) {
+        for balance in balances {
+            self.inventory
+                .insert((balance.exchange, balance.asset.clone()), balance.total);
+        }
+    }
+
+    /// Tracked inventory for one (exchange, asset); None when the asset
+    /// has never been seen (unknown, not zero)
+    pub fn inventory(&self, exchange: Exchange, asset: &str) -> Option<Decimal> {
+        self.inventory
+            .get(&(exchange, asset.to_string()))
+            .map(|v| *v)
+    }
+
+    /// All tracked entries, sorted for stable API output
+    pub fn snapshot(&self) -> Vec<PositionInfo> {
+        let mut out: Vec<PositionInfo> = self
+            .inventory
+            .iter()
+            .map(|e| PositionInfo {
+                exchange: e.key().0,
+                asset: e.key().1.clone(),
+                qty: *e.value(),
+            })
+            .collect();
+        out.sort_by_key(|p| (p.asset.clone(), p.exchange.to_string()));
+        out
+    }
+
+    /// Cross-venue skew per asset seen on more than one venue
+    pub fn skews(&self) -> Vec<SkewInfo> {
+        let mut per_asset: std::collections::BTreeMap<String, Vec<Decimal>> =
+            std::collections::BTreeMap::new();
+        for entry in self.inventory.iter() {
+            per_asset
+                .entry(entry.key().1.clone())
+                .or_default()
+                .push(*entry.value());
+        }
+        per_asset
+            .into_iter()
+            .filter(|(_, quantities)| quantities.len() > 1)
+            .map(|(asset, quantities)| {
+                let min_qty = quantities.iter().copied().min().unwrap_or_default();
+                let max_qty = quantities.iter().copied().max().unwrap_or_default();
+                SkewInfo {
+                    asset,
+                    min_qty,
+                    max_qty,
+                    skew: max_qty - min_qty,
+                }
+            })
+            .collect()
+    }
+}
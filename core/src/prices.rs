@@ -0,0 +1,64 @@
+use dashmap::DashMap;
+use tokio::sync::broadcast;
+
+use crate::types::{Exchange, Ticker};
+
+/// Shared latest-price cache keyed by (exchange, pair).
+///
+/// A single instance is owned by core and shared between the detector
+/// (which writes it) and the API layer (which reads through it), so there
+/// is one source of truth instead of two maps that can diverge. Live
+/// updates fan out over a broadcast channel for WebSocket push.
+pub struct PriceCache {
+    inner: DashMap<(Exchange, String), Ticker>,
+    updates: broadcast::Sender<Ticker>,
+}
+
+impl Default for PriceCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PriceCache {
+    pub fn new() -> Self {
+        let (updates, _) = broadcast::channel(1024);
+        Self {
+            inner: DashMap::new(),
+            updates,
+        }
+    }
+
+    /// Store the latest ticker and publish it to subscribers
+    pub fn insert(&self, ticker: Ticker) {
+        let key = (ticker.exchange, ticker.pair.to_string());
+        self.inner.insert(key, ticker.clone());
+        // Send only fails when there are no subscribers, which is fine
+        let _ = self.updates.send(ticker);
+    }
+
+    /// Latest ticker for a specific (exchange, pair)
+    pub fn get(&self, exchange: Exchange, pair: &str) -> Option<Ticker> {
+        self.inner
+            .get(&(exchange, pair.to_string()))
+            .map(|entry| entry.value().clone())
+    }
+
+    /// All current tickers
+    pub fn all(&self) -> Vec<Ticker> {
+        self.inner
+            .iter()
+            .map(|entry| entry.value().clone())
+            .collect()
+    }
+
+    /// Subscribe to the live ticker stream
+    pub fn subscribe(&self) -> broadcast::Receiver<Ticker> {
+        self.updates.subscribe()
+    }
+
+    /// Access to the underlying map for in-crate diagnostics
+    pub(crate) fn map(&self) -> &DashMap<(Exchange, String), Ticker> {
+        &self.inner
+    }
+}
@@ -0,0 +1,182 @@
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::config::RebalanceConfig;
+use crate::positions::PositionTracker;
+use crate::types::Exchange;
+
+/// Events the rebalancer keeps
+const REBALANCE_EVENT_CAP: usize = 500;
+
+/// Outcome of one planned transfer
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RebalanceStatus {
+    /// Dry-run mode: the transfer was planned and logged but not sent
+    Planned,
+    /// Live mode without venue withdrawal support wired up
+    Skipped,
+    /// Transfer submitted to the source venue
+    Submitted,
+    /// Submission failed
+    Failed,
+}
+
+/// One rebalancing decision, as exposed via GET /api/rebalance/events
+#[derive(Debug, Clone, Serialize)]
+pub struct RebalanceEvent {
+    pub id: String,
+    pub asset: String,
+    pub from: Exchange,
+    pub to: Exchange,
+    pub qty: Decimal,
+    pub skew: Decimal,
+    pub status: RebalanceStatus,
+    pub note: String,
+    pub at: DateTime<Utc>,
+}
+
+/// Watches cross-venue inventory skew and plans transfers from the
+/// overweight venue to the underweight one to restore an even split.
+/// Dry-run by default: every decision is logged but nothing moves until
+/// `rebalance.dry_run` is turned off.
+pub struct Rebalancer {
+    config: RebalanceConfig,
+    positions: Arc<PositionTracker>,
+    events: Mutex<Vec<RebalanceEvent>>,
+}
+
+impl Rebalancer {
+    pub fn from_config(config: &RebalanceConfig, positions: Arc<PositionTracker>) -> Self {
+        Self {
+            config: config.clone(),
+            positions,
+            events: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Run the periodic skew check; no-op unless enabled
+    pub async fn start(&self) {
+        if !self.config.enabled {
+            return;
+        }
+        info!(
+            "Rebalancer started (dry_run: {}, check every {}s)",
+            self.config.dry_run, self.config.check_secs
+        );
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(self.config.check_secs.max(30)))
+                .await;
+            self.check_once().await;
+        }
+    }
+
+    /// One pass over the tracked skews, planning a transfer for every
+    /// asset past its threshold
+    pub async fn check_once(&self) {
+        for skew in self.positions.skews() {
+            let Some(max_skew) = self.config.max_skew.get(&skew.asset) else {
+                continue;
+            };
+            if skew.skew <= *max_skew {
+                continue;
+            }
+
+            // Move half the skew so both venues end up near the midpoint
+            let qty = skew.skew / Decimal::TWO;
+            let min_transfer = self
+                .config
+                .min_transfer
+                .get(&skew.asset)
+                .copied()
+                .unwrap_or(Decimal::ZERO);
+            if qty < min_transfer {
+                continue;
+            }
+
+            let (Some(from), Some(to)) = self.venues_for(&skew.asset, skew.max_qty, skew.min_qty)
+            else {
+                continue;
+            };
+            self.plan_transfer(&skew.asset, from, to, qty, skew.skew).await;
+        }
+    }
+
+    /// The venues holding the most and least of an asset
+    fn venues_for(
+        &self,
+        asset: &str,
+        max_qty: Decimal,
+        min_qty: Decimal,
+    ) -> (Option<Exchange>, Option<Exchange>) {
+        let mut from = None;
+        let mut to = None;
+        for exchange in [Exchange::Bybit, Exchange::Bitget] {
+            if let Some(qty) = self.positions.inventory(exchange, asset) {
+                if qty == max_qty && from.is_none() {
+                    from = Some(exchange);
+                } else if qty == min_qty && to.is_none() {
+                    to = Some(exchange);
+                }
+            }
+        }
+        (from, to)
+    }
+
+    async fn plan_transfer(
+        &self,
+        asset: &str,
+        from: Exchange,
+        to: Exchange,
+        qty: Decimal,
+        skew: Decimal,
+    ) {
+        let (status, note) = if self.config.dry_run {
+            info!(
+                "Rebalance (dry run): would move {} {} from {} to {} (skew {})",
+                qty, asset, from, to, skew
+            );
+            (
+                RebalanceStatus::Planned,
+                "dry_run: transfer planned, not sent".to_string(),
+            )
+        } else {
+            warn!(
+                "Rebalance: {} {} from {} to {} needed, but live transfers \
+                 require venue withdrawal support",
+                qty, asset, from, to
+            );
+            (
+                RebalanceStatus::Skipped,
+                "live transfers not wired to venue withdrawals yet".to_string(),
+            )
+        };
+
+        let mut events = self.events.lock().await;
+        events.push(RebalanceEvent {
+            id: Uuid::new_v4().to_string(),
+            asset: asset.to_string(),
+            from,
+            to,
+            qty,
+            skew,
+            status,
+            note,
+            at: Utc::now(),
+        });
+        let overflow = events.len().saturating_sub(REBALANCE_EVENT_CAP);
+        if overflow > 0 {
+            events.drain(..overflow);
+        }
+    }
+
+    /// Recorded decisions, newest last
+    pub async fn events(&self) -> Vec<RebalanceEvent> {
+        self.events.lock().await.clone()
+    }
+}
@@ -0,0 +1,168 @@
+use chrono::{Duration as ChronoDuration, Utc};
+use rust_decimal::Decimal;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+use tracing::{info, warn};
+
+use crate::config::Config;
+use crate::exchange::ExchangeConnector;
+use crate::orders::OrderTracker;
+use crate::types::{DiscrepancyKind, ReconcileDiscrepancy, TradingPair};
+
+/// Local orders younger than this are skipped — the venue may simply not
+/// have settled them into its history yet
+const SETTLE_GRACE_SECS: i64 = 120;
+
+/// Periodically pulls each exchange's own order history and reconciles it
+/// against the orders this bot recorded, flagging orphan fills (the
+/// exchange has a fill we don't) and phantom trades (we recorded a fill
+/// the exchange doesn't). Discrepancies go out over the events/WS channel.
+pub struct Reconciler {
+    connectors: Vec<Arc<dyn ExchangeConnector>>,
+    orders: Arc<OrderTracker>,
+    config: Config,
+    discrepancy_tx: mpsc::UnboundedSender<ReconcileDiscrepancy>,
+    /// Discrepancies already reported (kind + exchange + order id)
+    reported: Mutex<HashSet<String>>,
+}
+
+impl Reconciler {
+    pub fn new(
+        connectors: Vec<Arc<dyn ExchangeConnector>>,
+        orders: Arc<OrderTracker>,
+        config: Config,
+        discrepancy_tx: mpsc::UnboundedSender<ReconcileDiscrepancy>,
+    ) -> Self {
+        Self {
+            connectors,
+            orders,
+            config,
+            discrepancy_tx,
+            reported: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Run the periodic reconciliation pass; no-op unless enabled
+    pub async fn start(&self) {
+        if !self.config.reconcile.enabled {
+            return;
+        }
+        info!(
+            "Reconciler started (every {}s, lookback {}s)",
+            self.config.reconcile.check_secs, self.config.reconcile.lookback_secs
+        );
+        loop {
+            tokio::time::sleep(std::time::Duration::from_secs(
+                self.config.reconcile.check_secs.max(30),
+            ))
+            .await;
+            self.check_once().await;
+        }
+    }
+
+    /// One reconciliation pass over every connector × configured pair
+    pub async fn check_once(&self) {
+        let since = Utc::now() - ChronoDuration::seconds(self.config.reconcile.lookback_secs as i64);
+
+        for connector in &self.connectors {
+            let exchange = connector.exchange();
+            for pair_str in &self.config.trading.pairs {
+                let Some(pair) = TradingPair::parse(pair_str) else {
+                    continue;
+                };
+                if !self.config.pair_enabled_on(&exchange, &pair) {
+                    continue;
+                }
+
+                let venue_orders = match connector.get_order_history(&pair, since).await {
+                    Ok(orders) => orders,
+                    Err(e) => {
+                        warn!(
+                            "Reconciliation: could not fetch {} history on {}: {}",
+                            pair, exchange, e
+                        );
+                        continue;
+                    }
+                };
+
+                let local: Vec<_> = self
+                    .orders
+                    .all()
+                    .into_iter()
+                    .filter(|o| {
+                        o.exchange == exchange
+                            && o.pair.to_string() == pair.to_string()
+                            && o.created_at >= since
+                    })
+                    .collect();
+                let local_ids: HashSet<&str> = local.iter().map(|o| o.id.as_str()).collect();
+                let venue_ids: HashSet<&str> =
+                    venue_orders.iter().map(|o| o.id.as_str()).collect();
+
+                // Orphan fills: the venue filled an order we never recorded
+                for order in &venue_orders {
+                    if order.filled_qty > Decimal::ZERO && !local_ids.contains(order.id.as_str()) {
+                        self.report(ReconcileDiscrepancy {
+                            exchange,
+                            pair: pair.to_string(),
+                            order_id: order.id.clone(),
+                            kind: DiscrepancyKind::OrphanFill,
+                            detail: format!(
+                                "exchange reports {:?} fill of {} at {} with no local record",
+                                order.side,
+                                order.filled_qty,
+                                order.price.unwrap_or(Decimal::ZERO)
+                            ),
+                            at: Utc::now(),
+                        })
+                        .await;
+                    }
+                }
+
+                // Phantom trades: we recorded a fill the venue's history
+                // doesn't contain (grace period covers settlement lag)
+                let settled_before = Utc::now() - ChronoDuration::seconds(SETTLE_GRACE_SECS);
+                for order in &local {
+                    if order.filled_qty > Decimal::ZERO
+                        && order.created_at < settled_before
+                        && !venue_ids.contains(order.id.as_str())
+                    {
+                        self.report(ReconcileDiscrepancy {
+                            exchange,
+                            pair: pair.to_string(),
+                            order_id: order.id.clone(),
+                            kind: DiscrepancyKind::PhantomTrade,
+                            detail: format!(
+                                "local record shows {:?} fill of {} but the exchange history has no such order",
+                                order.side, order.filled_qty
+                            ),
+                            at: Utc::now(),
+                        })
+                        .await;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Publish a discrepancy once; repeats across passes are suppressed
+    async fn report(&self, discrepancy: ReconcileDiscrepancy) {
+        let key = format!(
+            "{:?}-{}-{}",
+            discrepancy.kind, discrepancy.exchange, discrepancy.order_id
+        );
+        let mut reported = self.reported.lock().await;
+        if !reported.insert(key) {
+            return;
+        }
+        if reported.len() > 10_000 {
+            reported.clear();
+        }
+        warn!(
+            "Reconciliation discrepancy on {}: {:?} for order {} — {}",
+            discrepancy.exchange, discrepancy.kind, discrepancy.order_id, discrepancy.detail
+        );
+        let _ = self.discrepancy_tx.send(discrepancy);
+    }
+}
@@ -0,0 +1,450 @@
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use parquet::basic::Compression as ParquetCompression;
+use parquet::data_type::{ByteArray, ByteArrayType, DoubleType, Int64Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+use rust_decimal::prelude::ToPrimitive;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::config::{Config, RecorderConfig};
+use crate::prices::PriceCache;
+use crate::types::{Ticker, TickerSource};
+
+/// Flush the gzip compressor to disk every this many records, so a crash
+/// loses at most a few seconds of data without paying per-line flush
+/// overhead
+const FLUSH_EVERY: usize = 256;
+
+/// Rows buffered per Parquet row group before it is written out
+const PARQUET_ROW_GROUP: usize = 4_096;
+
+/// Parquet schema: decimals land as doubles (ample for analytics; the
+/// JSONL format keeps exact decimals and remains what replay and the
+/// backtester read)
+const PARQUET_SCHEMA: &str = "
+    message ticker {
+        required binary exchange (UTF8);
+        required binary pair (UTF8);
+        required double bid;
+        required double ask;
+        required double last;
+        required double volume_24h;
+        required int64 timestamp_ms;
+        required binary source (UTF8);
+    }
+";
+
+/// The file currently being written, by format
+enum Sink {
+    Jsonl(GzEncoder<File>),
+    Parquet {
+        writer: SerializedFileWriter<File>,
+        pending: Vec<Ticker>,
+    },
+}
+
+/// Records every ticker coming off the live price stream to rotating
+/// files, for backtesting and post-mortem analysis of bad trades.
+///
+/// Two formats: hourly gzip JSONL (`tickers-YYYYMMDD-HH.jsonl.gz`, one
+/// serialized `Ticker` per line — the format replay and the backtester
+/// consume), or per-day Snappy Parquet (`tickers-YYYYMMDD.parquet` with
+/// exchange/pair columns) that DuckDB and Polars query directly, for
+/// months-long recording. A Parquet file only gains its footer when
+/// closed, so a hard kill can lose the open file — prefer JSONL where
+/// that matters.
+///
+/// Order books are not streamed today — the connectors fetch depth over
+/// REST on demand — so tickers are the only feed recorded. Files older
+/// than the configured retention are deleted at rotation.
+pub struct MarketDataRecorder {
+    prices: Arc<PriceCache>,
+    config: RecorderConfig,
+}
+
+impl MarketDataRecorder {
+    pub fn new(prices: Arc<PriceCache>, config: Config) -> Self {
+        Self {
+            prices,
+            config: config.recorder,
+        }
+    }
+
+    /// Run the recording loop; no-op unless enabled
+    pub async fn start(&self) {
+        if !self.config.enabled {
+            return;
+        }
+        if let Err(e) = std::fs::create_dir_all(&self.config.dir) {
+            warn!(
+                "Market data recorder disabled: could not create {}: {}",
+                self.config.dir, e
+            );
+            return;
+        }
+        info!(
+            "Market data recorder started (dir {}, format {}, retention {}h)",
+            self.config.dir, self.config.format, self.config.retention_hours
+        );
+
+        let mut updates = self.prices.subscribe();
+        // (rotation key, open writer) for the file currently being written
+        let mut current: Option<(String, Sink)> = None;
+        let mut since_flush = 0usize;
+
+        use tokio::sync::broadcast::error::RecvError;
+        loop {
+            match updates.recv().await {
+                Ok(ticker) => {
+                    self.write(&ticker, &mut current, &mut since_flush);
+                }
+                // Slow disk: drop the missed tickers and keep recording
+                Err(RecvError::Lagged(n)) => {
+                    warn!("Market data recorder lagged, {} tickers dropped", n);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+        if let Some((_, sink)) = current.take() {
+            close_sink(sink);
+        }
+    }
+
+    /// Append one ticker, rotating on the hour (JSONL) or day (Parquet)
+    /// boundary. A restart within a JSONL hour appends a second gzip
+    /// member, which every standard decoder reads transparently; Parquet
+    /// cannot be appended, so a restart opens a sibling file with a time
+    /// suffix instead.
+    fn write(
+        &self,
+        ticker: &Ticker,
+        current: &mut Option<(String, Sink)>,
+        since_flush: &mut usize,
+    ) {
+        let parquet = self.config.format == "parquet";
+        let now = chrono::Utc::now();
+        let key = if parquet {
+            now.format("%Y%m%d").to_string()
+        } else {
+            now.format("%Y%m%d-%H").to_string()
+        };
+
+        if current.as_ref().map(|(k, _)| k != &key).unwrap_or(true) {
+            if let Some((_, sink)) = current.take() {
+                close_sink(sink);
+            }
+            self.prune();
+
+            let sink = if parquet {
+                self.open_parquet(&key, &now)
+            } else {
+                self.open_jsonl(&key)
+            };
+            match sink {
+                Some(sink) => *current = Some((key, sink)),
+                None => return,
+            }
+        }
+
+        let Some((_, sink)) = current.as_mut() else {
+            return;
+        };
+        match sink {
+            Sink::Jsonl(encoder) => match serde_json::to_string(ticker) {
+                Ok(line) => {
+                    if let Err(e) = writeln!(encoder, "{}", line) {
+                        warn!("Could not record ticker: {}", e);
+                        return;
+                    }
+                    *since_flush += 1;
+                    if *since_flush >= FLUSH_EVERY {
+                        let _ = encoder.flush();
+                        *since_flush = 0;
+                    }
+                }
+                Err(e) => warn!("Could not serialize ticker: {}", e),
+            },
+            Sink::Parquet { writer, pending } => {
+                pending.push(ticker.clone());
+                if pending.len() >= PARQUET_ROW_GROUP {
+                    if let Err(e) = write_row_group(writer, pending) {
+                        warn!("Could not write Parquet row group: {}", e);
+                    }
+                    pending.clear();
+                }
+            }
+        }
+    }
+
+    fn open_jsonl(&self, key: &str) -> Option<Sink> {
+        let path = Path::new(&self.config.dir).join(format!("tickers-{}.jsonl.gz", key));
+        match std::fs::OpenOptions::new().create(true).append(true).open(&path) {
+            Ok(file) => Some(Sink::Jsonl(GzEncoder::new(file, Compression::default()))),
+            Err(e) => {
+                warn!("Could not open {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    fn open_parquet(&self, key: &str, now: &chrono::DateTime<chrono::Utc>) -> Option<Sink> {
+        let dir = Path::new(&self.config.dir);
+        let mut path = dir.join(format!("tickers-{}.parquet", key));
+        if path.exists() {
+            path = dir.join(format!("tickers-{}-{}.parquet", key, now.format("%H%M%S")));
+        }
+        let schema = match parse_message_type(PARQUET_SCHEMA) {
+            Ok(schema) => Arc::new(schema),
+            Err(e) => {
+                warn!("Invalid Parquet schema: {}", e);
+                return None;
+            }
+        };
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_compression(ParquetCompression::SNAPPY)
+                .build(),
+        );
+        let file = match File::create(&path) {
+            Ok(file) => file,
+            Err(e) => {
+                warn!("Could not create {}: {}", path.display(), e);
+                return None;
+            }
+        };
+        match SerializedFileWriter::new(file, schema, props) {
+            Ok(writer) => Some(Sink::Parquet {
+                writer,
+                pending: Vec::with_capacity(PARQUET_ROW_GROUP),
+            }),
+            Err(e) => {
+                warn!("Could not open Parquet writer {}: {}", path.display(), e);
+                None
+            }
+        }
+    }
+
+    /// Delete recorded files past the retention window (0 keeps everything)
+    fn prune(&self) {
+        if self.config.retention_hours == 0 {
+            return;
+        }
+        let cutoff = std::time::SystemTime::now()
+            - std::time::Duration::from_secs(self.config.retention_hours * 3_600);
+        let Ok(entries) = std::fs::read_dir(&self.config.dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let recorded = path
+                .extension()
+                .map(|ext| ext == "gz" || ext == "parquet")
+                .unwrap_or(false);
+            if !recorded {
+                continue;
+            }
+            let expired = entry
+                .metadata()
+                .and_then(|meta| meta.modified())
+                .map(|modified| modified < cutoff)
+                .unwrap_or(false);
+            if expired {
+                match std::fs::remove_file(&path) {
+                    Ok(()) => info!("Pruned recorded market data {}", path.display()),
+                    Err(e) => warn!("Could not prune {}: {}", path.display(), e),
+                }
+            }
+        }
+    }
+}
+
+fn close_sink(sink: Sink) {
+    match sink {
+        Sink::Jsonl(encoder) => {
+            let _ = encoder.finish();
+        }
+        Sink::Parquet {
+            mut writer,
+            mut pending,
+        } => {
+            if !pending.is_empty() {
+                if let Err(e) = write_row_group(&mut writer, &pending) {
+                    warn!("Could not write final Parquet row group: {}", e);
+                }
+                pending.clear();
+            }
+            if let Err(e) = writer.close() {
+                warn!("Could not close Parquet file: {}", e);
+            }
+        }
+    }
+}
+
+/// Write `pending` as one row group, columns in schema order
+fn write_row_group(
+    writer: &mut SerializedFileWriter<File>,
+    pending: &[Ticker],
+) -> parquet::errors::Result<()> {
+    let utf8 = |values: Vec<String>| -> Vec<ByteArray> {
+        values
+            .into_iter()
+            .map(|value| ByteArray::from(value.into_bytes()))
+            .collect()
+    };
+    let double = |values: Vec<f64>| values;
+    let exchanges = utf8(pending.iter().map(|t| t.exchange.to_string()).collect());
+    let pairs = utf8(pending.iter().map(|t| t.pair.to_string()).collect());
+    let bids = double(pending.iter().map(|t| t.bid.to_f64().unwrap_or(0.0)).collect());
+    let asks = double(pending.iter().map(|t| t.ask.to_f64().unwrap_or(0.0)).collect());
+    let lasts = double(pending.iter().map(|t| t.last.to_f64().unwrap_or(0.0)).collect());
+    let volumes = double(
+        pending
+            .iter()
+            .map(|t| t.volume_24h.to_f64().unwrap_or(0.0))
+            .collect(),
+    );
+    let timestamps: Vec<i64> = pending.iter().map(|t| t.timestamp.timestamp_millis()).collect();
+    let sources = utf8(
+        pending
+            .iter()
+            .map(|t| {
+                match t.source {
+                    TickerSource::Ws => "ws",
+                    TickerSource::RestPoll => "rest_poll",
+                }
+                .to_string()
+            })
+            .collect(),
+    );
+
+    let mut group = writer.next_row_group()?;
+    let mut column_index = 0usize;
+    while let Some(mut column) = group.next_column()? {
+        match column_index {
+            0 => {
+                column
+                    .typed::<ByteArrayType>()
+                    .write_batch(&exchanges, None, None)?;
+            }
+            1 => {
+                column
+                    .typed::<ByteArrayType>()
+                    .write_batch(&pairs, None, None)?;
+            }
+            2 => {
+                column.typed::<DoubleType>().write_batch(&bids, None, None)?;
+            }
+            3 => {
+                column.typed::<DoubleType>().write_batch(&asks, None, None)?;
+            }
+            4 => {
+                column.typed::<DoubleType>().write_batch(&lasts, None, None)?;
+            }
+            5 => {
+                column
+                    .typed::<DoubleType>()
+                    .write_batch(&volumes, None, None)?;
+            }
+            6 => {
+                column
+                    .typed::<Int64Type>()
+                    .write_batch(&timestamps, None, None)?;
+            }
+            7 => {
+                column
+                    .typed::<ByteArrayType>()
+                    .write_batch(&sources, None, None)?;
+            }
+            _ => {}
+        }
+        column.close()?;
+        column_index += 1;
+    }
+    group.close()?;
+    Ok(())
+}
+
+/// Append tickers to the recorder-format JSONL file for their own hour,
+/// grouped by timestamp (the live path above groups by wall clock instead,
+/// since it writes as data arrives). Each call appends one gzip member per
+/// hour touched. Used by the historical downloader; `tickers` should
+/// already be chronological so each hour's file is opened once.
+pub fn append_tickers(dir: &str, tickers: &[Ticker]) -> std::io::Result<usize> {
+    let mut current: Option<(String, GzEncoder<File>)> = None;
+    let mut written = 0usize;
+
+    for ticker in tickers {
+        let key = ticker.timestamp.format("%Y%m%d-%H").to_string();
+        if current.as_ref().map(|(k, _)| k != &key).unwrap_or(true) {
+            if let Some((_, encoder)) = current.take() {
+                encoder.finish()?;
+            }
+            let path = Path::new(dir).join(format!("tickers-{}.jsonl.gz", key));
+            let file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+            current = Some((key, GzEncoder::new(file, Compression::default())));
+        }
+        let Some((_, encoder)) = current.as_mut() else {
+            continue;
+        };
+        writeln!(encoder, "{}", serde_json::to_string(ticker)?)?;
+        written += 1;
+    }
+    if let Some((_, encoder)) = current.take() {
+        encoder.finish()?;
+    }
+    Ok(written)
+}
+
+/// Load every ticker recorded at `path` — either one capture file or a
+/// directory of them — sorted chronologically. Gzip files may hold several
+/// members (one per recorder restart within the hour); plain `.jsonl` is
+/// accepted too. Unparseable lines are skipped with a warning so one
+/// corrupt record doesn't sink a whole replay.
+pub fn load_tickers(path: &str) -> std::io::Result<Vec<Ticker>> {
+    let root = Path::new(path);
+    let mut files = Vec::new();
+    if root.is_dir() {
+        for entry in std::fs::read_dir(root)?.flatten() {
+            let file = entry.path();
+            let name = file.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            if name.ends_with(".jsonl.gz") || name.ends_with(".jsonl") {
+                files.push(file);
+            }
+        }
+        files.sort();
+    } else {
+        files.push(root.to_path_buf());
+    }
+
+    let mut tickers = Vec::new();
+    for file in &files {
+        let raw = std::fs::read(file)?;
+        let text = if file.extension().map(|ext| ext == "gz") == Some(true) {
+            let mut decoded = String::new();
+            let mut decoder = flate2::read::MultiGzDecoder::new(raw.as_slice());
+            std::io::Read::read_to_string(&mut decoder, &mut decoded)?;
+            decoded
+        } else {
+            String::from_utf8_lossy(&raw).into_owned()
+        };
+        let mut skipped = 0usize;
+        for line in text.lines().filter(|line| !line.trim().is_empty()) {
+            match serde_json::from_str::<Ticker>(line) {
+                Ok(ticker) => tickers.push(ticker),
+                Err(_) => skipped += 1,
+            }
+        }
+        if skipped > 0 {
+            warn!("{}: skipped {} unparseable lines", file.display(), skipped);
+        }
+    }
+    tickers.sort_by_key(|t| t.timestamp);
+    Ok(tickers)
+}
@@ -0,0 +1,145 @@
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::config::ReferenceConfig;
+
+/// One cached reference price as exposed via GET /api/reference/prices
+#[derive(Debug, Clone, Serialize)]
+pub struct ReferencePriceInfo {
+    pub asset: String,
+    /// USD per one unit of the asset
+    pub usd_price: Decimal,
+    pub as_of: Option<DateTime<Utc>>,
+    pub stale: bool,
+}
+
+/// Staleness-checked external reference prices (an independent index feed),
+/// used to sanity-check exchange quotes. A mis-parsed exchange field can
+/// produce an absurd "spread" that every internal consistency check passes —
+/// only an outside reference catches it.
+pub struct ReferencePriceCache {
+    config: ReferenceConfig,
+    client: reqwest::Client,
+    /// Base asset (uppercase) → USD price
+    prices: DashMap<String, Decimal>,
+    /// Millisecond timestamp of the last successful refresh (0 = never)
+    last_refresh_ms: AtomicI64,
+}
+
+impl ReferencePriceCache {
+    pub fn from_config(config: &ReferenceConfig) -> Self {
+        Self {
+            config: config.clone(),
+            client: reqwest::Client::new(),
+            prices: DashMap::new(),
+            last_refresh_ms: AtomicI64::new(0),
+        }
+    }
+
+    /// Refresh loop — fetches immediately, then every `refresh_secs`
+    /// (no-op unless `reference.enabled`)
+    pub async fn start(&self) {
+        if !self.config.enabled || self.config.ids.is_empty() {
+            return;
+        }
+        let refresh_secs = self.config.refresh_secs.max(30);
+        info!(
+            "Reference price cache started (provider={}, {} assets, refresh every {}s)",
+            self.config.provider,
+            self.config.ids.len(),
+            refresh_secs
+        );
+        loop {
+            match self.fetch().await {
+                Ok(()) => {
+                    self.last_refresh_ms
+                        .store(Utc::now().timestamp_millis(), Ordering::Relaxed);
+                }
+                Err(e) => warn!(
+                    "Reference price refresh failed ({}): {}",
+                    self.config.provider, e
+                ),
+            }
+            tokio::time::sleep(Duration::from_secs(refresh_secs)).await;
+        }
+    }
+
+    /// Fetch USD prices for every configured asset from CoinGecko's
+    /// simple-price endpoint (the only provider currently wired up)
+    async fn fetch(&self) -> Result<(), String> {
+        let ids: Vec<&str> = self.config.ids.values().map(String::as_str).collect();
+        let url = format!(
+            "https://api.coingecko.com/api/v3/simple/price?ids={}&vs_currencies=usd",
+            ids.join(",")
+        );
+
+        let data: serde_json::Value = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| e.to_string())?
+            .json()
+            .await
+            .map_err(|e| e.to_string())?;
+
+        let mut updated = 0;
+        for (asset, id) in &self.config.ids {
+            if let Some(price) = data[id]["usd"]
+                .as_f64()
+                .and_then(Decimal::from_f64_retain)
+                .filter(|p| *p > Decimal::ZERO)
+            {
+                self.prices.insert(asset.to_uppercase(), price);
+                updated += 1;
+            }
+        }
+        if updated == 0 {
+            return Err(format!("provider returned no usable prices: {}", data));
+        }
+        Ok(())
+    }
+
+    fn is_stale(&self) -> bool {
+        let last = self.last_refresh_ms.load(Ordering::Relaxed);
+        last == 0
+            || Utc::now().timestamp_millis() - last > (self.config.max_age_secs * 1000) as i64
+    }
+
+    /// USD reference price for a base asset, or None when the feed is
+    /// disabled, the asset is unmapped, or the cache has gone stale
+    pub fn usd_price(&self, asset: &str) -> Option<Decimal> {
+        if !self.config.enabled || self.is_stale() {
+            return None;
+        }
+        self.prices.get(&asset.to_uppercase()).map(|p| *p)
+    }
+
+    /// Deviation of an exchange quote from the reference, in percent, or
+    /// None when no reference is available for the asset
+    pub fn deviation_pct(&self, asset: &str, price: Decimal) -> Option<Decimal> {
+        let reference = self.usd_price(asset).filter(|p| *p > Decimal::ZERO)?;
+        Some((price - reference).abs() / reference * Decimal::from(100))
+    }
+
+    /// Snapshot of all cached reference prices with staleness, for the API
+    pub fn snapshot(&self) -> Vec<ReferencePriceInfo> {
+        let last = self.last_refresh_ms.load(Ordering::Relaxed);
+        let as_of = (last > 0).then(|| DateTime::from_timestamp_millis(last).unwrap_or_default());
+        let stale = self.is_stale();
+        self.prices
+            .iter()
+            .map(|entry| ReferencePriceInfo {
+                asset: entry.key().clone(),
+                usd_price: *entry.value(),
+                as_of,
+                stale,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,71 @@
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::arbitrage::ArbitrageDetector;
+use crate::recorder;
+
+/// Longest gap honored between two paced tickers — recorded captures can
+/// span feed outages, and replaying those minutes in real time helps nobody
+const MAX_PACED_GAP_MS: i64 = 5_000;
+
+/// Replays a recorded capture through the live detector in place of the
+/// WebSocket feeds, so a production incident can be re-run bit-for-bit
+/// through detection and (simulated) execution for debugging.
+///
+/// Paced mode sleeps out the recorded inter-ticker gaps so time-sensitive
+/// behavior (cooldowns, staleness filters, persistence windows) fires as
+/// it did live; unpaced mode replays as fast as possible.
+pub struct Replayer {
+    detector: Arc<ArbitrageDetector>,
+    paced: bool,
+}
+
+impl Replayer {
+    pub fn new(detector: Arc<ArbitrageDetector>, paced: bool) -> Self {
+        Self { detector, paced }
+    }
+
+    /// Feed the capture at `path` (a recorded file or the recorder's
+    /// directory) through the detector, then return
+    pub async fn run(&self, path: &str) {
+        let tickers = match recorder::load_tickers(path) {
+            Ok(tickers) => tickers,
+            Err(e) => {
+                warn!("Replay aborted: could not load {}: {}", path, e);
+                return;
+            }
+        };
+        if tickers.is_empty() {
+            warn!("Replay aborted: {} contains no tickers", path);
+            return;
+        }
+        info!(
+            "Replaying {} tickers from {} ({} — {}, {})",
+            tickers.len(),
+            path,
+            tickers[0].timestamp,
+            tickers[tickers.len() - 1].timestamp,
+            if self.paced { "paced" } else { "as fast as possible" }
+        );
+
+        let mut previous = tickers[0].timestamp;
+        for ticker in &tickers {
+            if self.paced {
+                let gap = (ticker.timestamp - previous)
+                    .num_milliseconds()
+                    .clamp(0, MAX_PACED_GAP_MS);
+                if gap > 0 {
+                    tokio::time::sleep(std::time::Duration::from_millis(gap as u64)).await;
+                }
+                previous = ticker.timestamp;
+            }
+            self.detector.ingest_ticker(ticker).await;
+        }
+        let span = tickers[tickers.len() - 1].timestamp - tickers[0].timestamp;
+        info!(
+            "Replay complete: {} tickers covering {}m of recorded time",
+            tickers.len(),
+            span.num_minutes().max(0)
+        );
+    }
+}
@@ -0,0 +1,197 @@
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+use crate::types::Exchange;
+
+/// Latency samples kept per venue and per metric
+const SLA_WINDOW: usize = 500;
+
+/// REST order-ack p95 above which a venue starts losing score, ms
+const REST_TARGET_MS: f64 = 500.0;
+
+/// WS inter-tick gap p95 above which a venue starts losing score, ms
+const WS_TARGET_MS: f64 = 1_000.0;
+
+/// Rolling latency stats for one REST endpoint on one venue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndpointLatency {
+    /// Endpoint name as the connector calls it, e.g. "get_ticker"
+    pub endpoint: String,
+    pub p50_ms: Option<f64>,
+    pub p95_ms: Option<f64>,
+    pub samples: usize,
+}
+
+/// Rolling SLA report for one venue, as exposed via GET /api/sla
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VenueSlaReport {
+    pub exchange: Exchange,
+    pub rest_ack_p50_ms: Option<f64>,
+    pub rest_ack_p95_ms: Option<f64>,
+    pub ws_gap_p50_ms: Option<f64>,
+    pub ws_gap_p95_ms: Option<f64>,
+    /// WS message lag: exchange-stamped event time vs local receive time.
+    /// Includes clock skew between us and the venue, which is part of what
+    /// a cross-venue strategy actually experiences.
+    pub ws_lag_p50_ms: Option<f64>,
+    pub ws_lag_p95_ms: Option<f64>,
+    /// REST round-trip times broken out per endpoint
+    pub rest_endpoints: Vec<EndpointLatency>,
+    /// Composite health score in [0, 1]; 1 = meeting both targets
+    pub score: f64,
+    pub rest_samples: usize,
+    pub ws_samples: usize,
+}
+
+/// Rolling response-time tracking per venue: REST order-ack times from the
+/// executor and WS inter-tick gaps from the detector. The composite score
+/// feeds opportunity confidence so chronically slow venues get
+/// deprioritized without manual intervention.
+#[derive(Default)]
+pub struct VenueSla {
+    rest_ack_ms: DashMap<Exchange, VecDeque<f64>>,
+    ws_gap_ms: DashMap<Exchange, VecDeque<f64>>,
+    ws_lag_ms: DashMap<Exchange, VecDeque<f64>>,
+    rest_rtt_ms: DashMap<(Exchange, String), VecDeque<f64>>,
+}
+
+impl VenueSla {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record how long a venue took to acknowledge an order
+    pub fn record_rest_ack(&self, exchange: Exchange, ms: f64) {
+        Self::push(&self.rest_ack_ms, exchange, ms);
+    }
+
+    /// Record the gap between two consecutive WS ticks from a venue
+    pub fn record_ws_gap(&self, exchange: Exchange, ms: f64) {
+        Self::push(&self.ws_gap_ms, exchange, ms);
+    }
+
+    /// Record how far behind local receipt a venue's exchange-stamped WS
+    /// message arrived
+    pub fn record_ws_lag(&self, exchange: Exchange, ms: f64) {
+        Self::push(&self.ws_lag_ms, exchange, ms);
+    }
+
+    /// Record one successful REST round trip for a named endpoint
+    pub fn record_rest_rtt(&self, exchange: Exchange, endpoint: &str, ms: f64) {
+        if !ms.is_finite() || ms < 0.0 {
+            return;
+        }
+        let mut window = self
+            .rest_rtt_ms
+            .entry((exchange, endpoint.to_string()))
+            .or_default();
+        window.push_back(ms);
+        while window.len() > SLA_WINDOW {
+            window.pop_front();
+        }
+    }
+
+    fn push(map: &DashMap<Exchange, VecDeque<f64>>, exchange: Exchange, ms: f64) {
+        if !ms.is_finite() || ms < 0.0 {
+            return;
+        }
+        let mut window = map.entry(exchange).or_default();
+        window.push_back(ms);
+        while window.len() > SLA_WINDOW {
+            window.pop_front();
+        }
+    }
+
+    /// Composite health score in [0, 1] — the product of how well the
+    /// venue's p95s meet the REST and WS targets. Metrics without samples
+    /// score 1 so a fresh venue starts unpenalized.
+    pub fn score(&self, exchange: Exchange) -> f64 {
+        let rest = Self::target_component(&self.rest_ack_ms, exchange, REST_TARGET_MS);
+        let ws = Self::target_component(&self.ws_gap_ms, exchange, WS_TARGET_MS);
+        rest * ws
+    }
+
+    fn target_component(
+        map: &DashMap<Exchange, VecDeque<f64>>,
+        exchange: Exchange,
+        target_ms: f64,
+    ) -> f64 {
+        map.get(&exchange)
+            .and_then(|window| Self::percentile(&window, 0.95))
+            .map(|p95| (target_ms / p95.max(target_ms)).clamp(0.0, 1.0))
+            .unwrap_or(1.0)
+    }
+
+    fn percentile(window: &VecDeque<f64>, p: f64) -> Option<f64> {
+        if window.is_empty() {
+            return None;
+        }
+        let mut sorted: Vec<f64> = window.iter().copied().collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let index = ((sorted.len() - 1) as f64 * p).round() as usize;
+        sorted.get(index).copied()
+    }
+
+    /// Per-venue SLA reports, for the API
+    pub fn snapshot(&self) -> Vec<VenueSlaReport> {
+        [Exchange::Bybit, Exchange::Bitget]
+            .into_iter()
+            .map(|exchange| {
+                let (rest_p50, rest_p95, rest_samples) = self
+                    .rest_ack_ms
+                    .get(&exchange)
+                    .map(|w| {
+                        (
+                            Self::percentile(&w, 0.5),
+                            Self::percentile(&w, 0.95),
+                            w.len(),
+                        )
+                    })
+                    .unwrap_or((None, None, 0));
+                let (ws_p50, ws_p95, ws_samples) = self
+                    .ws_gap_ms
+                    .get(&exchange)
+                    .map(|w| {
+                        (
+                            Self::percentile(&w, 0.5),
+                            Self::percentile(&w, 0.95),
+                            w.len(),
+                        )
+                    })
+                    .unwrap_or((None, None, 0));
+                let (lag_p50, lag_p95) = self
+                    .ws_lag_ms
+                    .get(&exchange)
+                    .map(|w| (Self::percentile(&w, 0.5), Self::percentile(&w, 0.95)))
+                    .unwrap_or((None, None));
+                let mut rest_endpoints: Vec<EndpointLatency> = self
+                    .rest_rtt_ms
+                    .iter()
+                    .filter(|entry| entry.key().0 == exchange)
+                    .map(|entry| EndpointLatency {
+                        endpoint: entry.key().1.clone(),
+                        p50_ms: Self::percentile(entry.value(), 0.5),
+                        p95_ms: Self::percentile(entry.value(), 0.95),
+                        samples: entry.value().len(),
+                    })
+                    .collect();
+                rest_endpoints.sort_by(|a, b| a.endpoint.cmp(&b.endpoint));
+
+                VenueSlaReport {
+                    exchange,
+                    rest_ack_p50_ms: rest_p50,
+                    rest_ack_p95_ms: rest_p95,
+                    ws_gap_p50_ms: ws_p50,
+                    ws_gap_p95_ms: ws_p95,
+                    ws_lag_p50_ms: lag_p50,
+                    ws_lag_p95_ms: lag_p95,
+                    rest_endpoints,
+                    score: self.score(exchange),
+                    rest_samples,
+                    ws_samples,
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,78 @@
+use tracing::warn;
+
+use crate::config::SlackConfig;
+use crate::notify::{Notification, Severity};
+
+/// Slack incoming-webhook sink for the notification dispatcher, with
+/// per-kind message templates and a severity floor.
+///
+/// Templates support the placeholders `{title}`, `{detail}`, `{kind}` and
+/// `{severity}`; the default renders `*title*` on one line and the detail
+/// under it (Slack mrkdwn).
+pub struct SlackNotifier {
+    config: SlackConfig,
+    client: reqwest::Client,
+}
+
+impl SlackNotifier {
+    pub fn from_config(config: &SlackConfig) -> Self {
+        Self {
+            config: config.clone(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.config.enabled && !self.config.webhook_url.is_empty()
+    }
+
+    /// Post one notification; severities below the configured floor are
+    /// dropped, failures are logged and dropped
+    pub async fn send(&self, notification: &Notification) {
+        if !self.enabled() {
+            return;
+        }
+        if notification.kind.severity() < self.min_severity() {
+            return;
+        }
+        let text = self.render(notification);
+        let result = self
+            .client
+            .post(&self.config.webhook_url)
+            .json(&serde_json::json!({ "text": text }))
+            .send()
+            .await;
+        match result {
+            Ok(response) if !response.status().is_success() => {
+                warn!("Slack webhook returned {}", response.status());
+            }
+            Err(e) => warn!("Slack webhook failed: {}", e),
+            _ => {}
+        }
+    }
+
+    fn min_severity(&self) -> Severity {
+        match self.config.min_severity.as_str() {
+            "critical" => Severity::Critical,
+            "warning" => Severity::Warning,
+            _ => Severity::Info,
+        }
+    }
+
+    /// The configured template for this notification's kind (keyed by the
+    /// kind's snake_case name), or the default layout
+    fn render(&self, notification: &Notification) -> String {
+        let kind = notification.kind.name();
+        let template = self
+            .config
+            .templates
+            .get(kind)
+            .map(String::as_str)
+            .unwrap_or("*{title}*\n{detail}");
+        template
+            .replace("{title}", &notification.title)
+            .replace("{detail}", &notification.detail)
+            .replace("{kind}", kind)
+            .replace("{severity}", notification.kind.severity().name())
+    }
+}
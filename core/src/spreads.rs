@@ -0,0 +1,99 @@
+use chrono::Utc;
+use dashmap::DashMap;
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// Samples kept per pair (a few hours at typical tick rates)
+const SPREAD_SERIES_CAP: usize = 20_000;
+
+/// One spread observation, as exposed via GET /api/spreads/{pair}
+#[derive(Debug, Clone, Serialize)]
+pub struct SpreadPoint {
+    /// Epoch milliseconds of the observation
+    pub ts_ms: i64,
+    /// Gross cross-exchange spread, percent
+    pub spread_pct: f64,
+}
+
+/// Continuous per-pair ring buffer of the computed cross-exchange spread —
+/// below-threshold ticks included — so the dashboard can chart spread
+/// behavior over time rather than only seeing the opportunities that fired
+#[derive(Default)]
+pub struct SpreadRecorder {
+    series: DashMap<String, VecDeque<SpreadPoint>>,
+}
+
+impl SpreadRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one spread observation for a pair
+    pub fn record(&self, pair: &str, spread_pct: f64) {
+        if !spread_pct.is_finite() {
+            return;
+        }
+        let mut series = self.series.entry(pair.to_string()).or_default();
+        series.push_back(SpreadPoint {
+            ts_ms: Utc::now().timestamp_millis(),
+            spread_pct,
+        });
+        while series.len() > SPREAD_SERIES_CAP {
+            series.pop_front();
+        }
+    }
+
+    /// Points for a pair within the trailing window, downsampled to at
+    /// most `max_points` by averaging fixed time buckets
+    pub fn query(&self, pair: &str, window_ms: i64, max_points: usize) -> Vec<SpreadPoint> {
+        let cutoff = Utc::now().timestamp_millis() - window_ms.max(1);
+        let Some(series) = self.series.get(pair) else {
+            return Vec::new();
+        };
+        let recent: Vec<SpreadPoint> = series
+            .iter()
+            .filter(|p| p.ts_ms >= cutoff)
+            .cloned()
+            .collect();
+        let max_points = max_points.max(1);
+        if recent.len() <= max_points {
+            return recent;
+        }
+
+        // Average each fixed-width time bucket into one point
+        let bucket_ms = (window_ms / max_points as i64).max(1);
+        let mut out: Vec<SpreadPoint> = Vec::with_capacity(max_points);
+        let mut bucket = i64::MIN;
+        let mut ts_sum: i64 = 0;
+        let mut spread_sum = 0.0;
+        let mut count: usize = 0;
+        for point in &recent {
+            let point_bucket = (point.ts_ms - cutoff) / bucket_ms;
+            if point_bucket != bucket && count > 0 {
+                out.push(SpreadPoint {
+                    ts_ms: ts_sum / count as i64,
+                    spread_pct: spread_sum / count as f64,
+                });
+                ts_sum = 0;
+                spread_sum = 0.0;
+                count = 0;
+            }
+            bucket = point_bucket;
+            ts_sum += point.ts_ms;
+            spread_sum += point.spread_pct;
+            count += 1;
+        }
+        if count > 0 {
+            out.push(SpreadPoint {
+                ts_ms: ts_sum / count as i64,
+                spread_pct: spread_sum / count as f64,
+            });
+        }
+        out
+    }
+
+    /// Pairs with recorded samples
+    pub fn pairs(&self) -> Vec<String> {
+        self.series.iter().map(|e| e.key().clone()).collect()
+    }
+}
@@ -0,0 +1,170 @@
+use chrono::{DateTime, Utc};
+use rusqlite::Connection;
+use std::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::config::StoreConfig;
+use crate::types::{ArbitrageOpportunity, TradeResult};
+
+/// Optional SQLite persistence for trades and opportunities, so history
+/// survives restarts and `/api/trades` / `/api/opportunities` can answer
+/// queries older than the in-memory windows. Rows keep the full record as
+/// JSON next to the indexed columns, so the schema never chases the
+/// struct definitions.
+///
+/// Writes happen inline on the recording path — single-row inserts into a
+/// local SQLite file are far quicker than the venue round-trip that
+/// precedes every trade.
+pub struct SqliteStore {
+    conn: Option<Mutex<Connection>>,
+}
+
+impl SqliteStore {
+    pub fn from_config(config: &StoreConfig) -> Self {
+        if !config.enabled {
+            return Self { conn: None };
+        }
+        match Self::open(&config.path) {
+            Ok(conn) => {
+                info!("SQLite store opened at {}", config.path);
+                Self {
+                    conn: Some(Mutex::new(conn)),
+                }
+            }
+            Err(e) => {
+                warn!("SQLite store disabled: could not open {}: {}", config.path, e);
+                Self { conn: None }
+            }
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.conn.is_some()
+    }
+
+    fn open(path: &str) -> rusqlite::Result<Connection> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS trades (
+                 id TEXT PRIMARY KEY,
+                 pair TEXT NOT NULL,
+                 executed_at_ms INTEGER NOT NULL,
+                 json TEXT NOT NULL
+             );
+             CREATE INDEX IF NOT EXISTS idx_trades_executed_at
+                 ON trades (executed_at_ms);
+             CREATE TABLE IF NOT EXISTS opportunities (
+                 id TEXT PRIMARY KEY,
+                 pair TEXT NOT NULL,
+                 detected_at_ms INTEGER NOT NULL,
+                 json TEXT NOT NULL
+             );
+             CREATE INDEX IF NOT EXISTS idx_opportunities_detected_at
+                 ON opportunities (detected_at_ms);",
+        )?;
+        Ok(conn)
+    }
+
+    /// Persist one trade; failures are logged, never surfaced — losing a
+    /// history row must not fail the trade path
+    pub fn record_trade(&self, trade: &TradeResult) {
+        let Some(conn) = &self.conn else { return };
+        let Ok(json) = serde_json::to_string(trade) else {
+            return;
+        };
+        let result = conn.lock().unwrap().execute(
+            "INSERT OR REPLACE INTO trades (id, pair, executed_at_ms, json)
+             VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![
+                trade.id,
+                trade.pair.to_string(),
+                trade.executed_at.timestamp_millis(),
+                json
+            ],
+        );
+        if let Err(e) = result {
+            warn!("Could not persist trade {}: {}", trade.id, e);
+        }
+    }
+
+    /// Persist one opportunity
+    pub fn record_opportunity(&self, opp: &ArbitrageOpportunity) {
+        let Some(conn) = &self.conn else { return };
+        let Ok(json) = serde_json::to_string(opp) else {
+            return;
+        };
+        let result = conn.lock().unwrap().execute(
+            "INSERT OR REPLACE INTO opportunities (id, pair, detected_at_ms, json)
+             VALUES (?1, ?2, ?3, ?4)",
+            rusqlite::params![
+                opp.id,
+                opp.pair.to_string(),
+                opp.detected_at.timestamp_millis(),
+                json
+            ],
+        );
+        if let Err(e) = result {
+            warn!("Could not persist opportunity {}: {}", opp.id, e);
+        }
+    }
+
+    /// Trades executed at or after `since`, oldest first, optionally
+    /// restricted to one pair
+    pub fn trades_since(
+        &self,
+        since: DateTime<Utc>,
+        pair: Option<&str>,
+        limit: usize,
+    ) -> Vec<TradeResult> {
+        self.query_since("trades", "executed_at_ms", since, pair, limit)
+    }
+
+    /// Opportunities detected at or after `since`, oldest first
+    pub fn opportunities_since(
+        &self,
+        since: DateTime<Utc>,
+        pair: Option<&str>,
+        limit: usize,
+    ) -> Vec<ArbitrageOpportunity> {
+        self.query_since("opportunities", "detected_at_ms", since, pair, limit)
+    }
+
+    fn query_since<T: serde::de::DeserializeOwned>(
+        &self,
+        table: &str,
+        ts_column: &str,
+        since: DateTime<Utc>,
+        pair: Option<&str>,
+        limit: usize,
+    ) -> Vec<T> {
+        let Some(conn) = &self.conn else {
+            return Vec::new();
+        };
+        let sql = format!(
+            "SELECT json FROM {table} WHERE {ts_column} >= ?1 AND pair LIKE ?2
+             ORDER BY {ts_column} ASC LIMIT ?3"
+        );
+        let conn = conn.lock().unwrap();
+        let rows = conn.prepare(&sql).and_then(|mut stmt| {
+            stmt.query_map(
+                rusqlite::params![
+                    since.timestamp_millis(),
+                    pair.unwrap_or("%"),
+                    limit as i64
+                ],
+                |row| row.get::<_, String>(0),
+            )
+            .map(|mapped| mapped.filter_map(|r| r.ok()).collect::<Vec<String>>())
+        });
+        match rows {
+            Ok(rows) => rows
+                .iter()
+                .filter_map(|json| serde_json::from_str(json).ok())
+                .collect(),
+            Err(e) => {
+                warn!("Store query on {} failed: {}", table, e);
+                Vec::new()
+            }
+        }
+    }
+}
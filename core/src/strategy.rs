@@ -0,0 +1,341 @@
+use dashmap::DashMap;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_decimal_macros::dec;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::exchange::ExchangeConnector;
+use crate::prices::PriceCache;
+use crate::types::*;
+
+/// A detection strategy. The detector feeds every ticker update through
+/// each registered strategy; whatever candidates come back are depth-sized
+/// and forwarded to the executor. Strategies are registered by name via
+/// `engine.strategies` in config, so cross-exchange, triangular and
+/// statistical variants can coexist without forking the detector.
+pub trait Strategy: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// React to one ticker update with zero or more candidate opportunities
+    fn on_ticker(&self, ticker: &Ticker, prices: &PriceCache) -> Vec<ArbitrageOpportunity>;
+}
+
+/// Build the strategies named in `engine.strategies`, warning on unknowns
+pub fn build_strategies(
+    config: &Config,
+    connectors: &[Arc<dyn ExchangeConnector>],
+) -> Vec<Arc<dyn Strategy>> {
+    let mut strategies: Vec<Arc<dyn Strategy>> = Vec::new();
+    for name in &config.engine.strategies {
+        match name.as_str() {
+            "cross_exchange" => strategies.push(Arc::new(CrossExchangeStrategy::new(
+                connectors.to_vec(),
+                config.clone(),
+            ))),
+            "zscore" => strategies.push(Arc::new(ZScoreStrategy::new(
+                connectors.to_vec(),
+                config.clone(),
+            ))),
+            other => warn!("Unknown strategy '{}' in engine.strategies — skipping", other),
+        }
+    }
+    strategies
+}
+
+/// The original detection logic: compare the incoming ticker against every
+/// other exchange's latest quote for the same pair, in both directions
+pub struct CrossExchangeStrategy {
+    connectors: Vec<Arc<dyn ExchangeConnector>>,
+    config: Config,
+}
+
+impl CrossExchangeStrategy {
+    pub fn new(connectors: Vec<Arc<dyn ExchangeConnector>>, config: Config) -> Self {
+        Self { connectors, config }
+    }
+
+    /// Evaluate a specific buy/sell direction for profitability
+    fn evaluate_spread(
+        &self,
+        buy_ticker: &Ticker,  // We buy at the ask price here
+        sell_ticker: &Ticker, // We sell at the bid price here
+    ) -> Option<ArbitrageOpportunity> {
+        let buy_price = buy_ticker.ask;
+        let sell_price = sell_ticker.bid;
+
+        if buy_price <= Decimal::ZERO || sell_price <= Decimal::ZERO {
+            return None;
+        }
+
+        // Gross spread percentage
+        let spread_pct = ((sell_price - buy_price) / buy_price) * dec!(100);
+
+        // Get fees for both exchanges
+        let buy_fee = self
+            .connectors
+            .iter()
+            .find(|c| c.exchange() == buy_ticker.exchange)
+            .map(|c| c.fee_pct())
+            .unwrap_or(dec!(0.1));
+
+        let sell_fee = self
+            .connectors
+            .iter()
+            .find(|c| c.exchange() == sell_ticker.exchange)
+            .map(|c| c.fee_pct())
+            .unwrap_or(dec!(0.1));
+
+        // Net spread after fees on both sides
+        let total_fees = buy_fee + sell_fee;
+        let net_spread_pct = spread_pct - total_fees;
+
+        // Only surface candidates that clear fees — the configurable
+        // threshold itself is applied by the "min_spread" stage of the
+        // filter pipeline
+        if net_spread_pct <= Decimal::ZERO {
+            return None;
+        }
+
+        let quantity = self.config.max_trade_qty_for(&buy_ticker.pair);
+        let potential_profit = quantity * (sell_price - buy_price)
+            - quantity * buy_price * (buy_fee / dec!(100))
+            - quantity * sell_price * (sell_fee / dec!(100));
+
+        let opportunity = ArbitrageOpportunity {
+            id: Uuid::new_v4().to_string(),
+            strategy: String::new(),
+            pair: buy_ticker.pair.clone(),
+            buy_exchange: buy_ticker.exchange,
+            sell_exchange: sell_ticker.exchange,
+            buy_price,
+            sell_price,
+            spread_pct,
+            net_spread_pct,
+            confidence: dec!(1),
+            potential_profit,
+            potential_profit_reporting: None,
+            quantity,
+            context: serde_json::Map::new(),
+            size_constraints: None,
+            detected_at: chrono::Utc::now(),
+            is_actionable: net_spread_pct > dec!(0),
+        };
+
+        debug!(
+            "Opportunity: Buy {} @ {} on {}, Sell @ {} on {} | Spread: {}% (net: {}%)",
+            opportunity.pair,
+            buy_price,
+            buy_ticker.exchange,
+            sell_price,
+            sell_ticker.exchange,
+            spread_pct.round_dp(4),
+            net_spread_pct.round_dp(4),
+        );
+
+        Some(opportunity)
+    }
+}
+
+impl Strategy for CrossExchangeStrategy {
+    fn name(&self) -> &'static str {
+        "cross_exchange"
+    }
+
+    fn on_ticker(&self, ticker: &Ticker, prices: &PriceCache) -> Vec<ArbitrageOpportunity> {
+        let pair_str = ticker.pair.to_string();
+        let exchanges = [Exchange::Bybit, Exchange::Bitget];
+        let mut opportunities = Vec::new();
+
+        for other_exchange in &exchanges {
+            if *other_exchange == ticker.exchange {
+                continue;
+            }
+
+            if let Some(other_ticker) = prices.get(*other_exchange, &pair_str) {
+                // Direction 1: Buy on incoming exchange, sell on other
+                if let Some(opp) = self.evaluate_spread(ticker, &other_ticker) {
+                    opportunities.push(opp);
+                }
+                // Direction 2: Buy on other exchange, sell on incoming
+                if let Some(opp) = self.evaluate_spread(&other_ticker, ticker) {
+                    opportunities.push(opp);
+                }
+            }
+        }
+
+        opportunities
+    }
+}
+
+/// Rolling spread statistics for one (pair, direction)
+#[derive(Debug, Default)]
+struct SpreadStats {
+    window: VecDeque<f64>,
+    /// True after an entry signal until the spread mean-reverts below
+    /// `exit_z` — stops the strategy re-firing on every tick of a wide
+    /// spread
+    signaled: bool,
+}
+
+impl SpreadStats {
+    fn push(&mut self, sample: f64, window: usize) {
+        self.window.push_back(sample);
+        while self.window.len() > window.max(2) {
+            self.window.pop_front();
+        }
+    }
+
+    fn mean_std(&self) -> (f64, f64) {
+        let n = self.window.len() as f64;
+        let mean = self.window.iter().sum::<f64>() / n;
+        let var = self.window.iter().map(|s| (s - mean) * (s - mean)).sum::<f64>() / n;
+        (mean, var.sqrt())
+    }
+}
+
+/// Statistical spread strategy: tracks a rolling mean/stddev of the
+/// cross-exchange spread per pair and direction, signaling only when the
+/// spread deviates by a configurable number of standard deviations. Fixed
+/// `min_spread_pct` thresholds are too blunt for pairs whose baseline
+/// spread varies; this adapts to each pair's own baseline.
+pub struct ZScoreStrategy {
+    connectors: Vec<Arc<dyn ExchangeConnector>>,
+    config: Config,
+    stats: DashMap<String, SpreadStats>,
+}
+
+impl ZScoreStrategy {
+    pub fn new(connectors: Vec<Arc<dyn ExchangeConnector>>, config: Config) -> Self {
+        Self {
+            connectors,
+            config,
+            stats: DashMap::new(),
+        }
+    }
+
+    fn evaluate_direction(
+        &self,
+        buy_ticker: &Ticker,
+        sell_ticker: &Ticker,
+    ) -> Option<ArbitrageOpportunity> {
+        let buy_price = buy_ticker.ask;
+        let sell_price = sell_ticker.bid;
+        if buy_price <= Decimal::ZERO || sell_price <= Decimal::ZERO {
+            return None;
+        }
+
+        let spread_pct = ((sell_price - buy_price) / buy_price) * dec!(100);
+        let sample = spread_pct.to_f64()?;
+        let zcfg = &self.config.zscore;
+
+        let key = format!(
+            "{}|{}->{}",
+            buy_ticker.pair, buy_ticker.exchange, sell_ticker.exchange
+        );
+        let mut stats = self.stats.entry(key).or_default();
+        stats.push(sample, zcfg.window);
+        if stats.window.len() < zcfg.min_samples.max(2) {
+            return None;
+        }
+
+        let (mean, std) = stats.mean_std();
+        if std <= f64::EPSILON {
+            return None;
+        }
+        let z = (sample - mean) / std;
+
+        // Hysteresis: re-arm only once the spread has mean-reverted
+        if stats.signaled {
+            if z <= zcfg.exit_z {
+                stats.signaled = false;
+            }
+            return None;
+        }
+        if z < zcfg.entry_z {
+            return None;
+        }
+        stats.signaled = true;
+        drop(stats);
+
+        let buy_fee = self
+            .connectors
+            .iter()
+            .find(|c| c.exchange() == buy_ticker.exchange)
+            .map(|c| c.fee_pct())
+            .unwrap_or(dec!(0.1));
+        let sell_fee = self
+            .connectors
+            .iter()
+            .find(|c| c.exchange() == sell_ticker.exchange)
+            .map(|c| c.fee_pct())
+            .unwrap_or(dec!(0.1));
+        let net_spread_pct = spread_pct - buy_fee - sell_fee;
+
+        let quantity = self.config.max_trade_qty_for(&buy_ticker.pair);
+        let potential_profit = quantity * (sell_price - buy_price)
+            - quantity * buy_price * (buy_fee / dec!(100))
+            - quantity * sell_price * (sell_fee / dec!(100));
+
+        debug!(
+            "Z-score entry: {} {}->{} spread {}% (z={:.2}, baseline {:.4}%)",
+            buy_ticker.pair,
+            buy_ticker.exchange,
+            sell_ticker.exchange,
+            spread_pct.round_dp(4),
+            z,
+            mean,
+        );
+
+        Some(ArbitrageOpportunity {
+            id: Uuid::new_v4().to_string(),
+            strategy: String::new(),
+            pair: buy_ticker.pair.clone(),
+            buy_exchange: buy_ticker.exchange,
+            sell_exchange: sell_ticker.exchange,
+            buy_price,
+            sell_price,
+            spread_pct,
+            net_spread_pct,
+            confidence: dec!(1),
+            potential_profit,
+            potential_profit_reporting: None,
+            quantity,
+            context: serde_json::Map::new(),
+            size_constraints: None,
+            detected_at: chrono::Utc::now(),
+            is_actionable: net_spread_pct > dec!(0),
+        })
+    }
+}
+
+impl Strategy for ZScoreStrategy {
+    fn name(&self) -> &'static str {
+        "zscore"
+    }
+
+    fn on_ticker(&self, ticker: &Ticker, prices: &PriceCache) -> Vec<ArbitrageOpportunity> {
+        let pair_str = ticker.pair.to_string();
+        let exchanges = [Exchange::Bybit, Exchange::Bitget];
+        let mut opportunities = Vec::new();
+
+        for other_exchange in &exchanges {
+            if *other_exchange == ticker.exchange {
+                continue;
+            }
+            if let Some(other_ticker) = prices.get(*other_exchange, &pair_str) {
+                if let Some(opp) = self.evaluate_direction(ticker, &other_ticker) {
+                    opportunities.push(opp);
+                }
+                if let Some(opp) = self.evaluate_direction(&other_ticker, ticker) {
+                    opportunities.push(opp);
+                }
+            }
+        }
+
+        opportunities
+    }
+}
@@ -0,0 +1,144 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tracing::{info, warn};
+
+use crate::config::TelegramConfig;
+use crate::prices::PriceCache;
+
+/// Long-poll timeout for getUpdates, seconds
+const POLL_TIMEOUT_SECS: u64 = 30;
+
+/// Telegram bot: pushes notifications to the configured chat and
+/// (optionally) accepts a small command set — `/status`, `/pause`,
+/// `/resume` — mapped onto the same pause flag the engine control API
+/// flips. Commands from any other chat id are ignored.
+pub struct TelegramBot {
+    config: TelegramConfig,
+    client: reqwest::Client,
+}
+
+impl TelegramBot {
+    pub fn from_config(config: &TelegramConfig) -> Self {
+        Self {
+            config: config.clone(),
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.config.enabled && !self.config.bot_token.is_empty() && !self.config.chat_id.is_empty()
+    }
+
+    /// Push one message to the configured chat; failures are logged and
+    /// dropped (Telegram being down must never stall anything)
+    pub async fn send(&self, text: &str) {
+        if !self.enabled() {
+            return;
+        }
+        let url = format!(
+            "https://api.telegram.org/bot{}/sendMessage",
+            self.config.bot_token
+        );
+        let result = self
+            .client
+            .post(&url)
+            .json(&serde_json::json!({
+                "chat_id": self.config.chat_id,
+                "text": text,
+            }))
+            .send()
+            .await;
+        match result {
+            Ok(response) if !response.status().is_success() => {
+                warn!("Telegram send returned {}", response.status());
+            }
+            Err(e) => warn!("Telegram send failed: {}", e),
+            _ => {}
+        }
+    }
+
+    /// Long-poll for commands and apply them; no-op unless commands are
+    /// enabled
+    pub async fn start_commands(&self, paused: Arc<AtomicBool>, prices: Arc<PriceCache>) {
+        if !self.enabled() || !self.config.commands_enabled {
+            return;
+        }
+        info!("Telegram command polling started");
+        let url = format!(
+            "https://api.telegram.org/bot{}/getUpdates",
+            self.config.bot_token
+        );
+        let mut offset = 0i64;
+        loop {
+            let result = self
+                .client
+                .get(&url)
+                .query(&[
+                    ("offset", offset.to_string()),
+                    ("timeout", POLL_TIMEOUT_SECS.to_string()),
+                ])
+                .timeout(std::time::Duration::from_secs(POLL_TIMEOUT_SECS + 10))
+                .send()
+                .await;
+            let data: serde_json::Value = match result {
+                Ok(response) => match response.json().await {
+                    Ok(data) => data,
+                    Err(e) => {
+                        warn!("Telegram poll parse failed: {}", e);
+                        tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                        continue;
+                    }
+                },
+                Err(e) => {
+                    warn!("Telegram poll failed: {}", e);
+                    tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                    continue;
+                }
+            };
+
+            for update in data["result"].as_array().unwrap_or(&Vec::new()) {
+                if let Some(id) = update["update_id"].as_i64() {
+                    offset = offset.max(id + 1);
+                }
+                let chat_id = update["message"]["chat"]["id"]
+                    .as_i64()
+                    .map(|id| id.to_string())
+                    .unwrap_or_default();
+                if chat_id != self.config.chat_id {
+                    continue;
+                }
+                let text = update["message"]["text"].as_str().unwrap_or("");
+                self.handle_command(text, &paused, &prices).await;
+            }
+        }
+    }
+
+    async fn handle_command(&self, text: &str, paused: &AtomicBool, prices: &PriceCache) {
+        match text.trim() {
+            "/status" => {
+                let state = if paused.load(Ordering::Relaxed) {
+                    "paused"
+                } else {
+                    "running"
+                };
+                self.send(&format!(
+                    "Engine {} — {} live tickers cached",
+                    state,
+                    prices.all().len()
+                ))
+                .await;
+            }
+            "/pause" => {
+                paused.store(true, Ordering::Relaxed);
+                info!("Engine paused via Telegram command");
+                self.send("Engine paused").await;
+            }
+            "/resume" => {
+                paused.store(false, Ordering::Relaxed);
+                info!("Engine resumed via Telegram command");
+                self.send("Engine resumed").await;
+            }
+            _ => {}
+        }
+    }
+}
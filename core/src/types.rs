@@ -1,7 +1,9 @@
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt;
+use std::sync::{OnceLock, RwLock};
 
 /// Supported exchanges
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -20,11 +22,47 @@ impl fmt::Display for Exchange {
     }
 }
 
+/// Per-exchange symbol overrides from `[symbol_overrides]` in config,
+/// flattened to (exchange key, pair string) → exchange symbol
+fn symbol_overrides() -> &'static RwLock<HashMap<(String, String), String>> {
+    static MAP: OnceLock<RwLock<HashMap<(String, String), String>>> = OnceLock::new();
+    MAP.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+/// Install the symbol override table from config. Called on config load
+/// (and reload), so `symbol_for` picks up mappings like `PEPE/USDT` →
+/// `1000PEPEUSDT` without threading config into every connector call.
+pub fn set_symbol_overrides(overrides: &HashMap<String, HashMap<String, String>>) {
+    let flat = overrides
+        .iter()
+        .flat_map(|(exchange, pairs)| {
+            pairs.iter().map(move |(pair, symbol)| {
+                (
+                    (exchange.to_lowercase(), pair.to_uppercase()),
+                    symbol.clone(),
+                )
+            })
+        })
+        .collect();
+    *symbol_overrides().write().unwrap() = flat;
+}
+
+/// Which market a pair trades on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketType {
+    #[default]
+    Spot,
+    Perpetual,
+}
+
 /// Trading pair
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TradingPair {
     pub base: String,  // e.g. "BTC"
     pub quote: String, // e.g. "USDT"
+    #[serde(default)]
+    pub market: MarketType,
 }
 
 impl TradingPair {
@@ -32,24 +70,69 @@ impl TradingPair {
         Self {
             base: base.to_uppercase(),
             quote: quote.to_uppercase(),
+            market: MarketType::Spot,
+        }
+    }
+
+    /// Parse "BASE/QUOTE" or "BASE/QUOTE-PERP" as written in config
+    pub fn parse(s: &str) -> Option<Self> {
+        let (base, quote) = s.split_once('/')?;
+        if base.is_empty() || quote.is_empty() {
+            return None;
+        }
+        match quote.strip_suffix("-PERP") {
+            Some(quote) => Some(Self::perp(base, quote)),
+            None => Some(Self::new(base, quote)),
         }
     }
 
-    /// Returns the pair symbol for a specific exchange
+    /// The perpetual contract for the same base/quote
+    pub fn perp(base: &str, quote: &str) -> Self {
+        Self {
+            base: base.to_uppercase(),
+            quote: quote.to_uppercase(),
+            market: MarketType::Perpetual,
+        }
+    }
+
+    /// Returns the pair symbol for a specific exchange, consulting the
+    /// configured `[symbol_overrides]` table first (both venues otherwise
+    /// use plain concatenation for spot and USDT-margined perpetuals)
     pub fn symbol_for(&self, exchange: Exchange) -> String {
-        match exchange {
-            Exchange::Bybit => format!("{}{}", self.base, self.quote),    // BTCUSDT
-            Exchange::Bitget => format!("{}{}", self.base, self.quote),   // BTCUSDT
+        let key = match exchange {
+            Exchange::Bybit => "bybit",
+            Exchange::Bitget => "bitget",
+        };
+        if let Some(symbol) = symbol_overrides()
+            .read()
+            .unwrap()
+            .get(&(key.to_string(), self.to_string()))
+        {
+            return symbol.clone();
         }
+        format!("{}{}", self.base, self.quote) // BTCUSDT
     }
 }
 
 impl fmt::Display for TradingPair {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}/{}", self.base, self.quote)
+        match self.market {
+            MarketType::Spot => write!(f, "{}/{}", self.base, self.quote),
+            MarketType::Perpetual => write!(f, "{}/{}-PERP", self.base, self.quote),
+        }
     }
 }
 
+/// Where a ticker update came from — REST polling is the degraded
+/// fallback used when a venue's WS feed has gone quiet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TickerSource {
+    #[default]
+    Ws,
+    RestPoll,
+}
+
 /// Real-time ticker data from an exchange
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Ticker {
@@ -60,6 +143,8 @@ pub struct Ticker {
     pub last: Decimal,      // Last traded price
     pub volume_24h: Decimal,
     pub timestamp: DateTime<Utc>,
+    #[serde(default)]
+    pub source: TickerSource,
 }
 
 impl Ticker {
@@ -72,6 +157,37 @@ impl Ticker {
     }
 }
 
+/// Current funding rate on a perpetual market
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundingRate {
+    pub exchange: Exchange,
+    pub pair: TradingPair,
+    /// Rate per funding interval (e.g. 0.0001 = 1 bp per interval)
+    pub rate: Decimal,
+    /// Funding interval length (8h on both venues)
+    pub interval_hours: u32,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// One price level in an L2 order book
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBookLevel {
+    pub price: Decimal,
+    pub qty: Decimal,
+}
+
+/// L2 order book snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderBook {
+    pub exchange: Exchange,
+    pub pair: TradingPair,
+    /// Best (highest) bid first
+    pub bids: Vec<OrderBookLevel>,
+    /// Best (lowest) ask first
+    pub asks: Vec<OrderBookLevel>,
+    pub timestamp: DateTime<Utc>,
+}
+
 /// Order side
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -88,10 +204,87 @@ pub enum OrderType {
     Limit,
 }
 
+/// Exchange-side order lifecycle state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+    Rejected,
+}
+
+/// An order as placed on (or reported by) an exchange — both venues'
+/// payloads normalize onto this
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Order {
+    /// Exchange-assigned order id
+    pub id: String,
+    /// Our id echoed back by the exchange, when one was attached
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    pub exchange: Exchange,
+    pub pair: TradingPair,
+    pub side: OrderSide,
+    pub order_type: OrderType,
+    /// Limit price (None for market orders)
+    pub price: Option<Decimal>,
+    pub quantity: Decimal,
+    pub filled_qty: Decimal,
+    pub status: OrderStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Order {
+    /// Whether the order can still fill (or be cancelled) on the exchange
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, OrderStatus::New | OrderStatus::PartiallyFilled)
+    }
+}
+
+/// Realized execution details for an order, as reported by the venue —
+/// what actually happened, as opposed to the prices the detector saw
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderFill {
+    pub order_id: String,
+    /// Average fill price across all executions
+    pub avg_price: Decimal,
+    pub filled_qty: Decimal,
+    /// Fee actually charged, in the pair's quote currency (venues that
+    /// charge spot-buy fees in the base asset are converted at `avg_price`)
+    pub fee: Decimal,
+}
+
+/// The candidate sizes each sizing constraint allowed for one opportunity
+/// and which one ended up binding — carried onto the trade so the usual
+/// bottleneck (depth? balances? risk limits?) can be analyzed later
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SizeConstraints {
+    /// Largest profitably fillable quantity given current depth
+    pub depth_qty: Option<Decimal>,
+    /// Largest quantity current balances could fund (None in simulation)
+    pub balance_qty: Option<Decimal>,
+    /// Remaining per-pair risk headroom
+    pub risk_headroom_qty: Decimal,
+    /// Largest quantity before modeled price impact eats the spread
+    pub impact_qty: Option<Decimal>,
+    /// The quantity actually chosen (min of the above, capped at
+    /// `max_trade_qty`)
+    pub chosen_qty: Decimal,
+    /// Name of the constraint that bound
+    pub binding: String,
+}
+
 /// An arbitrage opportunity detected by the engine
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArbitrageOpportunity {
     pub id: String,
+    /// Name of the strategy that produced this candidate (tagged by the
+    /// detector)
+    #[serde(default)]
+    pub strategy: String,
     pub pair: TradingPair,
     pub buy_exchange: Exchange,
     pub sell_exchange: Exchange,
@@ -99,17 +292,42 @@ pub struct ArbitrageOpportunity {
     pub sell_price: Decimal,      // Bid on sell exchange
     pub spread_pct: Decimal,      // Spread as percentage
     pub net_spread_pct: Decimal,  // Spread after fees
+    /// 1.0 for opportunities priced off live WS feeds, reduced when either
+    /// leg's quote came from degraded REST polling
+    #[serde(default = "default_confidence")]
+    pub confidence: Decimal,
     pub potential_profit: Decimal, // Estimated profit in quote currency
+    /// `potential_profit` in the reporting currency (None when no
+    /// conversion path was available)
+    #[serde(default)]
+    pub potential_profit_reporting: Option<Decimal>,
     pub quantity: Decimal,
+    /// Extensible feature map for downstream ML consumers (volatility,
+    /// depth near touch, feed lags, imbalance, time-of-day, …) populated
+    /// by the detector so filters can be trained without re-deriving
+    /// features from raw recordings
+    #[serde(default)]
+    pub context: serde_json::Map<String, serde_json::Value>,
+    /// What each sizing constraint allowed (set once the detector has
+    /// depth-sized the candidate)
+    #[serde(default)]
+    pub size_constraints: Option<SizeConstraints>,
     pub detected_at: DateTime<Utc>,
     pub is_actionable: bool,
 }
 
+fn default_confidence() -> Decimal {
+    Decimal::ONE
+}
+
 /// Result of an executed trade
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradeResult {
     pub id: String,
     pub opportunity_id: String,
+    /// Strategy the originating opportunity came from
+    #[serde(default)]
+    pub strategy: String,
     pub pair: TradingPair,
     pub buy_exchange: Exchange,
     pub sell_exchange: Exchange,
@@ -119,7 +337,21 @@ pub struct TradeResult {
     pub gross_profit: Decimal,
     pub fees: Decimal,
     pub net_profit: Decimal,
+    /// `net_profit` in the reporting currency (None when no conversion
+    /// path was available)
+    #[serde(default)]
+    pub net_profit_reporting: Option<Decimal>,
+    /// Sizing constraints inherited from the opportunity
+    #[serde(default)]
+    pub size_constraints: Option<SizeConstraints>,
     pub status: TradeStatus,
+    /// Milliseconds from opportunity detection to order submission
+    #[serde(default)]
+    pub detect_to_submit_ms: i64,
+    /// Milliseconds from order submission to booking the result (order
+    /// acks, limit follow-up and fill polling included)
+    #[serde(default)]
+    pub submit_to_fill_ms: i64,
     pub executed_at: DateTime<Utc>,
 }
 
@@ -134,6 +366,81 @@ pub enum TradeStatus {
     Cancelled,
 }
 
+/// A deposit address for an asset on one venue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DepositAddress {
+    pub exchange: Exchange,
+    pub asset: String,
+    pub network: String,
+    pub address: String,
+    /// Memo/tag, for networks that require one
+    pub tag: Option<String>,
+}
+
+/// Kind of account-level event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccountEventKind {
+    Deposit,
+    Withdrawal,
+    /// A fill on the exchange that did not originate from this bot
+    #[serde(rename = "external_trade")]
+    ExternalTrade,
+}
+
+/// Account-level event observed on an exchange (deposit, withdrawal, or a
+/// trade made outside the bot) — used for inventory tracking and
+/// reconciliation so external activity doesn't show up as balance drift
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountEvent {
+    /// Exchange-assigned id of the underlying record (used for dedup)
+    pub id: String,
+    pub exchange: Exchange,
+    pub kind: AccountEventKind,
+    pub asset: String,
+    /// Positive for inflows (deposits, buys), negative for outflows
+    pub amount: Decimal,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// What a reconciliation discrepancy is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscrepancyKind {
+    /// The exchange reports a fill we have no local record of
+    OrphanFill,
+    /// We recorded a fill the exchange's history doesn't contain
+    PhantomTrade,
+}
+
+/// A mismatch between local trade records and an exchange's own history,
+/// found by the background reconciliation job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReconcileDiscrepancy {
+    pub exchange: Exchange,
+    pub pair: String,
+    /// Exchange-assigned order id at the center of the mismatch
+    pub order_id: String,
+    pub kind: DiscrepancyKind,
+    pub detail: String,
+    pub at: DateTime<Utc>,
+}
+
+/// A manual operator action (pause, kill, config change, …) recorded in the
+/// audit journal for multi-operator deployments
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OperatorAction {
+    pub id: u64,
+    /// Who performed the action — API token/role once auth lands, masked
+    /// bearer token or "anonymous" until then
+    pub actor: String,
+    /// Action name, e.g. "config_update", "pause", "kill"
+    pub action: String,
+    /// Action-specific parameters
+    pub params: serde_json::Value,
+    pub timestamp: DateTime<Utc>,
+}
+
 /// Exchange balance info
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExchangeBalance {
@@ -144,16 +451,40 @@ pub struct ExchangeBalance {
     pub total: Decimal,
 }
 
+/// Connectivity state of one venue's market-data feed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConnectionState {
+    Connected,
+    Reconnecting,
+    Down,
+}
+
+/// Connectivity snapshot for one venue, from the exchange health registry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExchangeHealth {
+    pub exchange: Exchange,
+    pub state: ConnectionState,
+    /// When the venue last produced a ticker (None = never)
+    pub last_ticker: Option<DateTime<Utc>>,
+    /// WS reconnect attempts since startup
+    pub reconnects: u64,
+}
+
 /// Engine status
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EngineStatus {
     pub running: bool,
+    /// True while the operator has paused detection and execution
+    pub paused: bool,
     pub uptime_secs: u64,
     pub opportunities_found: u64,
     pub trades_executed: u64,
     pub total_profit: Decimal,
     pub active_exchanges: Vec<Exchange>,
     pub monitored_pairs: Vec<TradingPair>,
+    /// Rolling per-venue latency: WS message lag and REST round-trips
+    pub latency: Vec<crate::sla::VenueSlaReport>,
 }
 
 /// Messages sent over the WebSocket to the UI
@@ -168,4 +499,12 @@ pub enum WsMessage {
     Trade(TradeResult),
     #[serde(rename = "status")]
     Status(EngineStatus),
+    #[serde(rename = "account_event")]
+    AccountEvent(AccountEvent),
+    #[serde(rename = "discrepancy")]
+    Discrepancy(ReconcileDiscrepancy),
+    #[serde(rename = "exchange_health")]
+    ExchangeHealth(Vec<ExchangeHealth>),
+    #[serde(rename = "system_event")]
+    SystemEvent(crate::eventlog::SystemEvent),
 }